@@ -0,0 +1,133 @@
+//! Benchmarks for parsing each of the API responses in `test_data`.
+//!
+//! Run with `cargo bench --features bench` for the default xml-rs backend, and
+//! `cargo bench --features bench,quick-xml` to compare against the quick-xml backend.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+fn bench_file<T>(c: &mut Criterion, path: &str, parse: fn(&str) -> arnak::Result<T>) {
+    bench_file_as(c, path, "parse", parse);
+}
+
+fn bench_file_as<T>(c: &mut Criterion, path: &str, id: &str, parse: fn(&str) -> arnak::Result<T>) {
+    let xml = std::fs::read_to_string(path).expect("failed to load test data");
+    assert!(parse(&xml).is_ok(), "failed to parse {path}");
+
+    let mut group = c.benchmark_group(path.trim_start_matches("test_data/"));
+    group.throughput(Throughput::Bytes(xml.len() as u64));
+    group.bench_function(id, |b| b.iter(|| parse(black_box(&xml))));
+    group.finish();
+}
+
+fn accessory(c: &mut Criterion) {
+    bench_file(
+        c,
+        "test_data/accessory/accessory_full.xml",
+        arnak::bench::parse_accessories,
+    );
+}
+
+fn collection(c: &mut Criterion) {
+    bench_file(
+        c,
+        "test_data/collection/collection_multiple.xml",
+        arnak::bench::parse_collection,
+    );
+    bench_file_as(
+        c,
+        "test_data/collection/collection_multiple.xml",
+        "parse_borrowed",
+        arnak::bench::parse_collection_borrowed,
+    );
+    bench_file(
+        c,
+        "test_data/collection/collection_owned_with_stats.xml",
+        arnak::bench::parse_collection,
+    );
+    bench_file(
+        c,
+        "test_data/collection/collection_brief_with_version.xml",
+        arnak::bench::parse_collection_brief,
+    );
+}
+
+fn forum(c: &mut Criterion) {
+    bench_file(c, "test_data/forum/forum.xml", arnak::bench::parse_forum);
+    bench_file(
+        c,
+        "test_data/forum_group/thing_forum_group.xml",
+        arnak::bench::parse_forum_group,
+    );
+}
+
+fn game(c: &mut Criterion) {
+    bench_file(c, "test_data/game/game_all.xml", arnak::bench::parse_games);
+    bench_file(
+        c,
+        "test_data/game/game_multiple.xml",
+        arnak::bench::parse_games,
+    );
+    bench_file_as(
+        c,
+        "test_data/game/game_multiple.xml",
+        "parse_borrowed",
+        arnak::bench::parse_games_borrowed,
+    );
+    bench_file(
+        c,
+        "test_data/game_family/game_family_multiple.xml",
+        arnak::bench::parse_game_families,
+    );
+}
+
+fn guild(c: &mut Criterion) {
+    bench_file(
+        c,
+        "test_data/guild/guild_with_member_page.xml",
+        arnak::bench::parse_guild,
+    );
+}
+
+fn hot_list(c: &mut Criterion) {
+    bench_file(
+        c,
+        "test_data/hot_list/hot_list.xml",
+        arnak::bench::parse_hot_list,
+    );
+}
+
+fn plays(c: &mut Criterion) {
+    bench_file(
+        c,
+        "test_data/plays/user_plays.xml",
+        arnak::bench::parse_plays,
+    );
+    bench_file(
+        c,
+        "test_data/plays/thing_plays.xml",
+        arnak::bench::parse_plays,
+    );
+}
+
+fn search(c: &mut Criterion) {
+    bench_file(
+        c,
+        "test_data/search/search.xml",
+        arnak::bench::parse_search_results,
+    );
+}
+
+fn thread(c: &mut Criterion) {
+    bench_file(c, "test_data/thread/thread.xml", arnak::bench::parse_thread);
+}
+
+fn user(c: &mut Criterion) {
+    bench_file(c, "test_data/user/user_full.xml", arnak::bench::parse_user);
+}
+
+criterion_group!(
+    benches, accessory, collection, forum, game, guild, hot_list, plays, search, thread, user
+);
+criterion_main!(benches);
@@ -1,4 +1,5 @@
 use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 use reqwest::{RequestBuilder, Response};
@@ -6,48 +7,143 @@ use tokio::time::sleep;
 
 use crate::deserialize::deserialize_xml_string;
 use crate::endpoints::collection::CollectionApi;
+use crate::session::LoginRequest;
+use crate::token::auth_header_value;
 use crate::{
-    deserialize_maybe_error, AccessoryApi, CollectionItem, CollectionItemBrief, Error, ForumApi,
-    ForumGroupApi, GameApi, GameFamilyApi, GuildApi, HotListApi, PlaysApi, Result, SearchApi,
-    ThreadApi, UserApi,
+    deserialize_maybe_error, AccessoryApi, CollectionItem, CollectionItemBrief, Domain, Error,
+    ForumApi, ForumGroupApi, GameApi, GameFamilyApi, GeekListApi, GuildApi, HotListApi, PlaysApi,
+    Result, RpgItemApi, SearchApi, Session, StaticTokenProvider, ThingApi, ThreadApi,
+    TokenProvider, UserApi, V1Api, VideoGameApi,
 };
 
-fn http_client_from_token(auth_token: &str) -> Result<reqwest::Client> {
-    let mut default_headers = reqwest::header::HeaderMap::new();
-    let mut auth_header_value = reqwest::header::HeaderValue::from_str(
-        format!("Bearer {auth_token}").as_str(),
-    )
-    .map_err(|_| {
-        Error::HttpClientCreationError("auth token contains invalid header characters".to_owned())
-    })?;
-    auth_header_value.set_sensitive(true);
-    default_headers.insert(reqwest::header::AUTHORIZATION, auth_header_value);
+fn http_client() -> Result<reqwest::Client> {
     reqwest::ClientBuilder::new()
-        .default_headers(default_headers)
         .build()
         .map_err(|e| Error::HttpClientCreationError(e.to_string()))
 }
 
 /// API for making requests to the [Board Game Geek API](https://boardgamegeek.com/wiki/page/BGG_XML_API2).
+///
+/// Cloning the API is cheap, the underlying HTTP client is shared between clones.
+#[derive(Clone)]
 pub struct BoardGameGeekApi {
     // URL for the board game geek API.
     // Note this is a String instead of a 'static &str for unit test purposes.
     pub(crate) base_url: String,
     // Http client for making requests to the underlying API.
     pub(crate) client: reqwest::Client,
+    // Which site's items are requested, board games unless opted in to another.
+    pub(crate) domain: Domain,
+    // Provides the auth token sent with each request.
+    pub(crate) token_provider: Arc<dyn TokenProvider>,
 }
 
 impl BoardGameGeekApi {
     const BASE_URL: &'static str = "https://boardgamegeek.com/xmlapi2";
 
-    /// Creates a new API from a default HTTP client.
+    /// Creates a new API from a default HTTP client, that sends the same auth token with every
+    /// request.
     pub fn new(auth_token: &str) -> Result<Self> {
+        auth_header_value(auth_token).map_err(|_| {
+            Error::HttpClientCreationError(
+                "auth token contains invalid header characters".to_owned(),
+            )
+        })?;
+        Self::with_token_provider(StaticTokenProvider::new(auth_token))
+    }
+
+    /// Creates a new API from a default HTTP client, that asks the given provider for the auth
+    /// token to send with each request. Used when tokens are rotated, so that the new token is
+    /// picked up without rebuilding the API.
+    ///
+    /// ```
+    /// use arnak::{BoardGameGeekApi, EnvTokenProvider};
+    ///
+    /// let api = BoardGameGeekApi::with_token_provider(EnvTokenProvider::new("BGG_AUTH_TOKEN"))
+    ///     .unwrap();
+    /// ```
+    pub fn with_token_provider(token_provider: impl TokenProvider + 'static) -> Result<Self> {
         Ok(Self {
             base_url: String::from(BoardGameGeekApi::BASE_URL),
-            client: http_client_from_token(auth_token)?,
+            client: http_client()?,
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(token_provider),
         })
     }
 
+    /// Sets the domain that requests are made for, to opt in to role-playing games from RPG Geek
+    /// or video games from Video Game Geek instead of board games.
+    ///
+    /// This changes what is returned by the search, game family, collection, user, and hot list
+    /// endpoints. Details of RPG items and video games are requested with
+    /// [`BoardGameGeekApi::rpg_item`] and [`BoardGameGeekApi::video_game`].
+    ///
+    /// ```
+    /// use arnak::{BoardGameGeekApi, Domain};
+    ///
+    /// let api = BoardGameGeekApi::new("my_auth_token")
+    ///     .unwrap()
+    ///     .with_domain(Domain::Rpg);
+    /// assert_eq!(api.domain(), Domain::Rpg);
+    /// ```
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Sets the URL of the XML API that requests are made to, in place of
+    /// `https://boardgamegeek.com/xmlapi2`. For example a local server standing in for the real
+    /// API in tests.
+    ///
+    /// Requests to the rest of the site, such as logging in and logging plays, are made relative to
+    /// this URL with any trailing `/xmlapi2` removed.
+    ///
+    /// ```
+    /// use arnak::BoardGameGeekApi;
+    ///
+    /// let api = BoardGameGeekApi::new("my_auth_token")
+    ///     .unwrap()
+    ///     .with_base_url("http://localhost:8080/xmlapi2");
+    /// ```
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// The domain that requests are made for, [`Domain::BoardGame`] unless set with
+    /// [`BoardGameGeekApi::with_domain`].
+    pub fn domain(&self) -> Domain {
+        self.domain
+    }
+
+    /// Logs in to the site as the given user, returning a [`Session`] that can be used for requests
+    /// that act as that user, such as logging plays.
+    ///
+    /// Returns an [`Error::LoginError`] if the username or password is rejected.
+    pub async fn log_in(&self, username: &str, password: &str) -> Result<Session> {
+        let request = self
+            .client
+            .post(format!("{}/login/api/v1", self.root_url()))
+            .json(&LoginRequest::new(username, password));
+        let response = self.authorize(request).await?.send().await?;
+
+        match response.status() {
+            status if status.is_success() => Session::from_login_response(username, &response),
+            reqwest::StatusCode::BAD_REQUEST
+            | reqwest::StatusCode::UNAUTHORIZED
+            | reqwest::StatusCode::FORBIDDEN => {
+                Err(Error::LoginError("invalid username or password".to_owned()))
+            },
+            _ => match response.error_for_status() {
+                Err(e) => Err(Error::HttpError(e)),
+                Ok(response) => Err(Error::UnexpectedResponseError(format!(
+                    "login returned status {}",
+                    response.status(),
+                ))),
+            },
+        }
+    }
+
     /// Returns the accessory endpoint of the API, which is used for querying game accessories by
     /// their ID.
     pub fn accessory(&self) -> AccessoryApi<'_> {
@@ -101,6 +197,12 @@ impl BoardGameGeekApi {
         GameApi::new(self)
     }
 
+    /// Returns the geek list endpoint of the API, which is used for querying geek lists, curated
+    /// lists of games and other items, by their IDs.
+    pub fn geek_list(&self) -> GeekListApi<'_> {
+        GeekListApi::new(self)
+    }
+
     /// Returns the guild endpoint of the API, which is used for querying
     /// guilds by their IDs.
     pub fn guild(&self) -> GuildApi<'_> {
@@ -119,18 +221,42 @@ impl BoardGameGeekApi {
         PlaysApi::new(self)
     }
 
+    /// Returns the RPG item endpoint of the API, which is used for querying items for
+    /// role-playing games, such as rulebooks and adventures, by their IDs.
+    pub fn rpg_item(&self) -> RpgItemApi<'_> {
+        RpgItemApi::new(self)
+    }
+
     /// Returns the search endpoint of the API, which is used for searching for
     /// board games by name.
     pub fn search(&self) -> SearchApi<'_> {
         SearchApi::new(self)
     }
 
+    /// Returns the thing endpoint of the API, which is used for querying items of mixed types,
+    /// such as games and accessories together, by their IDs.
+    pub fn thing(&self) -> ThingApi<'_> {
+        ThingApi::new(self)
+    }
+
     /// Returns the thread endpoint of the API, which is used for searching for
     /// forum threads.
     pub fn thread(&self) -> ThreadApi<'_> {
         ThreadApi::new(self)
     }
 
+    /// Returns the endpoints of the older XML API v1, which are used for querying data that the
+    /// current API doesn't provide, such as the ratings of a game over time.
+    pub fn v1(&self) -> V1Api<'_> {
+        V1Api::new(self)
+    }
+
+    /// Returns the video game endpoint of the API, which is used for querying video games by
+    /// their IDs.
+    pub fn video_game(&self) -> VideoGameApi<'_> {
+        VideoGameApi::new(self)
+    }
+
     /// Returns the user endpoint of the API, which is used for querying users
     /// on the site.
     pub fn user(&self) -> UserApi<'_> {
@@ -149,45 +275,129 @@ impl BoardGameGeekApi {
             .query(query)
     }
 
-    // Handles a HTTP request by calling execute_request_raw, then parses the
+    // Creates a reqwest::RequestBuilder for an endpoint of the older XML API v1, which is served
+    // from `/xmlapi` alongside the current API at `/xmlapi2`. Some data, such as geek lists, is
+    // only available from v1 endpoints.
+    pub(crate) fn build_v1_request(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+    ) -> reqwest::RequestBuilder {
+        self.client
+            .get(format!("{}/xmlapi/{endpoint}", self.root_url()))
+            .query(query)
+    }
+
+    // Creates a reqwest::RequestBuilder for a POST to a page of the site outside of the XML API,
+    // made as the user of the given session. Used for requests that change data, such as logging
+    // plays, which the XML API has no support for.
+    pub(crate) fn build_site_post_request(
+        &self,
+        path: &str,
+        session: &Session,
+    ) -> Result<reqwest::RequestBuilder> {
+        let request = self.client.post(format!("{}/{path}", self.root_url()));
+        with_session(request, session)
+    }
+
+    // Creates a reqwest::RequestBuilder the same as `build_request`, but made as the user of the
+    // given session. Used for requests to the XML API that return more to a logged in user, such
+    // as private collection info.
+    pub(crate) fn build_request_with_session(
+        &self,
+        endpoint: &str,
+        query: &[(&str, String)],
+        session: &Session,
+    ) -> Result<reqwest::RequestBuilder> {
+        with_session(self.build_request(endpoint, query), session)
+    }
+
+    // The root URL of the site, the base URL without the XML API path.
+    fn root_url(&self) -> &str {
+        self.base_url
+            .strip_suffix("/xmlapi2")
+            .unwrap_or(&self.base_url)
+    }
+
+    // Handles a HTTP request by calling execute_raw_request, then parses the
     // response to the expected type.
     pub(crate) async fn execute_request<T: serde::de::DeserializeOwned>(
         &self,
         request: RequestBuilder,
     ) -> Result<T> {
-        let response = self.send_request(request).await?;
-        let response_text = response.text().await?;
-
-        let parse_result = deserialize_xml_string(&response_text);
-        match parse_result {
-            Ok(result) => Ok(result),
-            Err(e) => {
-                // The API returns a 200 but with an XML error in some cases,
-                // such as a username not found, so we try to parse that first
-                // for a more specific error.
-                match deserialize_maybe_error(&response_text) {
-                    Some(api_error) => Err(api_error),
-                    // If the error cannot be parsed, that likely means it was a successful response
-                    // that we failed to parse. So return an unexpected response with the original
-                    // error.
-                    None => Err(Error::InvalidResponseError(e)),
-                }
+        let response_text = self.execute_raw_request(request).await?;
+        parse_response(&response_text)
+    }
+
+    // Handles a HTTP request made to the XML API with a session, then parses the response to the
+    // expected type. The API responds with a 403 when the session is no longer valid.
+    pub(crate) async fn execute_request_with_session<T: serde::de::DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<T> {
+        match self.execute_request(request).await {
+            Err(Error::HttpError(e)) if e.status() == Some(reqwest::StatusCode::FORBIDDEN) => {
+                Err(Error::SessionExpired)
             },
+            result => result,
         }
     }
 
+    // Handles a HTTP request made with a session to the site outside of the XML API, returning
+    // the text of the response. The site responds with a 401 or 403 when the session is no longer
+    // valid.
+    pub(crate) async fn execute_site_request(&self, request: RequestBuilder) -> Result<String> {
+        let response = self.authorize(request).await?.send().await?;
+        match response.status() {
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                Err(Error::SessionExpired)
+            },
+            _ => Ok(response.error_for_status()?.text().await?),
+        }
+    }
+
+    // Handles a HTTP request, returning the text of the response without parsing it.
+    pub(crate) async fn execute_raw_request(&self, request: RequestBuilder) -> Result<String> {
+        let response = self.send_request(request).await?;
+        Ok(response.text().await?)
+    }
+
+    // Adds the current auth token to a request.
+    async fn authorize(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        authorize(request, self.token_provider.as_ref()).await
+    }
+
     // Handles an HTTP request. send_request accepts a reqwest::ReqwestBuilder,
     // sends it and awaits. If the response is Accepted (202), it will wait for the
     // data to be ready and try again.
-    fn send_request(&self, request: RequestBuilder) -> impl Future<Output = Result<Response>> {
+    //
+    // The request is sent with the current auth token. If it is rejected as unauthorized (401) the
+    // token is refreshed and the request tried once more, in case the token was rotated. A
+    // forbidden (403) response is not a problem with the token, it is returned for private or
+    // session-only resources, so it is passed on as an error for the caller to handle.
+    pub(crate) fn send_request(
+        &self,
+        request: RequestBuilder,
+    ) -> impl Future<Output = Result<Response>> {
+        let token_provider = Arc::clone(&self.token_provider);
         let mut retries: u32 = 0;
+        let mut refreshed_token = false;
         async move {
             loop {
                 let request_clone = request.try_clone().expect("Couldn't clone request");
+                let request_clone = authorize(request_clone, token_provider.as_ref()).await?;
                 let response = match request_clone.send().await {
                     Ok(response) => response,
                     Err(e) => break Err(Error::HttpError(e)),
                 };
+                if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+                    if refreshed_token {
+                        break Err(Error::Unauthorized);
+                    }
+                    token_provider.refresh().await?;
+                    refreshed_token = true;
+                    continue;
+                }
                 if response.status() == reqwest::StatusCode::ACCEPTED {
                     if retries >= 4 {
                         break Err(Error::CollectionNotReady);
@@ -209,9 +419,94 @@ impl BoardGameGeekApi {
     }
 }
 
+// Adds the current token from the provider to a request in an `Authorization` header.
+async fn authorize(
+    request: RequestBuilder,
+    token_provider: &dyn TokenProvider,
+) -> Result<RequestBuilder> {
+    let token = token_provider.token().await?;
+    Ok(request.header(reqwest::header::AUTHORIZATION, auth_header_value(&token)?))
+}
+
+// Parses the text of a response from the API to the expected type.
+pub(crate) fn parse_response<'a, T: serde::de::Deserialize<'a>>(
+    response_text: &'a str,
+) -> Result<T> {
+    parse_response_with(response_text, deserialize_xml_string)
+}
+
+// Parses the text of a response from the API with the given parse function, checking for an error
+// returned by the API if parsing fails.
+pub(crate) fn parse_response_with<'a, T>(
+    response_text: &'a str,
+    parse: impl FnOnce(&'a str) -> core::result::Result<T, serde_xml_rs::Error>,
+) -> Result<T> {
+    let parse_result = parse(response_text);
+    match parse_result {
+        Ok(result) => Ok(result),
+        Err(e) => {
+            // The API returns a 200 but with an XML error in some cases,
+            // such as a username not found, so we try to parse that first
+            // for a more specific error.
+            match deserialize_maybe_error(response_text) {
+                Some(api_error) => Err(api_error),
+                // If the error cannot be parsed, that likely means it was a successful response
+                // that we failed to parse. So return an unexpected response with the original
+                // error.
+                None => Err(Error::InvalidResponseError(e)),
+            }
+        },
+    }
+}
+
+// Adds the cookies of a session to a request, so that it is made as the user of the session.
+fn with_session(request: RequestBuilder, session: &Session) -> Result<RequestBuilder> {
+    if session.is_expired() {
+        return Err(Error::SessionExpired);
+    }
+    Ok(request.header(reqwest::header::COOKIE, session.cookie.clone()))
+}
+
+// Parses the JSON response of a request made to the site outside of the XML API, such as logging a
+// play. Errors, such as an item not existing, are returned with a successful status and an `error`
+// message in place of the usual response. If the session is no longer valid this is a message
+// asking the user to log in.
+pub(crate) fn parse_site_response<T: serde::de::DeserializeOwned>(
+    response_text: &str,
+) -> Result<T> {
+    #[derive(serde::Deserialize)]
+    struct SiteError {
+        #[serde(default)]
+        error: Option<String>,
+    }
+
+    if let Ok(SiteError { error: Some(error) }) = serde_json::from_str(response_text) {
+        let lowercase_error = error.to_lowercase();
+        if ["login", "log in", "logged in"]
+            .iter()
+            .any(|phrase| lowercase_error.contains(phrase))
+        {
+            return Err(Error::SessionExpired);
+        }
+        if !error.is_empty() {
+            return Err(Error::UnknownApiErrors(vec![error]));
+        }
+    }
+    serde_json::from_str(response_text).map_err(|e| {
+        Error::UnexpectedResponseError(format!("failed to parse response from site: {e}"))
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use chrono::{TimeZone, Utc};
+    use futures_core::future::BoxFuture;
+
     use super::*;
+    use crate::{Domain, StaticTokenProvider};
 
     #[tokio::test]
     async fn send_request() {
@@ -219,10 +514,13 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
             .mock("GET", "/some_endpoint")
+            .match_header("authorization", "Bearer my_auth_token")
             .with_status(200)
             .with_body("hello there")
             .create_async()
@@ -242,6 +540,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -257,12 +557,136 @@ mod tests {
         assert!(res.is_err());
     }
 
+    // Returns a new token after being refreshed, like a provider for a token that was rotated.
+    struct RotatingTokenProvider {
+        refreshed: AtomicBool,
+    }
+
+    impl TokenProvider for RotatingTokenProvider {
+        fn token(&self) -> BoxFuture<'_, Result<String>> {
+            Box::pin(async {
+                match self.refreshed.load(Ordering::SeqCst) {
+                    true => Ok("new_token".to_owned()),
+                    false => Ok("old_token".to_owned()),
+                }
+            })
+        }
+
+        fn refresh(&self) -> BoxFuture<'_, Result<()>> {
+            Box::pin(async {
+                self.refreshed.store(true, Ordering::SeqCst);
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn send_request_refreshes_token() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(RotatingTokenProvider {
+                refreshed: AtomicBool::new(false),
+            }),
+        };
+
+        let old_token_mock = server
+            .mock("GET", "/some_endpoint")
+            .match_header("authorization", "Bearer old_token")
+            .with_status(401)
+            .create_async()
+            .await;
+        let new_token_mock = server
+            .mock("GET", "/some_endpoint")
+            .match_header("authorization", "Bearer new_token")
+            .with_status(200)
+            .with_body("hello there")
+            .create_async()
+            .await;
+
+        let request = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(request).await;
+
+        old_token_mock.assert_async().await;
+        new_token_mock.assert_async().await;
+        assert!(res.is_ok());
+        assert!(res.unwrap().text().await.unwrap() == "hello there");
+    }
+
+    #[tokio::test]
+    async fn send_request_unauthorized() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(401)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let request = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(request).await;
+
+        mock.assert_async().await;
+        assert!(
+            matches!(res, Err(Error::Unauthorized)),
+            "expected unauthorized error but got {res:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn send_request_forbidden() {
+        let mut server = mockito::Server::new_async().await;
+        let token_provider = Arc::new(RotatingTokenProvider {
+            refreshed: AtomicBool::new(false),
+        });
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: token_provider.clone(),
+        };
+
+        let mock = server
+            .mock("GET", "/some_endpoint")
+            .with_status(403)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let request = api.build_request("some_endpoint", &[]);
+        let res = api.send_request(request).await;
+
+        mock.assert_async().await;
+        assert!(
+            matches!(
+                &res,
+                Err(Error::HttpError(e)) if e.status() == Some(reqwest::StatusCode::FORBIDDEN),
+            ),
+            "expected forbidden error but got {res:?}",
+        );
+        assert!(
+            !token_provider.refreshed.load(Ordering::SeqCst),
+            "token refreshed for a forbidden response",
+        );
+    }
+
     #[tokio::test(start_paused = true)]
     async fn send_request_202_retries() {
         let mut server = mockito::Server::new_async().await;
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -318,4 +742,68 @@ mod tests {
 
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn log_in() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi::new("my_auth_token")
+            .unwrap()
+            .with_base_url(format!("{}/xmlapi2", server.url()));
+
+        let mock = server
+            .mock("POST", "/login/api/v1")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "credentials": {
+                    "username": "bluebearbgg",
+                    "password": "hunter2",
+                },
+            })))
+            .with_status(204)
+            .with_header("set-cookie", "bggusername=deleted; path=/")
+            .with_header("set-cookie", "bggusername=bluebearbgg; path=/; HttpOnly")
+            .with_header(
+                "set-cookie",
+                "SessionID=abc123; expires=Mon, 21 Oct 2030 07:28:00 GMT; path=/; secure; HttpOnly",
+            )
+            .create_async()
+            .await;
+
+        let session = api.log_in("bluebearbgg", "hunter2").await;
+        mock.assert_async().await;
+
+        assert!(session.is_ok(), "error returned when okay expected");
+        let session = session.unwrap();
+        assert_eq!(session.username(), "bluebearbgg");
+        assert_eq!(session.cookie, "bggusername=bluebearbgg; SessionID=abc123");
+        assert_eq!(
+            session.expires_at(),
+            Some(Utc.with_ymd_and_hms(2030, 10, 21, 7, 28, 0).unwrap()),
+        );
+    }
+
+    #[tokio::test]
+    async fn log_in_invalid_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("POST", "/login/api/v1")
+            .with_status(401)
+            .with_body(r#"{"errors":{"message":"Invalid username or password"}}"#)
+            .create_async()
+            .await;
+
+        let session = api.log_in("bluebearbgg", "wrong").await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(session, Err(Error::LoginError(_))),
+            "expected login error but got {session:?}",
+        );
+    }
 }
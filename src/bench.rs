@@ -0,0 +1,76 @@
+// Not part of the public API. Parsing functions for each response type, exposed only so that the
+// benchmarks can measure parsing the test data without making any requests.
+
+use crate::deserialize::deserialize_xml_string;
+use crate::endpoints::accessory_models::Accessories;
+use crate::endpoints::game_family_models::GameFamilies;
+use crate::endpoints::game_models::{Games, GamesRef};
+use crate::endpoints::hot_list_models::HotList;
+use crate::endpoints::search_models::SearchResults;
+use crate::{
+    AccessoryDetails, Collection, CollectionItem, CollectionItemBrief, CollectionItemRef, Forum,
+    ForumGroup, GameDetails, GameFamily, Guild, HotListGame, Plays, Result, SearchResult, Thread,
+    User,
+};
+
+pub fn parse_accessories(xml: &str) -> Result<Vec<AccessoryDetails>> {
+    Ok(deserialize_xml_string::<Accessories>(xml)?.accessories)
+}
+
+pub fn parse_collection(xml: &str) -> Result<Collection<CollectionItem>> {
+    Ok(deserialize_xml_string(xml)?)
+}
+
+// The borrowed items can't outlive the input, so only the number of items is returned.
+pub fn parse_collection_borrowed(xml: &str) -> Result<usize> {
+    let collection: Collection<CollectionItemRef<'_>> = deserialize_xml_string(xml)?;
+    Ok(collection.items.len())
+}
+
+pub fn parse_collection_brief(xml: &str) -> Result<Collection<CollectionItemBrief>> {
+    Ok(deserialize_xml_string(xml)?)
+}
+
+pub fn parse_forum(xml: &str) -> Result<Forum> {
+    Ok(deserialize_xml_string(xml)?)
+}
+
+pub fn parse_forum_group(xml: &str) -> Result<ForumGroup> {
+    Ok(deserialize_xml_string(xml)?)
+}
+
+pub fn parse_game_families(xml: &str) -> Result<Vec<GameFamily>> {
+    Ok(deserialize_xml_string::<GameFamilies>(xml)?.game_families)
+}
+
+pub fn parse_games(xml: &str) -> Result<Vec<GameDetails>> {
+    Ok(deserialize_xml_string::<Games>(xml)?.games)
+}
+
+pub fn parse_games_borrowed(xml: &str) -> Result<usize> {
+    Ok(deserialize_xml_string::<GamesRef>(xml)?.games.len())
+}
+
+pub fn parse_guild(xml: &str) -> Result<Guild> {
+    Ok(deserialize_xml_string(xml)?)
+}
+
+pub fn parse_hot_list(xml: &str) -> Result<Vec<HotListGame>> {
+    Ok(deserialize_xml_string::<HotList<HotListGame>>(xml)?.items)
+}
+
+pub fn parse_plays(xml: &str) -> Result<Plays> {
+    Ok(deserialize_xml_string(xml)?)
+}
+
+pub fn parse_search_results(xml: &str) -> Result<Vec<SearchResult>> {
+    Ok(deserialize_xml_string::<SearchResults>(xml)?.results)
+}
+
+pub fn parse_thread(xml: &str) -> Result<Thread> {
+    Ok(deserialize_xml_string(xml)?)
+}
+
+pub fn parse_user(xml: &str) -> Result<User> {
+    Ok(deserialize_xml_string(xml)?)
+}
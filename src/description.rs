@@ -0,0 +1,35 @@
+/// Splits a description from boardgamegeek into its paragraphs.
+///
+/// Descriptions use blank lines to separate paragraphs, while single line breaks are used within
+/// a paragraph, for example to list components. Each paragraph is returned with the surrounding
+/// whitespace trimmed, and its single line breaks kept. Lines containing only whitespace, such as
+/// non breaking spaces, count as blank.
+///
+/// ```
+/// let description = "Explore the island.\n\n\nBuild a deck:\n- Cards\n- Workers\n";
+/// assert_eq!(
+///     arnak::description_paragraphs(description),
+///     vec!["Explore the island.", "Build a deck:\n- Cards\n- Workers"],
+/// );
+/// ```
+pub fn description_paragraphs(description: &str) -> Vec<&str> {
+    let mut paragraphs = vec![];
+    // Byte range of the paragraph currently being read, if any.
+    let mut paragraph: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in description.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some((start, end)) = paragraph.take() {
+                paragraphs.push(description[start..end].trim());
+            }
+        } else {
+            let start = paragraph.map_or(offset, |(start, _)| start);
+            paragraph = Some((start, offset + line.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = paragraph {
+        paragraphs.push(description[start..end].trim());
+    }
+    paragraphs
+}
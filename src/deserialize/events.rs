@@ -0,0 +1,795 @@
+// Deserializer over a stream of XML events, used by the quick-xml backend and for the items of
+// streamed responses, which are read as events by xml-rs before being deserialised.
+//
+// This follows the same rules as the serde-xml-rs deserializer, so the models can be used with
+// either unchanged. Attributes are keyed as `@name`, text content as `#text`, and child elements
+// not matching any field as `#content`. Repeated child elements are read as a sequence while they
+// are contiguous.
+//
+// Text is passed on borrowed where the event source borrowed it, so models with borrowed fields can
+// be deserialised without copying.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use serde::de::{Deserialize, DeserializeSeed, IntoDeserializer, Visitor};
+use serde_xml_rs::Error;
+use xml::name::OwnedName;
+use xml::reader::XmlEvent;
+
+type Result<T> = core::result::Result<T, Error>;
+
+// Key used for text content inside an element.
+const TEXT: &str = "#text";
+// Key used for child elements that don't match any of the fields of a struct.
+const CONTENT: &str = "#content";
+
+// Deserializes a value from the events of a single root element.
+pub(super) fn from_events<'de, T: Deserialize<'de>, S: EventSource<'de>>(source: S) -> Result<T> {
+    let mut reader = EventReader::new(source);
+    T::deserialize(ElementDeserializer::root(&mut reader))
+}
+
+// A source of the significant events of an XML document, with text already coalesced, trimmed,
+// and unescaped.
+pub(super) trait EventSource<'a> {
+    // Reads the next event, returning `Event::Eof` once there are no more.
+    fn read_event(&mut self) -> Result<Event<'a>>;
+}
+
+#[derive(Debug)]
+pub(super) enum Event<'a> {
+    StartElement(Element<'a>),
+    Text(Cow<'a, str>),
+    EndElement,
+    Eof,
+}
+impl fmt::Display for Event<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::StartElement(element) => write!(f, "start of element {}", element.name),
+            Event::Text(_) => write!(f, "text"),
+            Event::EndElement => write!(f, "end of element"),
+            Event::Eof => write!(f, "end of input"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Element<'a> {
+    pub(super) name: Cow<'a, str>,
+    pub(super) attributes: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+fn unexpected(expected: &'static str, event: &Event<'_>) -> Error {
+    Error::Unexpected {
+        expected,
+        but_got: event.to_string(),
+    }
+}
+
+// Passes text to a visitor, as a borrowed string if possible.
+fn visit_text<'de, V: Visitor<'de>>(visitor: V, text: Cow<'de, str>) -> Result<V::Value> {
+    match text {
+        Cow::Borrowed(text) => visitor.visit_borrowed_str(text),
+        Cow::Owned(text) => visitor.visit_string(text),
+    }
+}
+
+fn parse_xml_bool(text: &str) -> Result<bool> {
+    match text {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        s => Err(Error::Unexpected {
+            expected: "boolean",
+            but_got: s.to_owned(),
+        }),
+    }
+}
+
+// Event source over events already read by xml-rs, such as the events of a single item read from
+// a streamed response. The events are expected to come from a reader created with the same config
+// as the default backend, so text is already coalesced and trimmed.
+pub(super) struct XmlRsEvents<'a> {
+    events: std::slice::Iter<'a, XmlEvent>,
+}
+
+impl<'a> XmlRsEvents<'a> {
+    pub(super) fn new(events: &'a [XmlEvent]) -> Self {
+        Self {
+            events: events.iter(),
+        }
+    }
+}
+
+impl<'a> EventSource<'a> for XmlRsEvents<'a> {
+    fn read_event(&mut self) -> Result<Event<'a>> {
+        for event in self.events.by_ref() {
+            match event {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => {
+                    return Ok(Event::StartElement(Element {
+                        name: qualified_name(name),
+                        attributes: attributes
+                            .iter()
+                            .map(|attribute| {
+                                (
+                                    qualified_name(&attribute.name),
+                                    Cow::Borrowed(attribute.value.as_str()),
+                                )
+                            })
+                            .collect(),
+                    }));
+                },
+                XmlEvent::EndElement { .. } => return Ok(Event::EndElement),
+                XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                    return Ok(Event::Text(Cow::Borrowed(text)));
+                },
+                XmlEvent::EndDocument => return Ok(Event::Eof),
+                // Whitespace, comments, processing instructions and declarations are ignored.
+                _ => {},
+            }
+        }
+        Ok(Event::Eof)
+    }
+}
+
+// The name of an element or attribute including its prefix, as serde-xml-rs matches them.
+fn qualified_name(name: &OwnedName) -> Cow<'_, str> {
+    match &name.prefix {
+        Some(prefix) => Cow::Owned(format!("{prefix}:{}", name.local_name)),
+        None => Cow::Borrowed(&name.local_name),
+    }
+}
+
+// Reads events from a source, with a single event lookahead.
+struct EventReader<'a, S> {
+    source: S,
+    peeked: Option<Event<'a>>,
+}
+
+impl<'a, S: EventSource<'a>> EventReader<'a, S> {
+    fn new(source: S) -> Self {
+        Self {
+            source,
+            peeked: None,
+        }
+    }
+
+    fn peek(&mut self) -> Result<&Event<'a>> {
+        if self.peeked.is_none() {
+            let event = self.source.read_event()?;
+            self.peeked = Some(event);
+        }
+        Ok(self.peeked.as_ref().expect("peeked event was just set"))
+    }
+
+    fn next(&mut self) -> Result<Event<'a>> {
+        match self.peeked.take() {
+            Some(event) => Ok(event),
+            None => self.source.read_event(),
+        }
+    }
+
+    fn chars(&mut self) -> Result<Cow<'a, str>> {
+        match self.next()? {
+            Event::Text(text) => Ok(text),
+            event => Err(unexpected("text", &event)),
+        }
+    }
+
+    fn start_element(&mut self) -> Result<Element<'a>> {
+        match self.next()? {
+            Event::StartElement(element) => Ok(element),
+            event => Err(unexpected("start of element", &event)),
+        }
+    }
+
+    fn end_element(&mut self) -> Result<()> {
+        match self.next()? {
+            Event::EndElement => Ok(()),
+            event => Err(unexpected("end of element", &event)),
+        }
+    }
+
+    // Consumes the next element, and everything inside it.
+    fn ignore(&mut self) -> Result<()> {
+        self.start_element()?;
+        let mut depth = 1_usize;
+        while depth > 0 {
+            match self.next()? {
+                event @ Event::Eof => return Err(unexpected("anything", &event)),
+                Event::EndElement => depth -= 1,
+                Event::StartElement(_) => depth += 1,
+                Event::Text(_) => {},
+            }
+        }
+        Ok(())
+    }
+}
+
+// Deserializer for an element, or for the content of the current element when there is no
+// element name.
+struct ElementDeserializer<'r, 'a, S> {
+    reader: &'r mut EventReader<'a, S>,
+    element_name: Option<Cow<'a, str>>,
+    is_root: bool,
+}
+
+impl<'r, 'a, S: EventSource<'a>> ElementDeserializer<'r, 'a, S> {
+    fn root(reader: &'r mut EventReader<'a, S>) -> Self {
+        Self {
+            reader,
+            element_name: Some(Cow::Borrowed("")),
+            is_root: true,
+        }
+    }
+
+    fn new(reader: &'r mut EventReader<'a, S>, element_name: Option<Cow<'a, str>>) -> Self {
+        Self {
+            reader,
+            element_name,
+            is_root: false,
+        }
+    }
+
+    fn maybe_start_element(&mut self) -> Result<()> {
+        if self.element_name.is_some() {
+            self.reader.start_element()?;
+        }
+        Ok(())
+    }
+
+    fn maybe_end_element(&mut self) -> Result<()> {
+        if self.element_name.is_some() {
+            self.reader.end_element()?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($deserialize:ident => $visit:ident) => {
+        fn $deserialize<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+            self.maybe_start_element()?;
+            let value = self.reader.chars()?.parse()?;
+            self.maybe_end_element()?;
+            visitor.$visit(value)
+        }
+    };
+}
+
+impl<'de, S: EventSource<'de>> serde::Deserializer<'de> for ElementDeserializer<'_, 'de, S> {
+    type Error = Error;
+
+    deserialize_parsed!(deserialize_i8 => visit_i8);
+    deserialize_parsed!(deserialize_i16 => visit_i16);
+    deserialize_parsed!(deserialize_i32 => visit_i32);
+    deserialize_parsed!(deserialize_i64 => visit_i64);
+    deserialize_parsed!(deserialize_u8 => visit_u8);
+    deserialize_parsed!(deserialize_u16 => visit_u16);
+    deserialize_parsed!(deserialize_u32 => visit_u32);
+    deserialize_parsed!(deserialize_u64 => visit_u64);
+    deserialize_parsed!(deserialize_f32 => visit_f32);
+    deserialize_parsed!(deserialize_f64 => visit_f64);
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::Unsupported("deserialize any"))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        self.maybe_start_element()?;
+        let value = parse_xml_bool(&self.reader.chars()?)?;
+        self.maybe_end_element()?;
+        visitor.visit_bool(value)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        self.maybe_start_element()?;
+        let text = if matches!(self.reader.peek()?, Event::Text(_)) {
+            self.reader.chars()?
+        } else {
+            Cow::Borrowed("")
+        };
+        let value = visit_text(visitor, text)?;
+        self.maybe_end_element()?;
+        Ok(value)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::Unsupported("bytes"))
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        self.maybe_start_element()?;
+        let value =
+            visitor.visit_byte_buf::<Error>(self.reader.chars()?.into_owned().into_bytes())?;
+        self.maybe_end_element()?;
+        Ok(value)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let is_some = if self.element_name.is_some() {
+            matches!(self.reader.peek()?, Event::StartElement(_))
+        } else {
+            !matches!(self.reader.peek()?, Event::EndElement)
+        };
+        if is_some {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.element_name.is_none() || self.is_root {
+            visitor.visit_unit()
+        } else {
+            self.reader.start_element()?;
+            let value = visitor.visit_unit::<Error>()?;
+            self.reader.end_element()?;
+            Ok(value)
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.is_root {
+            return Err(Error::Unsupported("sequence in document root"));
+        }
+        visitor.visit_seq(SeqAccess {
+            reader: self.reader,
+            element_name: self.element_name,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(mut self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.maybe_start_element()?;
+        let text = self.reader.chars()?;
+        let value = visitor.visit_seq(TextDeserializer { text })?;
+        self.maybe_end_element()?;
+        Ok(value)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value> {
+        self.maybe_start_element()?;
+        let value = visitor.visit_map(MapAccess::new(self.reader, vec![], &[]))?;
+        self.maybe_end_element()?;
+        Ok(value)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let element = self.reader.start_element()?;
+        let value = visitor.visit_map(MapAccess::new(self.reader, element.attributes, fields))?;
+        self.reader.end_element()?;
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        mut self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.maybe_start_element()?;
+        let value = visitor.visit_enum(EnumAccess {
+            reader: self.reader,
+        })?;
+        self.maybe_end_element()?;
+        Ok(value)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.reader.ignore()?;
+        visitor.visit_unit()
+    }
+}
+
+struct MapAccess<'r, 'a, S> {
+    reader: &'r mut EventReader<'a, S>,
+    attributes: std::iter::Peekable<std::vec::IntoIter<(Cow<'a, str>, Cow<'a, str>)>>,
+    fields: &'static [&'static str],
+}
+
+impl<'r, 'a, S: EventSource<'a>> MapAccess<'r, 'a, S> {
+    fn new(
+        reader: &'r mut EventReader<'a, S>,
+        attributes: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+        fields: &'static [&'static str],
+    ) -> Self {
+        Self {
+            reader,
+            attributes: attributes.into_iter().peekable(),
+            fields,
+        }
+    }
+}
+
+// Calls `f` with the key used for an attribute, its name prefixed with `@`. The key is built on the
+// stack for all but very long names, as this is done for every attribute.
+fn with_attribute_key<T>(name: &str, f: impl FnOnce(&str) -> T) -> T {
+    let mut buffer = [0; 64];
+    match buffer.get_mut(1..=name.len()) {
+        Some(key_name) => {
+            key_name.copy_from_slice(name.as_bytes());
+            buffer[0] = b'@';
+            f(std::str::from_utf8(&buffer[..=name.len()]).expect("key is built from valid UTF-8"))
+        },
+        None => f(&format!("@{name}")),
+    }
+}
+
+fn is_content(fields: &[&str], element_name: &str) -> bool {
+    !fields.contains(&element_name) && fields.contains(&CONTENT)
+}
+
+impl<'de, S: EventSource<'de>> serde::de::MapAccess<'de> for MapAccess<'_, 'de, S> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if let Some((name, _)) = self.attributes.peek() {
+            return with_attribute_key(name, |key| {
+                seed.deserialize(IntoDeserializer::<Error>::into_deserializer(key))
+            })
+            .map(Some);
+        }
+        let key = match self.reader.peek()? {
+            Event::StartElement(element) => {
+                if is_content(self.fields, &element.name) {
+                    CONTENT
+                } else {
+                    &element.name
+                }
+            },
+            Event::Text(_) => TEXT,
+            _ => return Ok(None),
+        };
+        seed.deserialize(IntoDeserializer::<Error>::into_deserializer(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        if let Some((_, value)) = self.attributes.next() {
+            return seed.deserialize(TextDeserializer { text: value });
+        }
+        match self.reader.peek()? {
+            Event::StartElement(element) => {
+                let element_name = if is_content(self.fields, &element.name) {
+                    None
+                } else {
+                    Some(element.name.clone())
+                };
+                seed.deserialize(ElementDeserializer::new(self.reader, element_name))
+            },
+            Event::Text(_) => {
+                let text = self.reader.chars()?;
+                seed.deserialize(TextDeserializer { text })
+            },
+            event => Err(unexpected("start of element or text", event)),
+        }
+    }
+}
+
+struct SeqAccess<'r, 'a, S> {
+    reader: &'r mut EventReader<'a, S>,
+    element_name: Option<Cow<'a, str>>,
+}
+
+impl<'de, S: EventSource<'de>> serde::de::SeqAccess<'de> for SeqAccess<'_, 'de, S> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        match (&self.element_name, self.reader.peek()?) {
+            (Some(element_name), Event::StartElement(element)) if &element.name == element_name => {
+                let element_name = Some(element_name.clone());
+                seed.deserialize(ElementDeserializer::new(self.reader, element_name))
+                    .map(Some)
+            },
+            (None, Event::StartElement(_)) => seed
+                .deserialize(ElementDeserializer::new(self.reader, None))
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+struct EnumAccess<'r, 'a, S> {
+    reader: &'r mut EventReader<'a, S>,
+}
+
+impl<'de, 'r, S: EventSource<'de>> serde::de::EnumAccess<'de> for EnumAccess<'r, 'de, S> {
+    type Error = Error;
+    type Variant = VariantAccess<'r, 'de, S>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let element_name = match self.reader.peek()? {
+            Event::StartElement(element) => element.name.clone(),
+            Event::Text(_) => Cow::Borrowed(TEXT),
+            event => return Err(unexpected("start of element", event)),
+        };
+        let variant = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(
+            element_name.as_ref(),
+        ))?;
+        Ok((
+            variant,
+            VariantAccess {
+                reader: self.reader,
+                element_name,
+            },
+        ))
+    }
+}
+
+struct VariantAccess<'r, 'a, S> {
+    reader: &'r mut EventReader<'a, S>,
+    element_name: Cow<'a, str>,
+}
+
+impl<'de, S: EventSource<'de>> serde::de::VariantAccess<'de> for VariantAccess<'_, 'de, S> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        self.reader.start_element()?;
+        self.reader.end_element()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        if self.element_name == TEXT {
+            let text = self.reader.chars()?;
+            seed.deserialize(TextDeserializer { text })
+        } else {
+            seed.deserialize(ElementDeserializer::new(
+                self.reader,
+                Some(self.element_name),
+            ))
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.reader.start_element()?;
+        let text = self.reader.chars()?;
+        let value = visitor.visit_seq(TextDeserializer { text })?;
+        self.reader.end_element()?;
+        Ok(value)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let element = self.reader.start_element()?;
+        let value = visitor.visit_map(MapAccess::new(self.reader, element.attributes, fields))?;
+        self.reader.end_element()?;
+        Ok(value)
+    }
+}
+
+// Deserializer for plain text, such as attribute values.
+struct TextDeserializer<'a> {
+    text: Cow<'a, str>,
+}
+
+macro_rules! deserialize_text_parsed {
+    ($deserialize:ident => $visit:ident) => {
+        fn $deserialize<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+            visitor.$visit(self.text.parse()?)
+        }
+    };
+}
+
+impl<'de> serde::Deserializer<'de> for TextDeserializer<'de> {
+    type Error = Error;
+
+    deserialize_text_parsed!(deserialize_i8 => visit_i8);
+    deserialize_text_parsed!(deserialize_i16 => visit_i16);
+    deserialize_text_parsed!(deserialize_i32 => visit_i32);
+    deserialize_text_parsed!(deserialize_i64 => visit_i64);
+    deserialize_text_parsed!(deserialize_u8 => visit_u8);
+    deserialize_text_parsed!(deserialize_u16 => visit_u16);
+    deserialize_text_parsed!(deserialize_u32 => visit_u32);
+    deserialize_text_parsed!(deserialize_u64 => visit_u64);
+    deserialize_text_parsed!(deserialize_f32 => visit_f32);
+    deserialize_text_parsed!(deserialize_f64 => visit_f64);
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(parse_xml_bool(&self.text)?)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visit_text(visitor, self.text)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::Unsupported("bytes"))
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_byte_buf(self.text.into_owned().into_bytes())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(Error::Unsupported("map in text"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value> {
+        Err(Error::Unsupported("struct in text"))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+impl<'de> serde::de::EnumAccess<'de> for TextDeserializer<'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let variant = seed.deserialize(IntoDeserializer::<Error>::into_deserializer(
+            self.text.as_ref(),
+        ))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> serde::de::VariantAccess<'de> for TextDeserializer<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, _visitor: V) -> Result<V::Value> {
+        Err(Error::Unsupported("tuple variant in text"))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value> {
+        Err(Error::Unsupported("struct variant in text"))
+    }
+}
+
+impl<'de> serde::de::SeqAccess<'de> for TextDeserializer<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.text.is_empty() {
+            return Ok(None);
+        }
+        let next = match &mut self.text {
+            Cow::Borrowed(text) => {
+                let (next, remaining) = text.split_once(' ').unwrap_or((text, ""));
+                *text = remaining;
+                Cow::Borrowed(next)
+            },
+            Cow::Owned(text) => {
+                let (next, remaining) = text.split_once(' ').unwrap_or((text, ""));
+                let next = next.to_owned();
+                *text = remaining.to_owned();
+                Cow::Owned(next)
+            },
+        };
+        seed.deserialize(TextDeserializer { text: next }).map(Some)
+    }
+}
@@ -1,26 +1,179 @@
 use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, ParseError, Utc};
 use serde::Deserialize;
 
-use crate::{ItemFamilyRank, ItemType, NameType, RankValue, RatingValue};
+use crate::{ItemFamilyRank, ItemType, NameType, RankId, RankValue, RatingValue};
 
-pub(crate) fn deserialize_xml_string<T: serde::de::DeserializeOwned>(
-    xml: &str,
+mod events;
+mod html_entities;
+#[cfg(feature = "quick-xml")]
+mod quick_reader;
+
+use std::borrow::Cow;
+
+use html_entities::HTML_ENTITIES;
+
+// Entities that are predefined by the XML spec, and so are always understood by the XML readers.
+const XML_ENTITIES: &[&str] = &["amp", "apos", "gt", "lt", "quot"];
+
+#[cfg(not(feature = "quick-xml"))]
+pub(crate) fn deserialize_xml_string<'de, T: Deserialize<'de>>(
+    xml: &'de str,
+) -> core::result::Result<T, serde_xml_rs::Error> {
+    // Registering every HTML entity with the reader is slow, so they are replaced with character
+    // references beforehand instead.
+    let xml = html_entities_to_char_references(xml);
+    let xml_reader = xml_reader_config().create_reader(xml.as_bytes());
+    let mut deserializer = serde_xml_rs::Deserializer::new(xml_reader);
+    T::deserialize(&mut deserializer)
+}
+
+#[cfg(feature = "quick-xml")]
+pub(crate) fn deserialize_xml_string<'de, T: Deserialize<'de>>(
+    xml: &'de str,
 ) -> core::result::Result<T, serde_xml_rs::Error> {
+    quick_reader::from_str(xml)
+}
+
+// Deserializes a value from the events of a single element, as read by a reader created with
+// `streaming_xml_reader_config`.
+pub(crate) fn deserialize_xml_events<'de, T: Deserialize<'de>>(
+    xml_events: &'de [xml::reader::XmlEvent],
+) -> core::result::Result<T, serde_xml_rs::Error> {
+    events::from_events(events::XmlRsEvents::new(xml_events))
+}
+
+// The XML reader config used when parsing responses from the API.
+//
+// This only understands the entities predefined by XML, so any HTML entities used by boardgamegeek
+// must be replaced before parsing with this config.
+pub(crate) fn xml_reader_config() -> xml::ParserConfig {
     // The parser config used by serde_xml
-    let default_xml_reader_config = xml::ParserConfig::new()
+    xml::ParserConfig::new()
         .trim_whitespace(true)
         .whitespace_to_characters(true)
         .cdata_to_characters(true)
         .ignore_comments(true)
-        .coalesce_characters(true);
-    // Not allowed by the default XML spec, so the underlying XML reader will return an error
-    // while trying to deserialise. But this is used by boardgamegeek in the descriptions so
-    // we need to add it here.
-    let xml_reader_config = default_xml_reader_config.add_entity("mdash", "—");
+        .coalesce_characters(true)
+}
 
-    let xml_reader = xml::reader::EventReader::new_with_config(xml.as_bytes(), xml_reader_config);
-    let mut deserializer = serde_xml_rs::Deserializer::new(xml_reader);
-    T::deserialize(&mut deserializer)
+// The XML reader config used when a response is parsed as it is received, so HTML entities can't be
+// replaced beforehand. Instead every HTML entity is registered with the reader, which makes this
+// comparatively expensive to create.
+pub(crate) fn streaming_xml_reader_config() -> xml::ParserConfig {
+    xml_reader_config().add_entities(
+        HTML_ENTITIES
+            .iter()
+            .filter(|(name, _)| !XML_ENTITIES.contains(name))
+            .copied(),
+    )
+}
+
+// Looks up the characters for a named HTML entity, such as `ndash` or `hellip`.
+fn resolve_html_entity(name: &str) -> Option<&'static str> {
+    HTML_ENTITIES
+        .binary_search_by(|(entity, _)| (*entity).cmp(name))
+        .ok()
+        .map(|index| HTML_ENTITIES[index].1)
+}
+
+// Resolves a named entity, either one of the predefined XML entities or any of the HTML entities
+// used by boardgamegeek.
+#[cfg(feature = "quick-xml")]
+fn resolve_entity(name: &str) -> Option<&'static str> {
+    quick_xml::escape::resolve_xml_entity(name).or_else(|| resolve_html_entity(name))
+}
+
+// Calls `on_reference` for each entity or character reference found in the text, with the byte
+// range of the reference including the `&` and `;` and the characters it represents. References
+// that aren't known or aren't valid are skipped.
+fn for_each_html_reference(text: &str, mut on_reference: impl FnMut(usize, usize, Reference)) {
+    let mut search_from = 0;
+    while let Some(offset) = text[search_from..].find('&') {
+        let start = search_from + offset;
+        search_from = start + 1;
+        // The longest named entity is 31 characters, so there is no need to look any further.
+        let Some(length) = text[search_from..]
+            .bytes()
+            .take(33)
+            .position(|byte| byte == b';')
+        else {
+            continue;
+        };
+        let end = search_from + length + 1;
+        let name = &text[search_from..end - 1];
+        let reference = if let Some(number) = name.strip_prefix('#') {
+            let code_point = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16),
+                None => number.parse::<u32>(),
+            };
+            match code_point.ok().and_then(char::from_u32) {
+                Some(c) if c != '\0' => Reference::Char(c),
+                _ => continue,
+            }
+        } else {
+            match resolve_html_entity(name) {
+                Some(value) => Reference::Named(value),
+                None => continue,
+            }
+        };
+        on_reference(start, end, reference);
+        search_from = end;
+    }
+}
+
+enum Reference {
+    Char(char),
+    Named(&'static str),
+}
+
+// Replaces all named HTML entities in an XML document that aren't also XML entities with the
+// equivalent character references, so that the document can be read by an XML parser.
+pub(crate) fn html_entities_to_char_references(xml: &str) -> Cow<'_, str> {
+    use std::fmt::Write;
+
+    let mut replaced = String::new();
+    let mut copied_up_to = 0;
+    for_each_html_reference(xml, |start, end, reference| {
+        let Reference::Named(value) = reference else {
+            return;
+        };
+        if XML_ENTITIES.contains(&&xml[start + 1..end - 1]) {
+            return;
+        }
+        replaced.push_str(&xml[copied_up_to..start]);
+        for c in value.chars() {
+            let _ = write!(replaced, "&#x{:x};", u32::from(c));
+        }
+        copied_up_to = end;
+    });
+    if copied_up_to == 0 {
+        return Cow::Borrowed(xml);
+    }
+    replaced.push_str(&xml[copied_up_to..]);
+    Cow::Owned(replaced)
+}
+
+// Decodes all named and numeric HTML entities in some text. The text is returned unchanged if it
+// doesn't contain any.
+//
+// Descriptions from boardgamegeek are escaped twice, so after the XML has been parsed they still
+// contain entities such as `&amp;ndash;` or `&#10;`.
+pub(crate) fn decode_html_entities(text: Cow<'_, str>) -> Cow<'_, str> {
+    let mut decoded = String::new();
+    let mut copied_up_to = 0;
+    for_each_html_reference(&text, |start, end, reference| {
+        decoded.push_str(&text[copied_up_to..start]);
+        match reference {
+            Reference::Char(c) => decoded.push(c),
+            Reference::Named(value) => decoded.push_str(value),
+        }
+        copied_up_to = end;
+    });
+    if copied_up_to == 0 {
+        return text;
+    }
+    decoded.push_str(&text[copied_up_to..]);
+    Cow::Owned(decoded)
 }
 
 // Types that only exist as intermediary values when deserialising more complex types.
@@ -73,6 +226,70 @@ pub(crate) struct XmlName {
     pub(crate) value: String,
 }
 
+// The same as `XmlName`, but with the value borrowed from the input where possible.
+#[derive(Debug, Deserialize)]
+pub(crate) struct XmlBorrowedName<'a> {
+    #[serde(rename = "@type")]
+    pub(crate) name_type: NameType,
+    #[serde(borrow, rename = "@value")]
+    pub(crate) value: BorrowedStr<'a>,
+}
+
+// A string that is borrowed from the input where possible, and only copied if it had to be
+// unescaped. Deserialising a `Cow<str>` directly always copies, unless it is a field of a derived
+// struct marked with `#[serde(borrow)]`.
+#[derive(Debug)]
+pub(crate) struct BorrowedStr<'a>(pub(crate) Cow<'a, str>);
+
+impl<'de: 'a, 'a> Deserialize<'de> for BorrowedStr<'a> {
+    fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BorrowedStrVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BorrowedStrVisitor {
+            type Value = BorrowedStr<'de>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: serde::de::Error>(
+                self,
+                v: &'de str,
+            ) -> Result<Self::Value, E> {
+                Ok(BorrowedStr(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(BorrowedStr(Cow::Owned(v.to_owned())))
+            }
+
+            fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(BorrowedStr(Cow::Owned(v)))
+            }
+        }
+
+        deserializer.deserialize_str(BorrowedStrVisitor)
+    }
+}
+
+pub(crate) fn deserialize_borrowed_str<'de: 'a, 'a, D>(
+    deserializer: D,
+) -> Result<Cow<'a, str>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    BorrowedStr::deserialize(deserializer).map(|s| s.0)
+}
+
+pub(crate) fn deserialize_maybe_borrowed_str<'de: 'a, 'a, D>(
+    deserializer: D,
+) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<BorrowedStr>::deserialize(deserializer).map(|s| s.map(|s| s.0))
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct XmlLink {
     #[serde(rename = "@type")]
@@ -81,15 +298,18 @@ pub(crate) struct XmlLink {
     pub(crate) id: u64,
     #[serde(rename = "@value")]
     pub(crate) value: String,
+    // Set to true when the link is from the linked item to this one, otherwise left out.
+    #[serde(default, rename = "@inbound")]
+    pub(crate) inbound: bool,
 }
 
 pub(crate) fn deserialize_1_0_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    let s = BorrowedStr::deserialize(deserializer)?.0;
 
-    match s.as_str() {
+    match s.as_ref() {
         "1" => Ok(true),
         "0" => Ok(false),
         _ => Err(serde::de::Error::unknown_variant(&s, &["1", "0"])),
@@ -100,7 +320,7 @@ pub(crate) fn deserialize_minutes<'de, D>(deserializer: D) -> Result<Duration, D
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    let s = BorrowedStr::deserialize(deserializer)?.0;
     // Parse as unsigned because although Duration supports negative durations,
     // we do not want to support that for game playing time.
     let minutes = s.parse::<u32>().map_err(|e| {
@@ -126,7 +346,7 @@ pub(crate) fn deserialize_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
+    let s = BorrowedStr::deserialize(deserializer)?.0;
     NaiveDate::parse_from_str(&s, DATE_FORMAT).map_err(serde::de::Error::custom)
 }
 
@@ -134,7 +354,7 @@ pub(crate) fn deserialize_date_time<'de, D>(deserializer: D) -> Result<DateTime<
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
+    let s = BorrowedStr::deserialize(deserializer)?.0;
     let dt =
         NaiveDateTime::parse_from_str(&s, DATE_TIME_FORMAT).map_err(serde::de::Error::custom)?;
     Ok(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
@@ -151,7 +371,7 @@ pub(crate) fn deserialize_date_time_with_zone<'de, D>(
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
+    let s = BorrowedStr::deserialize(deserializer)?.0;
     let date_time = DateTime::parse_from_str(&s, DATE_TIME_ZONE_LONG_FORMAT)
         .map_err(serde::de::Error::custom)?;
     Ok(DateTime::<Utc>::from(date_time))
@@ -163,7 +383,7 @@ pub(crate) fn deserialize_maybe_date_time_with_zone<'de, D>(
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
+    let s = BorrowedStr::deserialize(deserializer)?.0;
     if s.is_empty() {
         return Ok(None);
     }
@@ -209,22 +429,10 @@ pub(crate) fn xml_ranks_to_ranks<'de, D: serde::de::MapAccess<'de>>(
                 if rank.is_some() {
                     return Err(serde::de::Error::duplicate_field("rank type=\"subtype\""));
                 }
-                rank = Some(ItemFamilyRank {
-                    id: family_rank.id,
-                    name: family_rank.name,
-                    friendly_name: family_rank.friendly_name,
-                    value: family_rank.value,
-                    bayesian_average: family_rank.bayesian_average,
-                });
+                rank = Some(ItemFamilyRank::from(family_rank));
             },
             ItemFamilyType::Family => {
-                sub_family_ranks.push(ItemFamilyRank {
-                    id: family_rank.id,
-                    name: family_rank.name,
-                    friendly_name: family_rank.friendly_name,
-                    value: family_rank.value,
-                    bayesian_average: family_rank.bayesian_average,
-                });
+                sub_family_ranks.push(ItemFamilyRank::from(family_rank));
             },
         }
     }
@@ -265,3 +473,15 @@ pub(crate) struct XmlItemFamilyRank {
     #[serde(rename = "@bayesaverage")]
     pub(crate) bayesian_average: RatingValue,
 }
+
+impl From<XmlItemFamilyRank> for ItemFamilyRank {
+    fn from(family_rank: XmlItemFamilyRank) -> Self {
+        Self {
+            id: RankId(family_rank.id),
+            name: family_rank.name,
+            friendly_name: family_rank.friendly_name,
+            value: family_rank.value,
+            bayesian_average: family_rank.bayesian_average,
+        }
+    }
+}
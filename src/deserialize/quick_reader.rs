@@ -0,0 +1,281 @@
+// Event source backed by quick-xml, enabled with the `quick-xml` feature.
+//
+// Whitespace is trimmed from text, and text, CDATA and entities are coalesced, as with the xml-rs
+// parser config used by the default backend.
+//
+// Unlike serde-xml-rs, text is borrowed from the input where it doesn't contain any escapes, so
+// models with borrowed fields can be deserialised without copying.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use quick_xml::events::attributes::Attributes;
+use quick_xml::events::{BytesRef, BytesStart, Event as XmlEvent};
+use quick_xml::Reader;
+use serde::de::Deserialize;
+use serde_xml_rs::Error;
+
+use super::events::{from_events, Element, Event, EventSource};
+use super::resolve_entity;
+
+type Result<T> = core::result::Result<T, Error>;
+
+pub(crate) fn from_str<'de, T: Deserialize<'de>>(xml: &'de str) -> Result<T> {
+    from_events(QuickXmlEvents::new(xml))
+}
+
+fn xml_error(error: impl fmt::Display) -> Error {
+    Error::Custom(error.to_string())
+}
+
+fn is_xml_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+// Trims whitespace from some text, without copying it if it was borrowed.
+fn trim_xml_whitespace(text: Cow<'_, str>) -> Cow<'_, str> {
+    match text {
+        Cow::Borrowed(text) => Cow::Borrowed(text.trim_matches(is_xml_whitespace)),
+        Cow::Owned(text) => {
+            let trimmed = text.trim_matches(is_xml_whitespace);
+            if trimmed.len() == text.len() {
+                Cow::Owned(text)
+            } else {
+                Cow::Owned(trimmed.to_owned())
+            }
+        },
+    }
+}
+
+// Reads significant events from the underlying quick-xml reader.
+struct QuickXmlEvents<'a> {
+    input: &'a str,
+    reader: Reader<&'a [u8]>,
+    // An event read while collecting text, to be handled next.
+    pending: Option<XmlEvent<'a>>,
+    depth: usize,
+}
+
+impl<'a> QuickXmlEvents<'a> {
+    fn new(xml: &'a str) -> Self {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().expand_empty_elements = true;
+        Self {
+            input: xml,
+            reader,
+            pending: None,
+            depth: 0,
+        }
+    }
+}
+
+impl<'a> EventSource<'a> for QuickXmlEvents<'a> {
+    fn read_event(&mut self) -> Result<Event<'a>> {
+        let mut text: Option<Cow<'a, str>> = None;
+        loop {
+            let event = match self.pending.take() {
+                Some(event) => event,
+                None => self.reader.read_event().map_err(xml_error)?,
+            };
+            // Text, CDATA and references are all coalesced into a single text event.
+            let content = match &event {
+                XmlEvent::Text(text) => Some(text.xml10_content().map_err(xml_error)?),
+                XmlEvent::CData(cdata) => Some(cdata.xml10_content().map_err(xml_error)?),
+                XmlEvent::GeneralRef(reference) => Some(resolve_reference(reference)?),
+                _ => None,
+            };
+            if let Some(content) = content {
+                text = Some(match text.take() {
+                    None => content,
+                    Some(text) => Cow::Owned(text.into_owned() + &content),
+                });
+                continue;
+            }
+            if let Some(text) = text.take() {
+                let text = trim_xml_whitespace(text);
+                if self.depth > 0 && !text.is_empty() {
+                    self.pending = Some(event);
+                    return Ok(Event::Text(text));
+                }
+            }
+            match event {
+                XmlEvent::Start(start) => {
+                    self.depth += 1;
+                    return Ok(Event::StartElement(element(self.input, &start)?));
+                },
+                XmlEvent::End(_) => {
+                    self.depth -= 1;
+                    return Ok(Event::EndElement);
+                },
+                XmlEvent::Eof => return Ok(Event::Eof),
+                // Empty elements are expanded into a start and end by the reader. Declarations,
+                // comments, processing instructions and doctypes are ignored.
+                _ => {},
+            }
+        }
+    }
+}
+
+fn resolve_reference(reference: &BytesRef<'_>) -> Result<Cow<'static, str>> {
+    if let Some(c) = reference.resolve_char_ref().map_err(xml_error)? {
+        return Ok(Cow::Owned(c.to_string()));
+    }
+    let name = reference.decode().map_err(xml_error)?;
+    resolve_entity(&name)
+        .map(Cow::Borrowed)
+        .ok_or_else(|| Error::Custom(format!("unknown entity `&{name};`")))
+}
+
+fn element<'a>(input: &'a str, start: &BytesStart<'_>) -> Result<Element<'a>> {
+    let name = start.name();
+    let name_length = name.as_ref().len();
+    // The name and attributes are read from the input directly where possible, so that they can
+    // borrow from the input rather than from the event.
+    let Some(buffer) = find_in_input(input, start) else {
+        let name = String::from_utf8_lossy(name.as_ref()).into_owned();
+        let attributes = attributes(start.attributes())?
+            .into_iter()
+            .map(|(key, value)| (Cow::Owned(key.into_owned()), Cow::Owned(value.into_owned())))
+            .collect();
+        return Ok(Element {
+            name: Cow::Owned(name),
+            attributes,
+        });
+    };
+    Ok(Element {
+        name: Cow::Borrowed(&buffer[..name_length]),
+        attributes: attributes(Attributes::new(buffer, name_length))?,
+    })
+}
+
+// Returns the same bytes from the input, if they were borrowed from it, so that they have the
+// lifetime of the input.
+fn find_in_input<'a>(input: &'a str, bytes: &[u8]) -> Option<&'a str> {
+    let offset = bytes.as_ptr().addr().checked_sub(input.as_ptr().addr())?;
+    input.get(offset..offset + bytes.len())
+}
+
+fn attributes(attributes: Attributes<'_>) -> Result<Vec<(Cow<'_, str>, Cow<'_, str>)>> {
+    let mut values = vec![];
+    for attribute in attributes {
+        let attribute = attribute.map_err(xml_error)?;
+        let key = String::from_utf8_lossy(attribute.key.0);
+        let raw_value = match attribute.value {
+            Cow::Borrowed(value) => Cow::Borrowed(std::str::from_utf8(value).map_err(xml_error)?),
+            Cow::Owned(value) => Cow::Owned(String::from_utf8(value).map_err(xml_error)?),
+        };
+        // Attribute values are normalised by replacing literal whitespace characters with spaces,
+        // before references are resolved.
+        let value = if raw_value.contains(['\t', '\n', '\r']) {
+            let normalised = raw_value
+                .replace("\r\n", " ")
+                .replace(['\t', '\n', '\r'], " ");
+            Cow::Owned(unescape(&normalised)?.into_owned())
+        } else {
+            match raw_value {
+                Cow::Borrowed(raw_value) => unescape(raw_value)?,
+                Cow::Owned(raw_value) => Cow::Owned(unescape(&raw_value)?.into_owned()),
+            }
+        };
+        values.push((key, value));
+    }
+    Ok(values)
+}
+
+fn unescape(raw_value: &str) -> Result<Cow<'_, str>> {
+    quick_xml::escape::unescape_with(raw_value, resolve_entity).map_err(xml_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        #[serde(rename = "@id")]
+        id: u64,
+        #[serde(default, rename = "#text")]
+        text: String,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Items {
+        #[serde(default, rename = "item")]
+        items: Vec<Item>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Content {
+        #[serde(rename = "#content")]
+        items: Vec<Item>,
+    }
+
+    #[test]
+    fn sequence() {
+        let items: Items =
+            from_str(r#"<items><item id="1">first</item><item id="2">second</item></items>"#)
+                .unwrap();
+        assert_eq!(
+            items,
+            Items {
+                items: vec![
+                    Item {
+                        id: 1,
+                        text: "first".to_owned(),
+                    },
+                    Item {
+                        id: 2,
+                        text: "second".to_owned(),
+                    },
+                ],
+            },
+        );
+
+        let items: Items = from_str("<items></items>").unwrap();
+        assert_eq!(items, Items { items: vec![] });
+    }
+
+    #[test]
+    fn sequence_malformed_element() {
+        // An element that fails to parse is an error, rather than ending the sequence early.
+        let items = from_str::<Content>(
+            r#"<items><item id="1">first</item><item id="oops">second</item></items>"#,
+        );
+        assert!(items.is_err(), "expected error but got {items:?}");
+
+        let items = from_str::<Items>(
+            r#"<items><item id="1">first</item><item id="oops">second</item></items>"#,
+        );
+        assert!(items.is_err(), "expected error but got {items:?}");
+    }
+
+    #[test]
+    fn entities() {
+        let item: Item =
+            from_str(r#"<item id="1">Fish &amp; Chips &#233; &ndash; done</item>"#).unwrap();
+        assert_eq!(item.text, "Fish & Chips é – done");
+    }
+
+    #[test]
+    fn cdata() {
+        let item: Item =
+            from_str(r#"<item id="1"> before <![CDATA[<b>&amp;</b>]]> after </item>"#).unwrap();
+        assert_eq!(item.text, "before <b>&amp;</b> after");
+    }
+
+    #[test]
+    fn borrows_unescaped_text() {
+        #[derive(Deserialize)]
+        struct Borrowed<'a> {
+            #[serde(borrow, rename = "#text")]
+            text: Cow<'a, str>,
+        }
+
+        let borrowed: Borrowed = from_str("<item>plain</item>").unwrap();
+        assert!(matches!(borrowed.text, Cow::Borrowed("plain")));
+        let owned: Borrowed = from_str("<item>a &amp; b</item>").unwrap();
+        assert_eq!(owned.text, "a & b");
+    }
+}
@@ -1,6 +1,8 @@
-use super::ItemType;
+use super::{parse_raw_items, ItemType, RawItem};
+use crate::api::parse_response_with;
 use crate::{
-    Accessories, AccessoryDetails, BoardGameGeekApi, Error, IntoQueryParam, QueryParam, Result,
+    Accessories, AccessoryDetails, AccessoryId, BoardGameGeekApi, Error, IntoQueryParam,
+    QueryParam, Result,
 };
 
 /// All optional query parameters for making a request to the accessory endpoint.
@@ -87,14 +89,14 @@ impl AccessoryQueryParams {
 // Struct for building a query for the request to the accessory endpoint.
 #[derive(Clone, Debug)]
 struct AccessoryQueryBuilder<'builder> {
-    game_ids: &'builder [u64],
+    game_ids: &'builder [AccessoryId],
     params: &'builder AccessoryQueryParams,
 }
 
 impl<'builder> AccessoryQueryBuilder<'builder> {
     // Constructs a new query builder from a list of IDs to request, and the rest of the
     // parameters.
-    fn new(game_ids: &'builder [u64], params: &'builder AccessoryQueryParams) -> Self {
+    fn new(game_ids: &'builder [AccessoryId], params: &'builder AccessoryQueryParams) -> Self {
         Self { game_ids, params }
     }
 
@@ -150,7 +152,7 @@ impl<'api> AccessoryApi<'api> {
     /// Searches for a board game accessory by a given ID.
     pub async fn get_by_id(
         &self,
-        id: u64,
+        id: AccessoryId,
         query_params: &AccessoryQueryParams,
     ) -> Result<AccessoryDetails> {
         let ids = &[id];
@@ -171,7 +173,7 @@ impl<'api> AccessoryApi<'api> {
     /// Searches for board games accessories by given IDs.
     pub async fn get_by_ids(
         &self,
-        ids: &[u64],
+        ids: &[AccessoryId],
         query_params: &AccessoryQueryParams,
     ) -> Result<Vec<AccessoryDetails>> {
         let query = AccessoryQueryBuilder::new(ids, query_params);
@@ -181,25 +183,56 @@ impl<'api> AccessoryApi<'api> {
 
         Ok(accessories.accessories)
     }
+
+    /// Searches for board game accessories by given IDs, returning each as a generic [`RawItem`]
+    /// rather than [`AccessoryDetails`].
+    ///
+    /// This can be used to read any data in the response that [`AccessoryDetails`] leaves out.
+    pub async fn get_raw_items_by_ids(
+        &self,
+        ids: &[AccessoryId],
+        query_params: &AccessoryQueryParams,
+    ) -> Result<Vec<RawItem>> {
+        let query = AccessoryQueryBuilder::new(ids, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let text = self.api.execute_raw_request(request).await?;
+
+        parse_response_with(&text, parse_raw_items)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{DateTime, NaiveDate, Utc};
     use mockito::Matcher;
 
     use crate::{
-        AccessoryDetails, AccessoryQueryParams, AccessoryVersion, BoardGameGeekApi, Game,
-        GameArtist, GameDesigner, GamePublisher, ItemCondition, MarketplaceListing, Price,
-        RatingComment, RatingCommentPage,
+        AccessoryDetails, AccessoryId, AccessoryQueryParams, AccessoryVersion, BoardGameGeekApi,
+        Currency, Decimal, Domain, Game, GameArtist, GameDesigner, GameId, GamePublisher,
+        ItemCondition, ItemLink, ItemType, LinkDirection, MarketplaceListing, ObjectId, PersonId,
+        Price, PublisherId, RatingComment, RatingCommentPage, StaticTokenProvider, VersionId,
     };
 
+    fn item_link(link_type: ItemType, id: u64, name: &str, direction: LinkDirection) -> ItemLink {
+        ItemLink {
+            link_type,
+            id: ObjectId(id),
+            name: name.to_owned(),
+            direction,
+        }
+    }
+
     #[tokio::test]
     async fn get_by_id() {
         let mut server = mockito::Server::new_async().await;
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -218,7 +251,7 @@ mod tests {
 
         let accessory = api
             .accessory()
-            .get_by_id(196_929, &AccessoryQueryParams::new())
+            .get_by_id(AccessoryId(196_929), &AccessoryQueryParams::new())
             .await;
         mock.assert_async().await;
 
@@ -228,7 +261,7 @@ mod tests {
         assert_eq!(
             accessory,
             AccessoryDetails {
-                id: 196_929,
+                id: AccessoryId(196_929),
                 name: "7 Wonders: Metal Coins".to_owned(),
                 alternate_names: vec!["7 Wonders: Wondrous Metal Coins".to_owned()],
                 description: "These high-quality metal coins replace the cardboard coins contained in the 7 Wonders core game and the 7 Wonders: Leaders expansion.".to_owned(),
@@ -236,14 +269,20 @@ mod tests {
                 thumbnail: Some("https://cf.geekdo-images.com/fIVmsro-RGJVBQzWlDy3Jw__small/img/GryodmBvp6vGamX_K2kZ1nuM-C0=/fit-in/200x150/filters:strip_icc()/pic7149814.jpg".to_owned()),
                 year_published: 0,
                 accessory_for: vec![
-                    Game { id: 68448, name: "7 Wonders".to_owned() },
-                    Game { id: 316377, name: "7 Wonders (Second Edition)".to_owned() },
+                    Game { id: GameId(68448), name: "7 Wonders".to_owned() },
+                    Game { id: GameId(316_377), name: "7 Wonders (Second Edition)".to_owned() },
                 ],
                 designers: vec![],
                 artists: vec![],
                 publishers: vec![
-                    GamePublisher { id: 28595, name: "The Broken Token".to_owned() },
-                    GamePublisher { id: 4384, name: "Repos Production".to_owned() },
+                    GamePublisher { id: PublisherId(28595), name: "The Broken Token".to_owned() },
+                    GamePublisher { id: PublisherId(4384), name: "Repos Production".to_owned() },
+                ],
+                links: vec![
+                    item_link(ItemType::BoardGamePublisher, 28_595, "The Broken Token", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 4384, "Repos Production", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameAccessory, 68_448, "7 Wonders", LinkDirection::Inbound),
+                    item_link(ItemType::BoardGameAccessory, 316_377, "7 Wonders (Second Edition)", LinkDirection::Inbound),
                 ],
                 versions: vec![],
                 marketplace_listings: vec![],
@@ -258,6 +297,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -285,7 +326,7 @@ mod tests {
             .include_rating_comments(true);
         let accessory = api
             .accessory()
-            .get_by_ids(&vec![22_510, 207_791], &params)
+            .get_by_ids(&[AccessoryId(22_510), AccessoryId(207_791)], &params)
             .await;
         mock.assert_async().await;
 
@@ -296,7 +337,7 @@ mod tests {
         assert_eq!(
             accessory[0],
             AccessoryDetails {
-                id: 22_510,
+                id: AccessoryId(22_510),
                 name: "Wings of War: Miniatures".to_owned(),
                 alternate_names: vec!["Wings of War: WW1 Airplane Packs".to_owned()],
                 description: "Wings of War Airplane Packs provide miniatures for the Wings of War system. Each Airplane Pack includes one pre-painted, pre-assembled 1/144 scale model plane with gaming base, the relative airplane card and its deck of Maneuver cards. A Wings of War Deluxe set is also available.".to_owned(),
@@ -304,38 +345,50 @@ mod tests {
                 thumbnail: Some("https://cf.geekdo-images.com/qGV1v8Ye0FKTxZNCF1ZINw__small/img/vgAzbZuLXNSawia3yp4BAPT_2is=/fit-in/200x150/filters:strip_icc()/pic830522.jpg".to_owned()),
                 year_published: 2007,
                 accessory_for: vec![
-                    Game { id: 15_953, name: "Wings of War: Burning Drachens".to_owned() },
-                    Game { id: 31_552, name: "Wings of War: Deluxe Set".to_owned() },
+                    Game { id: GameId(15_953), name: "Wings of War: Burning Drachens".to_owned() },
+                    Game { id: GameId(31_552), name: "Wings of War: Deluxe Set".to_owned() },
                 ],
                 designers: vec![
                     GameDesigner {
-                        id: 546,
+                        id: PersonId(546),
                         name: "Andrea Angiolino".to_owned(),
                     },
                     GameDesigner {
-                        id: 547,
+                        id: PersonId(547),
                         name: "Pier Giorgio Paglia".to_owned(),
                     },
                 ],
                 artists: vec![
-                    GameArtist { id: 12475, name: "Vincenzo Auletta".to_owned() },
-                    GameArtist { id: 12474, name: "Dario Calì".to_owned() },
-                    GameArtist { id: 20670, name: "Fabio Maiorana".to_owned() },
+                    GameArtist { id: PersonId(12475), name: "Vincenzo Auletta".to_owned() },
+                    GameArtist { id: PersonId(12474), name: "Dario Calì".to_owned() },
+                    GameArtist { id: PersonId(20670), name: "Fabio Maiorana".to_owned() },
                 ],
                 publishers: vec![
-                    GamePublisher { id: 17, name: "Fantasy Flight Games".to_owned() },
-                    GamePublisher { id: 504, name: "Nexus Editrice".to_owned() },
-                    GamePublisher { id: 3446, name: "Ubik".to_owned() },
+                    GamePublisher { id: PublisherId(17), name: "Fantasy Flight Games".to_owned() },
+                    GamePublisher { id: PublisherId(504), name: "Nexus Editrice".to_owned() },
+                    GamePublisher { id: PublisherId(3446), name: "Ubik".to_owned() },
+                ],
+                links: vec![
+                    item_link(ItemType::BoardGameDesigner, 546, "Andrea Angiolino", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 547, "Pier Giorgio Paglia", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 12_475, "Vincenzo Auletta", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 12_474, "Dario Calì", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 20_670, "Fabio Maiorana", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 17, "Fantasy Flight Games", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 504, "Nexus Editrice", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 3446, "Ubik", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameAccessory, 15_953, "Wings of War: Burning Drachens", LinkDirection::Inbound),
+                    item_link(ItemType::BoardGameAccessory, 31_552, "Wings of War: Deluxe Set", LinkDirection::Inbound),
                 ],
                 versions: vec![
                     AccessoryVersion {
-                        id: 168_378,
+                        id: VersionId(168_378),
                         name: "Wings of War: Miniatures".to_owned(),
                         thumbnail: None,
                         image: None,
                     },
                     AccessoryVersion {
-                        id: 168_379,
+                        id: VersionId(168_379),
                         name: "Wings of War: Miniatures".to_owned(),
                         thumbnail: Some("https://cf.geekdo-images.com/-qODJQlE2-T0ZhrcO6514g__small/img/SdSbI2zcepfXJqwWx-MOHG9vY9I=/fit-in/200x150/filters:strip_icc()/pic318897.jpg".to_owned()),
                         image: Some("https://cf.geekdo-images.com/-qODJQlE2-T0ZhrcO6514g__original/img/8y314LQOOa0dDqCWXwR_DK7LKbU=/0x0/filters:format(jpeg)/pic318897.jpg".to_owned()),
@@ -344,14 +397,14 @@ mod tests {
                 marketplace_listings: vec![
                     MarketplaceListing {
                         list_date: DateTime::from_naive_utc_and_offset(NaiveDate::from_ymd_opt(2024, 9, 18).unwrap().and_hms_opt(21, 40, 57).unwrap(), Utc),
-                        price: Price { currency: "USD".to_owned(), value: "44.99".to_owned() },
+                        price: Price { currency: Currency::USD, value: Decimal::new(4499, 2) },
                         condition: ItemCondition::New,
                         notes: "Buy this".to_owned(),
                         link: "https://boardgamegeek.com/market/product/3549459".to_owned(),
                     },
                     MarketplaceListing {
                         list_date: DateTime::from_naive_utc_and_offset(NaiveDate::from_ymd_opt(2024, 9, 18).unwrap().and_hms_opt(21, 43, 24).unwrap(), Utc),
-                        price: Price { currency: "USD".to_owned(), value: "44.99".to_owned() },
+                        price: Price { currency: Currency::USD, value: Decimal::new(4499, 2) },
                         condition: ItemCondition::New,
                         notes: "and this!".to_owned(),
                         link: "https://boardgamegeek.com/market/product/3549461".to_owned(),
@@ -370,7 +423,7 @@ mod tests {
         assert_eq!(
             accessory[1],
             AccessoryDetails {
-                id: 207_791,
+                id: AccessoryId(207_791),
                 name: "Scythe: Board Extension".to_owned(),
                 alternate_names: vec![],
                 description: "The board extension slides next to the back side of the standard game board, creating a complete board with 70% bigger hexes (the content is the same). All units and resources in Scythe are kept on the board, so the larger hexes provide more space. The standard game board is 624x818mm (24.6 x 32.2 in), and it grows to 818x939mm (32.2 x 37.0 in) with this extension.".to_owned(),
@@ -378,20 +431,26 @@ mod tests {
                 thumbnail: Some("https://cf.geekdo-images.com/Z9mTOM4Bvpuyg-D0s0IRZw__small/img/539zzfs3dwbbN2SNhuqpexiay-s=/fit-in/200x150/filters:strip_icc()/pic3403769.jpg".to_owned()),
                 year_published: 2016,
                 accessory_for: vec![
-                    Game { id: 169_786, name: "Scythe".to_owned() },
+                    Game { id: GameId(169_786), name: "Scythe".to_owned() },
                 ],
                 designers: vec![
-                    GameDesigner { id: 62_640, name: "Jamey Stegmaier".to_owned() },
+                    GameDesigner { id: PersonId(62_640), name: "Jamey Stegmaier".to_owned() },
                 ],
                 artists: vec![
-                    GameArtist { id: 33_148, name: "Jakub Rozalski".to_owned() },
+                    GameArtist { id: PersonId(33_148), name: "Jakub Rozalski".to_owned() },
                 ],
                 publishers: vec![
-                    GamePublisher { id: 23_202, name: "Stonemaier Games".to_owned() },
+                    GamePublisher { id: PublisherId(23_202), name: "Stonemaier Games".to_owned() },
+                ],
+                links: vec![
+                    item_link(ItemType::BoardGameDesigner, 62_640, "Jamey Stegmaier", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 33_148, "Jakub Rozalski", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 23_202, "Stonemaier Games", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameAccessory, 169_786, "Scythe", LinkDirection::Inbound),
                 ],
                 versions: vec![
                     AccessoryVersion {
-                        id: 324_209,
+                        id: VersionId(324_209),
                         name: "Scythe: Board Extension".to_owned(),
                         thumbnail: Some("https://cf.geekdo-images.com/Z9mTOM4Bvpuyg-D0s0IRZw__small/img/539zzfs3dwbbN2SNhuqpexiay-s=/fit-in/200x150/filters:strip_icc()/pic3403769.jpg".to_owned()),
                         image: Some("https://cf.geekdo-images.com/Z9mTOM4Bvpuyg-D0s0IRZw__original/img/46Xr6DJoXX0YS3Fm4USxPd_UVA8=/0x0/filters:format(jpeg)/pic3403769.jpg".to_owned()),
@@ -400,21 +459,21 @@ mod tests {
                 marketplace_listings: vec![
                     MarketplaceListing {
                         list_date: DateTime::from_naive_utc_and_offset(NaiveDate::from_ymd_opt(2022, 10, 14).unwrap().and_hms_opt(11, 52, 3).unwrap(), Utc),
-                        price: Price { currency: "GBP".to_owned(), value: "12.00".to_owned() },
+                        price: Price { currency: Currency::GBP, value: Decimal::new(1200, 2) },
                         condition: ItemCondition::New,
                         notes: "".to_owned(),
                         link: "https://boardgamegeek.com/market/product/2986606".to_owned(),
                     },
                     MarketplaceListing {
                         list_date: DateTime::from_naive_utc_and_offset(NaiveDate::from_ymd_opt(2023, 10, 28).unwrap().and_hms_opt(18, 11, 7).unwrap(), Utc),
-                        price: Price { currency: "EUR".to_owned(), value: "10.00".to_owned() },
+                        price: Price { currency: Currency::EUR, value: Decimal::new(1000, 2) },
                         condition: ItemCondition::LikeNew,
                         notes: "Only one use.".to_owned(),
                         link: "https://boardgamegeek.com/market/product/3293476".to_owned(),
                     },
                     MarketplaceListing {
                         list_date: DateTime::from_naive_utc_and_offset(NaiveDate::from_ymd_opt(2025, 12, 8).unwrap().and_hms_opt(20, 22, 51).unwrap(), Utc),
-                        price: Price { currency: "EUR".to_owned(), value: "19.00".to_owned() },
+                        price: Price { currency: Currency::EUR, value: Decimal::new(1900, 2) },
                         condition: ItemCondition::New,
                         notes: "Brand new copy of the game, in shrink wrap.".to_owned(),
                         link: "https://boardgamegeek.com/market/product/3908894".to_owned(),
@@ -2,10 +2,12 @@ use core::fmt;
 
 use serde::Deserialize;
 
-use crate::deserialize::{XmlLink, XmlName, XmlSignedValue, XmlStringValue};
+use crate::deserialize::{decode_html_entities, XmlLink, XmlName, XmlSignedValue, XmlStringValue};
+use crate::endpoints::links_matching;
 use crate::{
-    Game, GameArtist, GameDesigner, GamePublisher, MarketplaceListing, NameType, RatingCommentPage,
-    XmlMarketplaceListings,
+    description_paragraphs, AccessoryId, Game, GameArtist, GameDesigner, GameId, GamePublisher,
+    ItemLink, ItemType, MarketplaceListing, NameType, PersonId, PublisherId, RatingCommentPage,
+    VersionId, XmlMarketplaceListings,
 };
 
 // A struct containing the list of requested accessories with the full details.
@@ -25,9 +27,10 @@ pub(crate) struct Accessories {
 /// Some information, such as version info, comments, and marketplace data is only
 /// optionally included if requested.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct AccessoryDetails {
     /// The ID of the accessory.
-    pub id: u64,
+    pub id: AccessoryId,
     /// The name of the accessory.
     pub name: String,
     /// A list of alternate names for the accessory, usually translations of the primary name.
@@ -48,6 +51,9 @@ pub struct AccessoryDetails {
     pub artists: Vec<GameArtist>,
     /// The list of publishers for this accessory.
     pub publishers: Vec<GamePublisher>,
+    /// Every link from this accessory to another item, in the order they are returned, including
+    /// the ones in the lists above, along with which way each link points.
+    pub links: Vec<ItemLink>,
     /// Information for the various versions of the accessory.
     pub versions: Vec<AccessoryVersion>,
     /// Information of where to buy the accessory and for how much.
@@ -74,14 +80,15 @@ pub(crate) struct XmlAccessoryVersions {
 
 /// Information about a version of this accessory
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct AccessoryVersion {
-    /// The ID of this accessory.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    /// The ID of this version of the accessory.
+    #[serde(rename(deserialize = "@id"))]
+    pub id: VersionId,
     /// The name of the accessory.
     #[serde(
         deserialize_with = "deserialize_accessory_version_name",
-        rename = "canonicalname"
+        rename(deserialize = "canonicalname")
     )]
     pub name: String,
     /// A link to a jpg image for the accessory.
@@ -98,6 +105,18 @@ where
     Ok(name_value_xml.value)
 }
 
+impl AccessoryDetails {
+    /// The paragraphs of the accessory's description, see [`description_paragraphs`].
+    pub fn description_paragraphs(&self) -> Vec<&str> {
+        description_paragraphs(&self.description)
+    }
+
+    /// The links from this accessory of the given type, in either direction.
+    pub fn links_of_type(&self, link_type: ItemType) -> Vec<&ItemLink> {
+        links_matching(&self.links, link_type, None)
+    }
+}
+
 impl<'de> Deserialize<'de> for AccessoryDetails {
     fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
@@ -143,6 +162,7 @@ impl<'de> Deserialize<'de> for AccessoryDetails {
                 let mut designers = vec![];
                 let mut artists = vec![];
                 let mut publishers = vec![];
+                let mut links = vec![];
                 // Other
                 let mut versions = None;
                 let mut marketplace_listings = None;
@@ -191,7 +211,10 @@ impl<'de> Deserialize<'de> for AccessoryDetails {
                             if description.is_some() {
                                 return Err(serde::de::Error::duplicate_field("description"));
                             }
-                            description = Some(map.next_value()?);
+                            description = Some(
+                                decode_html_entities(map.next_value::<String>()?.into())
+                                    .into_owned(),
+                            );
                         },
                         Field::YearPublished => {
                             if year_published.is_some() {
@@ -202,30 +225,31 @@ impl<'de> Deserialize<'de> for AccessoryDetails {
                         },
                         Field::Link => {
                             let link: XmlLink = map.next_value()?;
+                            links.push(ItemLink::from(&link));
                             match link.link_type {
                                 crate::ItemType::BoardGameAccessory => {
                                     // The type "boardgameaccessory" with "inbound=true" is used to
                                     // list games that this is an accessory for.
                                     accessory_for.push(Game {
-                                        id: link.id,
+                                        id: GameId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameDesigner => {
                                     designers.push(GameDesigner {
-                                        id: link.id,
+                                        id: PersonId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameArtist => {
                                     artists.push(GameArtist {
-                                        id: link.id,
+                                        id: PersonId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGamePublisher => {
                                     publishers.push(GamePublisher {
-                                        id: link.id,
+                                        id: PublisherId(link.id),
                                         name: link.value,
                                     });
                                 },
@@ -286,6 +310,7 @@ impl<'de> Deserialize<'de> for AccessoryDetails {
                     designers,
                     artists,
                     publishers,
+                    links,
                     versions,
                     marketplace_listings,
                     rating_comments,
@@ -2,29 +2,38 @@ use std::ops::RangeInclusive;
 
 use chrono::NaiveDate;
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
-use crate::api::BoardGameGeekApi;
+use crate::api::{parse_site_response, BoardGameGeekApi};
+use crate::stream::forward_items;
 use crate::{
-    Collection, CollectionItem, CollectionItemBrief, CollectionItemRatingBrief,
-    CollectionItemStatsBrief, CollectionItemType, IntoQueryParam, QueryParam, Result,
-    WishlistPriority,
+    Collection, CollectionId, CollectionItem, CollectionItemBrief, CollectionItemRatingBrief,
+    CollectionItemStatsBrief, CollectionItemType, CollectionItemUpdate, Domain, IntoQueryParam,
+    ItemStream, ObjectId, QueryParam, RawCollection, Result, Session, WishlistPriority,
 };
 
+// The page of the site that items in a collection are saved with. This isn't part of the XML API,
+// it is what the site itself uses, and takes and returns JSON.
+const SAVE_COLLECTION_ITEM_PATH: &str = "geekcollection.php";
+
 /// Trait for a type that the collection endpoint can return. Allows us to get
 /// values for the mandatory query params for the different types.
-pub trait CollectionType<'a>: DeserializeOwned {
+pub trait CollectionType: DeserializeOwned {
     /// Returns the values for the mandatory query params. This ensures that
     /// for the brief type, the `brief` query param is always set to true, and
     /// vice versa.
-    fn base_query(username: &'a str) -> BaseCollectionQuery<'a>;
+    fn base_query(username: &str) -> BaseCollectionQuery<'_>;
 
     /// Get the stats of the type, so post processing helper functions
     /// can be written.
     fn get_stats(&self) -> CollectionItemStatsBrief;
+
+    /// Get the ID of the entry in the collection, so a particular copy of an item can be found.
+    fn get_collection_id(&self) -> CollectionId;
 }
 
-impl<'a> CollectionType<'a> for CollectionItemBrief {
-    fn base_query(username: &'a str) -> BaseCollectionQuery<'a> {
+impl CollectionType for CollectionItemBrief {
+    fn base_query(username: &str) -> BaseCollectionQuery<'_> {
         BaseCollectionQuery {
             username,
             brief: true,
@@ -34,10 +43,14 @@ impl<'a> CollectionType<'a> for CollectionItemBrief {
     fn get_stats(&self) -> CollectionItemStatsBrief {
         self.stats.clone()
     }
+
+    fn get_collection_id(&self) -> CollectionId {
+        self.collection_id
+    }
 }
 
-impl<'a> CollectionType<'a> for CollectionItem {
-    fn base_query(username: &'a str) -> BaseCollectionQuery<'a> {
+impl CollectionType for CollectionItem {
+    fn base_query(username: &str) -> BaseCollectionQuery<'_> {
         BaseCollectionQuery {
             username,
             brief: false,
@@ -59,6 +72,10 @@ impl<'a> CollectionType<'a> for CollectionItem {
             },
         }
     }
+
+    fn get_collection_id(&self) -> CollectionId {
+        self.collection_id
+    }
 }
 
 /// Required query parameters. Any type the collection query can implement
@@ -78,7 +95,7 @@ pub struct BaseCollectionQuery<'q> {
 #[derive(Clone, Debug, Default)]
 pub struct CollectionQueryParams {
     /// Filter collection to only return items of particular IDs.
-    item_ids: Vec<u64>,
+    item_ids: Vec<ObjectId>,
     /// Include only results for this item type.
     ///
     /// Note, if this is set to [`CollectionItemType::BoardGame`] then it will include both
@@ -136,7 +153,7 @@ pub struct CollectionQueryParams {
     /// collection and you are logged in.
     show_private: Option<bool>,
     /// ID of a particular game in a collection.
-    collection_id: Option<u64>,
+    collection_id: Option<CollectionId>,
 }
 
 impl CollectionQueryParams {
@@ -145,15 +162,16 @@ impl CollectionQueryParams {
         Self::default()
     }
 
-    /// Adds an ID to the list of item IDs to retrieve.
-    pub fn item_id(mut self, id: u64) -> Self {
-        self.item_ids.push(id);
+    /// Adds an ID to the list of item IDs to retrieve. This can be the ID of any kind of item,
+    /// such as a game or an accessory.
+    pub fn item_id(mut self, id: impl Into<ObjectId>) -> Self {
+        self.item_ids.push(id.into());
         self
     }
 
     /// Adds a list of IDs to the list of item IDs to retrieve.
-    pub fn item_ids(mut self, ids: Vec<u64>) -> Self {
-        self.item_ids.extend(ids);
+    pub fn item_ids(mut self, ids: impl IntoIterator<Item = impl Into<ObjectId>>) -> Self {
+        self.item_ids.extend(ids.into_iter().map(Into::into));
         self
     }
 
@@ -335,19 +353,17 @@ impl CollectionQueryParams {
         self
     }
 
-    // NOTE currently private until logging in can be done via the API.
     /// Sets the `show_private` parameter. If set then private information about
-    /// the collection will be returned. Only works if the user is logged in
-    /// and requesting their own collection.
-    #[allow(dead_code)]
-    fn show_private(mut self, show_private: bool) -> Self {
+    /// the collection will be returned, such as the price paid for each item. Only works if the
+    /// user is logged in and requesting their own collection, see [`CollectionApi::get_private`].
+    pub fn show_private(mut self, show_private: bool) -> Self {
         self.show_private = Some(show_private);
         self
     }
 
     /// Sets the `collection_id` parameter. If set then results will be filtered
     /// to get the game with the specific collection ID.
-    pub fn collection_id(mut self, collection_id: u64) -> Self {
+    pub fn collection_id(mut self, collection_id: CollectionId) -> Self {
         self.collection_id = Some(collection_id);
         self
     }
@@ -358,13 +374,23 @@ impl CollectionQueryParams {
 struct CollectionQueryBuilder<'q> {
     base: BaseCollectionQuery<'q>,
     params: &'q CollectionQueryParams,
+    // Used to pick the type of item returned when none is provided.
+    domain: Domain,
 }
 
 impl<'builder> CollectionQueryBuilder<'builder> {
-    // Constructs a new query builder from a base query, and the rest of the
-    // parameters.
-    fn new(base: BaseCollectionQuery<'builder>, params: &'builder CollectionQueryParams) -> Self {
-        Self { base, params }
+    // Constructs a new query builder from a base query, the rest of the
+    // parameters, and the domain of the API.
+    fn new(
+        base: BaseCollectionQuery<'builder>,
+        params: &'builder CollectionQueryParams,
+        domain: Domain,
+    ) -> Self {
+        Self {
+            base,
+            params,
+            domain,
+        }
     }
 
     // Converts the list of parameters into a vector of
@@ -382,7 +408,14 @@ impl<'builder> CollectionQueryBuilder<'builder> {
         if !self.params.item_ids.is_empty() {
             query_params.push(self.params.item_ids.into_query_param("id"));
         }
-        if let Some(item_type) = self.params.item_type {
+        // Board games are returned when the subtype is omitted, so for the other domains it must
+        // always be set.
+        let item_type = self.params.item_type.or(match self.domain {
+            Domain::BoardGame => None,
+            Domain::Rpg => Some(CollectionItemType::RpgItem),
+            Domain::VideoGame => Some(CollectionItemType::VideoGame),
+        });
+        if let Some(item_type) = item_type {
             query_params.push(item_type.into_query_param("subtype"));
         }
         if let Some(exclude_item_type) = self.params.exclude_item_type {
@@ -464,6 +497,119 @@ impl<'builder> CollectionQueryBuilder<'builder> {
     }
 }
 
+// The body of a request to save changes to an item in a collection. Only the fields that are set
+// are sent, so that everything else about the item is left as it is.
+#[derive(Serialize)]
+struct SaveCollectionItemRequest<'a> {
+    action: &'static str,
+    ajax: u8,
+    #[serde(rename = "objecttype")]
+    object_type: &'static str,
+    #[serde(rename = "objectid")]
+    object_id: u64,
+    #[serde(rename = "collid", skip_serializing_if = "Option::is_none")]
+    collection_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    own: Option<u8>,
+    #[serde(rename = "prevowned", skip_serializing_if = "Option::is_none")]
+    previously_owned: Option<u8>,
+    #[serde(rename = "fortrade", skip_serializing_if = "Option::is_none")]
+    for_trade: Option<u8>,
+    #[serde(rename = "want", skip_serializing_if = "Option::is_none")]
+    want_in_trade: Option<u8>,
+    #[serde(rename = "wanttoplay", skip_serializing_if = "Option::is_none")]
+    want_to_play: Option<u8>,
+    #[serde(rename = "wanttobuy", skip_serializing_if = "Option::is_none")]
+    want_to_buy: Option<u8>,
+    #[serde(rename = "preordered", skip_serializing_if = "Option::is_none")]
+    pre_ordered: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wishlist: Option<u8>,
+    #[serde(rename = "wishlistpriority", skip_serializing_if = "Option::is_none")]
+    wishlist_priority: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rating: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<&'a str>,
+    #[serde(rename = "privatecomment", skip_serializing_if = "Option::is_none")]
+    private_comment: Option<&'a str>,
+    #[serde(rename = "pricepaid", skip_serializing_if = "Option::is_none")]
+    price_paid: Option<String>,
+    #[serde(rename = "pp_currency", skip_serializing_if = "Option::is_none")]
+    price_paid_currency: Option<&'a str>,
+    #[serde(rename = "acquisitiondate", skip_serializing_if = "Option::is_none")]
+    acquisition_date: Option<NaiveDate>,
+    #[serde(rename = "acquiredfrom", skip_serializing_if = "Option::is_none")]
+    acquired_from: Option<&'a str>,
+    #[serde(rename = "invlocation", skip_serializing_if = "Option::is_none")]
+    inventory_location: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantity: Option<u64>,
+}
+
+impl<'a> SaveCollectionItemRequest<'a> {
+    fn new(item_id: ObjectId, update: &'a CollectionItemUpdate) -> Self {
+        Self {
+            action: "savedata",
+            ajax: 1,
+            object_type: "thing",
+            object_id: item_id.0,
+            collection_id: update.collection_id.map(u64::from),
+            own: update.own.map(u8::from),
+            previously_owned: update.previously_owned.map(u8::from),
+            for_trade: update.for_trade.map(u8::from),
+            want_in_trade: update.want_in_trade.map(u8::from),
+            want_to_play: update.want_to_play.map(u8::from),
+            want_to_buy: update.want_to_buy.map(u8::from),
+            pre_ordered: update.pre_ordered.map(u8::from),
+            wishlist: update.wishlist.map(u8::from),
+            wishlist_priority: update.wishlist_priority.map(wishlist_priority_value),
+            rating: update.rating,
+            comment: update.comment.as_deref(),
+            private_comment: update.private_comment.as_deref(),
+            price_paid: update.price_paid.map(|(price, _)| price.to_string()),
+            price_paid_currency: update
+                .price_paid
+                .as_ref()
+                .map(|(_, currency)| currency.code()),
+            acquisition_date: update.acquisition_date,
+            acquired_from: update.acquired_from.as_deref(),
+            inventory_location: update.inventory_location.as_deref(),
+            quantity: update.quantity,
+        }
+    }
+}
+
+// The same values as used for the wishlist priority query parameter, 1 being the highest.
+fn wishlist_priority_value(wishlist_priority: WishlistPriority) -> u8 {
+    match wishlist_priority {
+        WishlistPriority::DontBuyThis => 5,
+        WishlistPriority::ThinkingAboutIt => 4,
+        WishlistPriority::LikeToHave => 3,
+        WishlistPriority::LoveToHave => 2,
+        WishlistPriority::MustHave => 1,
+    }
+}
+
+// A successful response to saving an item only has the ID of the entry in the collection that was
+// saved, the updated item is requested from the collection endpoint instead.
+#[derive(Deserialize)]
+struct SaveCollectionItemResponse {
+    // The ID can be returned as either a string or a number.
+    #[serde(default, rename = "collid")]
+    collection_id: Option<serde_json::Value>,
+}
+
+impl SaveCollectionItemResponse {
+    fn collection_id(&self) -> Option<CollectionId> {
+        match self.collection_id.as_ref()? {
+            serde_json::Value::String(collection_id) => collection_id.parse().ok(),
+            serde_json::Value::Number(collection_id) => collection_id.as_u64().map(CollectionId),
+            _ => None,
+        }
+    }
+}
+
 /// Collection endpoint of the API. Used for returning user's collections
 /// of games by their username. Filtering by [`crate::CollectionItemStatus`], rating,
 /// recorded plays.
@@ -476,13 +622,13 @@ impl<'builder> CollectionQueryBuilder<'builder> {
 ///
 /// Some retries will be attempted in case there is no queue, in which case it is likely to be ready
 /// very shortly.
-pub struct CollectionApi<'api, T: CollectionType<'api>> {
+pub struct CollectionApi<'api, T: CollectionType> {
     pub(crate) api: &'api BoardGameGeekApi,
     endpoint: &'static str,
     type_marker: std::marker::PhantomData<T>,
 }
 
-impl<'api, T: CollectionType<'api> + 'api> CollectionApi<'api, T> {
+impl<'api, T: CollectionType + 'api> CollectionApi<'api, T> {
     pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
         Self {
             api,
@@ -498,12 +644,39 @@ impl<'api, T: CollectionType<'api> + 'api> CollectionApi<'api, T> {
         username: &'api str,
         query_params: &CollectionQueryParams,
     ) -> Result<Collection<T>> {
-        let query = CollectionQueryBuilder::new(T::base_query(username), query_params);
+        let query =
+            CollectionQueryBuilder::new(T::base_query(username), query_params, self.api.domain);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         self.api.execute_request::<Collection<T>>(request).await
     }
 
+    /// Makes a request for a given user's collection, with any additional
+    /// [`CollectionQueryParams`], returning a stream of the items in the collection.
+    ///
+    /// Items are parsed and returned one at a time while the response is still being read, so
+    /// this should be preferred over [`CollectionApi::get`] for very large collections.
+    pub async fn get_stream(
+        &self,
+        username: &'api str,
+        query_params: &CollectionQueryParams,
+    ) -> Result<ItemStream<T>>
+    where
+        T: Send + 'static,
+    {
+        let query =
+            CollectionQueryBuilder::new(T::base_query(username), query_params, self.api.domain);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let response = self.api.send_request(request).await?;
+
+        let (sender, stream) = ItemStream::channel();
+        tokio::spawn(async move {
+            forward_items(response, "items", "item", &sender).await;
+        });
+        Ok(stream)
+    }
+
     /// Get the user's board game accessory collection. Filtering by any additional
     /// query parameters provided. No board games will be returned in the collection
     /// alongside the accessories.
@@ -568,18 +741,122 @@ impl<'api, T: CollectionType<'api> + 'api> CollectionApi<'api, T> {
         });
         Ok(collection)
     }
+
+    /// Makes a request for the collection of the user of the session, with any additional
+    /// [`CollectionQueryParams`], including the private information about each item that only
+    /// they can see.
+    ///
+    /// The request is made as the user of the session, as private information is only returned to
+    /// the owner of the collection.
+    pub async fn get_private(
+        &self,
+        session: &Session,
+        query_params: &CollectionQueryParams,
+    ) -> Result<Collection<T>> {
+        let query_params = query_params.clone().show_private(true);
+        let query = CollectionQueryBuilder::new(
+            T::base_query(session.username()),
+            &query_params,
+            self.api.domain,
+        );
+
+        let request =
+            self.api
+                .build_request_with_session(self.endpoint, &query.build(), session)?;
+        self.api
+            .execute_request_with_session::<Collection<T>>(request)
+            .await
+    }
+
+    /// Makes changes to an item in the collection of the user of the session, adding it to their
+    /// collection if it isn't already in it. Returns the item as it is in the collection after the
+    /// changes are made, including its private information.
+    ///
+    /// The type of the item is needed to request it back from the collection endpoint once the
+    /// changes are saved, as otherwise expansions and accessories are left out. That endpoint is
+    /// cached by the underlying API, so the item can briefly still show some of its values from
+    /// before the changes. If the saved copy of the item can't be found in the collection yet,
+    /// `None` is returned, as the changes were still saved.
+    ///
+    /// Unlike requests for collections this is not part of the XML API, so it needs a logged in
+    /// [`Session`] from [`BoardGameGeekApi::log_in`].
+    pub async fn update_item(
+        &self,
+        session: &Session,
+        item_id: impl Into<ObjectId>,
+        item_type: CollectionItemType,
+        update: &CollectionItemUpdate,
+    ) -> Result<Option<T>> {
+        let item_id = item_id.into();
+        let request = self
+            .api
+            .build_site_post_request(SAVE_COLLECTION_ITEM_PATH, session)?
+            .json(&SaveCollectionItemRequest::new(item_id, update));
+        let response_text = self.api.execute_site_request(request).await?;
+        let collection_id = parse_site_response::<SaveCollectionItemResponse>(&response_text)?
+            .collection_id()
+            .or(update.collection_id);
+
+        let query_params = CollectionQueryParams::new()
+            .item_id(item_id)
+            .item_type(item_type);
+        let mut items = self.get_private(session, &query_params).await?.items;
+        let item = match collection_id {
+            Some(collection_id) => items
+                .into_iter()
+                .find(|item| item.get_collection_id() == collection_id),
+            // Without the ID of the entry that was saved, the item can only be picked out if the
+            // user has just the one copy of it.
+            None if items.len() == 1 => items.pop(),
+            None => None,
+        };
+        Ok(item)
+    }
+}
+
+impl<'api> CollectionApi<'api, CollectionItem> {
+    /// Makes a request for a given user's collection, with any additional
+    /// [`CollectionQueryParams`], without parsing the response.
+    ///
+    /// The collection can then be parsed with [`RawCollection::parse`], with items as
+    /// [`CollectionItemRef`]s that borrow from the response rather than copying from it. This is
+    /// useful for very large collections where only a few fields of each item are needed, but
+    /// borrowing only happens with the `quick-xml` feature enabled.
+    ///
+    /// [`CollectionItemRef`]: crate::CollectionItemRef
+    pub async fn get_raw(
+        &self,
+        username: &'api str,
+        query_params: &CollectionQueryParams,
+    ) -> Result<RawCollection> {
+        let query = CollectionQueryBuilder::new(
+            CollectionItem::base_query(username),
+            query_params,
+            self.api.domain,
+        );
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let text = self.api.execute_raw_request(request).await?;
+
+        Ok(RawCollection { text })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{Duration, TimeZone, Utc};
     use mockito::Matcher;
 
     use super::*;
+    use crate::deserialize::deserialize_xml_string;
     use crate::{
-        CollectionItemRating, CollectionItemStats, CollectionItemStatus, CollectionItemType,
-        Dimensions, Game, GameArtist, GamePublisher, GameVersion, ItemFamilyRank, Language,
-        RankValue, RatingValue,
+        CollectionId, CollectionItemPrivateInfo, CollectionItemRating, CollectionItemRef,
+        CollectionItemStats, CollectionItemStatus, CollectionItemType, Currency, Decimal,
+        Dimensions, Domain, Error, Game, GameArtist, GameId, GamePublisher, GameVersion,
+        ItemFamilyRank, Language, LanguageId, Length, Mass, PersonId, Price, PublisherId, RankId,
+        RankValue, RatingValue, StaticTokenProvider, VersionId,
     };
 
     #[test]
@@ -593,6 +870,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -625,8 +904,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItemBrief {
-                id: 131_835,
-                collection_id: 118_278_872,
+                id: ObjectId(131_835),
+                collection_id: CollectionId(118_278_872),
                 item_type: CollectionItemType::BoardGame,
                 name: "Boss Monster: The Dungeon Building Card Game".to_string(),
                 status: CollectionItemStatus {
@@ -655,6 +934,11 @@ mod tests {
                     },
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -666,6 +950,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -703,6 +989,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -735,8 +1023,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItem {
-                id: 131_835,
-                collection_id: 118_278_872,
+                id: ObjectId(131_835),
+                collection_id: CollectionId(118_278_872),
                 item_type: CollectionItemType::BoardGame,
                 name: "Boss Monster: The Dungeon Building Card Game".to_string(),
                 year_published: Some(2013),
@@ -770,7 +1058,7 @@ mod tests {
                         standard_deviation: 1.45941,
                         median: 0.0,
                         rank: ItemFamilyRank {
-                            id: 1,
+                            id: RankId(1),
                             name: "boardgame".to_owned(),
                             friendly_name: "Board Game Rank".to_owned(),
                             value: RankValue::Ranked(2486),
@@ -778,7 +1066,7 @@ mod tests {
                         },
                         sub_family_ranks: vec![
                             ItemFamilyRank {
-                                id: 5499,
+                                id: RankId(5499),
                                 name: "familygames".to_owned(),
                                 friendly_name: "Family Game Rank".to_owned(),
                                 value: RankValue::Ranked(1006),
@@ -788,17 +1076,120 @@ mod tests {
                     },
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
+                private_info: None,
             },
             "returned collection game doesn't match expected",
         );
     }
 
+    #[cfg(feature = "serialize")]
+    #[tokio::test]
+    async fn get_owned_serialize() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_owned_single.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let collection = api.collection().get_owned("somename").await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        let json = serde_json::to_value(&collection.items[0]).expect("failed to serialize");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "id": 131_835,
+                "collection_id": 118_278_872,
+                "item_type": "boardgame",
+                "name": "Boss Monster: The Dungeon Building Card Game",
+                "year_published": 2013,
+                "image": "https://cf.geekdo-images.com/VBwaHyx-NWL3VLcCWKRA0w__original/img/izAmJ81QELl5DoK3y2bzJw55lhA=/0x0/filters:format(jpeg)/pic1732644.jpg",
+                "thumbnail": "https://cf.geekdo-images.com/VBwaHyx-NWL3VLcCWKRA0w__thumb/img/wisLXxKXbo5-Ci-ZjEj8ryyoN2g=/fit-in/200x150/filters:strip_icc()/pic1732644.jpg",
+                "status": {
+                    "own": true,
+                    "previously_owned": false,
+                    "for_trade": false,
+                    "want_in_trade": false,
+                    "want_to_play": false,
+                    "want_to_buy": false,
+                    "wishlist": false,
+                    "wishlist_priority": null,
+                    "pre_ordered": false,
+                    "last_modified": "2024-04-13T18:29:01Z",
+                },
+                "number_of_plays": 2,
+                "stats": {
+                    "min_players": 2,
+                    "max_players": 4,
+                    "min_playtime": 30,
+                    "max_playtime": 30,
+                    "playing_time": 30,
+                    "owned_by": 36423,
+                    "rating": {
+                        "user_rating": 3.0,
+                        "users_rated": 17063,
+                        "average": 6.27139,
+                        "bayesian_average": 6.08972,
+                        "standard_deviation": 1.45941,
+                        "rank": {
+                            "id": 1,
+                            "name": "boardgame",
+                            "friendly_name": "Board Game Rank",
+                            "value": { "ranked": 2486 },
+                            "bayesian_average": { "rated": 6.08972 },
+                        },
+                        "sub_family_ranks": [
+                            {
+                                "id": 5499,
+                                "name": "familygames",
+                                "friendly_name": "Family Game Rank",
+                                "value": { "ranked": 1006 },
+                                "bayesian_average": { "rated": 6.05246 },
+                            },
+                        ],
+                    },
+                },
+                "version": null,
+                "comment": null,
+                "wishlist_comment": null,
+                "condition_text": null,
+                "has_parts_list": null,
+                "want_parts_list": null,
+                "private_info": null,
+            }),
+            "serialized collection item doesn't match expected",
+        );
+    }
+
     #[tokio::test]
     async fn get_wishlist() {
         let mut server = mockito::Server::new_async().await;
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -831,8 +1222,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItem {
-                id: 177_736,
-                collection_id: 118_332_974,
+                id: ObjectId(177_736),
+                collection_id: CollectionId(118_332_974),
                 item_type: CollectionItemType::BoardGame,
                 name: "A Feast for Odin".to_string(),
                 year_published: Some(2016),
@@ -866,14 +1257,14 @@ mod tests {
                         standard_deviation: 1.37019,
                         median: 0.0,
                         rank: ItemFamilyRank {
-                            id: 1,
+                            id: RankId(1),
                             name: "boardgame".to_owned(),
                             friendly_name: "Board Game Rank".to_owned(),
                             value: RankValue::Ranked(23),
                             bayesian_average: RatingValue::Rated(7.94347),
                         },
                         sub_family_ranks: vec![ItemFamilyRank {
-                            id: 5497,
+                            id: RankId(5497),
                             name: "strategygames".to_owned(),
                             friendly_name: "Strategy Game Rank".to_owned(),
                             value: RankValue::Ranked(19),
@@ -882,6 +1273,12 @@ mod tests {
                     },
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
+                private_info: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -893,6 +1290,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -927,8 +1326,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItemBrief {
-                id: 356_510,
-                collection_id: 118_278_786,
+                id: ObjectId(356_510),
+                collection_id: CollectionId(118_278_786),
                 item_type: CollectionItemType::BoardGame,
                 name: "Spirit Island: Feather & Flame".to_string(),
                 status: CollectionItemStatus {
@@ -957,14 +1356,19 @@ mod tests {
                     },
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
             },
             "returned collection game with no version doesn't match expected",
         );
         assert_eq!(
             collection.items[1],
             CollectionItemBrief {
-                id: 13,
-                collection_id: 122_520_827,
+                id: ObjectId(13),
+                collection_id: CollectionId(122_520_827),
                 item_type: CollectionItemType::BoardGame,
                 name: "Колонизаторы".to_string(),
                 status: CollectionItemStatus {
@@ -993,44 +1397,49 @@ mod tests {
                     },
                 },
                 version: Some(GameVersion {
-                    id: 712_636,
+                    id: VersionId(712_636),
                     name: "Russian edition 2024".to_owned(),
                     alternate_names: vec![],
                     year_published: 2024,
                     image: Some("https://cf.geekdo-images.com/IfUVNbebRWbtQ_SlGxG6ZQ__original/img/DNNED1WasGboaJH8OWMoGm6Zg1k=/0x0/filters:format(jpeg)/pic8177684.jpg".to_owned()),
                     thumbnail: Some("https://cf.geekdo-images.com/IfUVNbebRWbtQ_SlGxG6ZQ__thumb/img/E4EX1sbROpEXxte6IMx7cRgSL2E=/fit-in/200x150/filters:strip_icc()/pic8177684.jpg".to_owned()),
                     original_game: Game {
-                        id: 13,
+                        id: GameId(13),
                         name: "CATAN".to_owned(),
                     },
                     publishers: vec![GamePublisher {
-                        id: 18852,
+                        id: PublisherId(18852),
                         name: "Hobby World".to_owned(),
                     }],
                     artists: vec![GameArtist {
-                        id: 11825,
+                        id: PersonId(11825),
                         name: "Michael Menzel".to_owned(),
                     }],
                     languages: vec![Language {
-                        id: 2202,
+                        id: LanguageId(2202),
                         name: "Russian".to_owned(),
                     }],
                     dimensions: Some(Dimensions {
-                        width: 11.7323,
-                        length: 11.7323,
-                        depth: 2.79528,
+                        width: Some(Length::from_inches(11.7323)),
+                        length: Some(Length::from_inches(11.7323)),
+                        depth: Some(Length::from_inches(2.79528)),
                     }),
-                    weight: Some(2.7205),
+                    weight: Some(Mass::from_pounds(2.7205)),
                     product_code: Some("915853".to_owned()),
                 }),
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
             },
             "returned collection game with version doesn't match expected",
         );
         assert_eq!(
             collection.items[2],
             CollectionItemBrief {
-                id: 352_515,
-                collection_id: 118_278_970,
+                id: ObjectId(352_515),
+                collection_id: CollectionId(118_278_970),
                 item_type: CollectionItemType::BoardGame,
                 name: "ナナ".to_string(),
                 status: CollectionItemStatus {
@@ -1059,31 +1468,31 @@ mod tests {
                     },
                 },
                 version: Some(GameVersion {
-                    id: 590_616,
+                    id: VersionId(590_616),
                     name: "English/Japanese edition".to_owned(),
                     alternate_names: vec![],
                     year_published: 2021,
                     image: Some("https://cf.geekdo-images.com/rt5qzjbrXq7PgI9IaRekNA__original/img/rh02vSdTIvg-oPr4ymQETCLUEjU=/0x0/filters:format(jpeg)/pic7227031.jpg".to_owned()),
                     thumbnail: Some("https://cf.geekdo-images.com/rt5qzjbrXq7PgI9IaRekNA__thumb/img/HGNVOyEKBxZVl0Ry7YDwIcQ5vVc=/fit-in/200x150/filters:strip_icc()/pic7227031.jpg".to_owned()),
                     original_game: Game {
-                        id: 352_515,
+                        id: GameId(352_515),
                         name: "Trio".to_owned(),
                     },
                     publishers: vec![GamePublisher {
-                        id: 50472,
+                        id: PublisherId(50472),
                         name: "Mob+ (Mob Plus)".to_owned(),
                     }],
                     artists: vec![GameArtist {
-                        id: 108_040,
+                        id: PersonId(108_040),
                         name: "別府さい (Sai Beppu)".to_owned(),
                     }],
                     languages: vec![
                         Language {
-                            id: 2184,
+                            id: LanguageId(2184),
                             name: "English".to_owned(),
                         },
                         Language {
-                            id: 2194,
+                            id: LanguageId(2194),
                             name: "Japanese".to_owned(),
                         },
                     ],
@@ -1091,6 +1500,11 @@ mod tests {
                     weight: None,
                     product_code: None,
                 }),
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
             },
             "returned collection game with version doesn't match expected",
         );
@@ -1102,6 +1516,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -1163,8 +1579,8 @@ mod tests {
             .include_previously_owned(true)
             .item_type(CollectionItemType::BoardGameExpansion)
             .exclude_item_type(CollectionItemType::BoardGame)
-            .item_id(13)
-            .item_ids(vec![3000, 1])
+            .item_id(GameId(13))
+            .item_ids(vec![GameId(3000), GameId(1)])
             .include_rated_by_user(true)
             .include_played_by_user(true)
             .include_commented(true)
@@ -1177,7 +1593,7 @@ mod tests {
             .min_plays(2)
             .max_plays(450)
             .show_private(true)
-            .collection_id(345)
+            .collection_id(CollectionId(345))
             .modified_since(
                 Utc.with_ymd_and_hms(2024, 5, 17, 0, 0, 0)
                     .unwrap()
@@ -1189,12 +1605,199 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn get_stream() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let body = std::fs::read_to_string("test_data/collection/collection_multiple.xml")
+            .expect("failed to load test data");
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "somename".to_owned()),
+                Matcher::UrlEncoded("stats".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("brief".to_owned(), "0".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(&body)
+            .create_async()
+            .await;
+
+        let stream = api
+            .collection()
+            .get_stream("somename", &CollectionQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(stream.is_ok(), "error returned when okay expected");
+        let mut stream = stream.unwrap();
+
+        let mut items = vec![];
+        while let Some(item) = stream.next().await {
+            assert!(item.is_ok(), "error returned when okay expected");
+            items.push(item.unwrap());
+        }
+
+        let collection =
+            crate::deserialize::deserialize_xml_string::<Collection<CollectionItem>>(&body)
+                .expect("failed to parse test data");
+        assert_eq!(items.len(), 39);
+        assert_eq!(
+            items, collection.items,
+            "streamed collection items don't match expected",
+        );
+    }
+
+    #[tokio::test]
+    async fn get_stream_truncated() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let body = std::fs::read_to_string("test_data/collection/collection_multiple.xml")
+            .expect("failed to load test data");
+        // Cut the response off part way through the third item.
+        let third_item = body.match_indices("<item ").nth(2).unwrap().0;
+        let truncated = &body[..third_item + 100];
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(truncated)
+            .create_async()
+            .await;
+
+        let stream = api
+            .collection()
+            .get_stream("somename", &CollectionQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(stream.is_ok(), "error returned when okay expected");
+        let mut stream = stream.unwrap();
+
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+        assert!(matches!(stream.next().await, Some(Ok(_))));
+        let item = stream.next().await;
+        assert!(
+            matches!(item, Some(Err(Error::InvalidResponseError(_)))),
+            "expected invalid response error but got {item:?}",
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_stream_unknown_username() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                "<errors><error><message>Invalid username specified</message></error></errors>",
+            )
+            .create_async()
+            .await;
+
+        let stream = api
+            .collection()
+            .get_stream("someone", &CollectionQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(stream.is_ok(), "error returned when okay expected");
+        let mut stream = stream.unwrap();
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::UnknownUsernameError)),
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_raw() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let body = std::fs::read_to_string("test_data/collection/collection_multiple.xml")
+            .expect("failed to load test data");
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "somename".to_owned()),
+                Matcher::UrlEncoded("stats".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("brief".to_owned(), "0".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(&body)
+            .create_async()
+            .await;
+
+        let raw_collection = api
+            .collection()
+            .get_raw("somename", &CollectionQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(raw_collection.is_ok(), "error returned when okay expected");
+        let raw_collection = raw_collection.unwrap();
+        assert_eq!(raw_collection.text(), body);
+
+        let collection = raw_collection.parse();
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 39);
+        assert_eq!(
+            collection.items[0].name,
+            "Boss Monster: The Dungeon Building Card Game",
+        );
+        #[cfg(feature = "quick-xml")]
+        assert!(matches!(
+            collection.items[0].name,
+            std::borrow::Cow::Borrowed(_),
+        ));
+
+        let expected = deserialize_xml_string::<Collection<CollectionItem>>(&body).unwrap();
+        let items: Vec<CollectionItem> = collection
+            .items
+            .into_iter()
+            .map(CollectionItemRef::into_owned)
+            .collect();
+        assert_eq!(items, expected.items);
+    }
+
     #[tokio::test]
     async fn get_by_player_counts() {
         let mut server = mockito::Server::new_async().await;
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -1229,8 +1832,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItem {
-                id: 2281,
-                collection_id: 118_280_658,
+                id: ObjectId(2281),
+                collection_id: CollectionId(118_280_658),
                 item_type: CollectionItemType::BoardGame,
                 name: "Pictionary".to_string(),
                 year_published: Some(1985),
@@ -1264,7 +1867,7 @@ mod tests {
                         standard_deviation: 1.58457,
                         median: 0.0,
                         rank: ItemFamilyRank {
-                            id: 1,
+                            id: RankId(1),
                             name: "boardgame".to_owned(),
                             friendly_name: "Board Game Rank".to_owned(),
                             value: RankValue::Ranked(5587),
@@ -1272,7 +1875,7 @@ mod tests {
                         },
                         sub_family_ranks: vec![
                             ItemFamilyRank {
-                                id: 5498,
+                                id: RankId(5498),
                                 name: "partygames".to_owned(),
                                 friendly_name: "Party Game Rank".to_owned(),
                                 value: RankValue::Ranked(563),
@@ -1282,6 +1885,12 @@ mod tests {
                     }
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
+                private_info: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -1357,6 +1966,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -1391,8 +2002,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItem {
-                id: 2281,
-                collection_id: 118_280_658,
+                id: ObjectId(2281),
+                collection_id: CollectionId(118_280_658),
                 item_type: CollectionItemType::BoardGame,
                 name: "Pictionary".to_string(),
                 year_published: Some(1985),
@@ -1426,7 +2037,7 @@ mod tests {
                         standard_deviation: 1.58457,
                         median: 0.0,
                         rank: ItemFamilyRank {
-                            id: 1,
+                            id: RankId(1),
                             name: "boardgame".to_owned(),
                             friendly_name: "Board Game Rank".to_owned(),
                             value: RankValue::Ranked(5587),
@@ -1434,7 +2045,7 @@ mod tests {
                         },
                         sub_family_ranks: vec![
                             ItemFamilyRank {
-                                id: 5498,
+                                id: RankId(5498),
                                 name: "partygames".to_owned(),
                                 friendly_name: "Party Game Rank".to_owned(),
                                 value: RankValue::Ranked(563),
@@ -1444,6 +2055,12 @@ mod tests {
                     }
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
+                private_info: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -1520,6 +2137,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -1555,8 +2174,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItem {
-                id: 142_974,
-                collection_id: 122_439_219,
+                id: ObjectId(142_974),
+                collection_id: CollectionId(122_439_219),
                 item_type: CollectionItemType::BoardGameAccessory,
                 name: "12 Realms: Buildings Pack".to_string(),
                 year_published: Some(2013),
@@ -1590,7 +2209,7 @@ mod tests {
                         standard_deviation: 1.89983,
                         median: 0.0,
                         rank: ItemFamilyRank {
-                            id: 62,
+                            id: RankId(62),
                             name: "boardgameaccessory".to_owned(),
                             friendly_name: "Accessory Rank".to_owned(),
                             value: RankValue::Ranked(749),
@@ -1600,14 +2219,20 @@ mod tests {
                     },
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
+                private_info: None,
             },
             "returned collection game doesn't match expected",
         );
         assert_eq!(
             collection.items[1],
             CollectionItem {
-                id: 22510,
-                collection_id: 122_524_875,
+                id: ObjectId(22510),
+                collection_id: CollectionId(122_524_875),
                 item_type: CollectionItemType::BoardGameAccessory,
                 name: "Wings of War: Miniatures".to_string(),
                 year_published: Some(2007),
@@ -1641,7 +2266,7 @@ mod tests {
                         standard_deviation: 1.30371,
                         median: 0.0,
                         rank: ItemFamilyRank {
-                            id: 62,
+                            id: RankId(62),
                             name: "boardgameaccessory".to_owned(),
                             friendly_name: "Accessory Rank".to_owned(),
                             value: RankValue::Ranked(22),
@@ -1651,6 +2276,12 @@ mod tests {
                     },
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
+                private_info: None,
             },
             "returned collection game doesn't match expected",
         );
@@ -1662,6 +2293,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
         let mock = server
             .mock("GET", "/collection")
@@ -1700,6 +2333,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
         let mock = server
             .mock("GET", "/collection")
@@ -1733,8 +2368,8 @@ mod tests {
         assert_eq!(
             collection.items[0],
             CollectionItem {
-                id: 261_537,
-                collection_id: 116_940_928,
+                id: ObjectId(261_537),
+                collection_id: CollectionId(116_940_928),
                 item_type: CollectionItemType::BoardGame,
                 name: "Drinking Games for People Who Never Drink Except When They Do".to_string(),
                 year_published: None,
@@ -1768,7 +2403,7 @@ mod tests {
                         standard_deviation: 1.24722,
                         median: 0.0,
                         rank: ItemFamilyRank {
-                            id: 1,
+                            id: RankId(1),
                             name: "boardgame".to_owned(),
                             friendly_name: "Board Game Rank".to_owned(),
                             value: RankValue::NotRanked,
@@ -1778,8 +2413,472 @@ mod tests {
                     },
                 },
                 version: None,
+                comment: None,
+                wishlist_comment: None,
+                condition_text: None,
+                has_parts_list: None,
+                want_parts_list: None,
+                private_info: None,
             },
             "returned collection game doesn't match expected",
         );
     }
+
+    fn session() -> Session {
+        Session {
+            username: "bluebearbgg".to_owned(),
+            cookie: reqwest::header::HeaderValue::from_static(
+                "SessionID=abc123; bggusername=bluebearbgg",
+            ),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn update_item() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let save_mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_header("cookie", "SessionID=abc123; bggusername=bluebearbgg")
+            .match_body(Matcher::Json(serde_json::json!({
+                "action": "savedata",
+                "ajax": 1,
+                "objecttype": "thing",
+                "objectid": 177_736,
+                "want": 1,
+                "wishlist": 1,
+                "wishlistpriority": 2,
+                "comment": "Want to try the solo mode.",
+                "privatecomment": "Ask for it for my birthday.",
+                "pricepaid": "54.99",
+                "pp_currency": "GBP",
+            })))
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+        let get_mock = server
+            .mock("GET", "/collection")
+            .match_header("cookie", "SessionID=abc123; bggusername=bluebearbgg")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "bluebearbgg".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "177736".to_owned()),
+                Matcher::UrlEncoded("subtype".to_owned(), "boardgame".to_owned()),
+                Matcher::UrlEncoded("showprivate".to_owned(), "1".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_wishlist_single.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let session = session();
+        let update = CollectionItemUpdate::new()
+            .want_in_trade(true)
+            .wishlist(true)
+            .wishlist_priority(WishlistPriority::LoveToHave)
+            .comment("Want to try the solo mode.")
+            .private_comment("Ask for it for my birthday.")
+            .price_paid("54.99".parse().unwrap(), Currency::GBP);
+        let item = api
+            .collection()
+            .update_item(
+                &session,
+                GameId(177_736),
+                CollectionItemType::BoardGame,
+                &update,
+            )
+            .await;
+        save_mock.assert_async().await;
+        get_mock.assert_async().await;
+
+        assert!(item.is_ok(), "error returned when okay expected");
+        let item = item.unwrap().expect("saved item not found");
+
+        assert_eq!(item.id, ObjectId(177_736));
+        assert_eq!(
+            item.status,
+            CollectionItemStatus {
+                own: false,
+                previously_owned: false,
+                for_trade: false,
+                want_in_trade: true,
+                want_to_play: false,
+                want_to_buy: false,
+                pre_ordered: false,
+                wishlist: true,
+                wishlist_priority: Some(WishlistPriority::LoveToHave),
+                last_modified: Utc.with_ymd_and_hms(2024, 4, 18, 19, 28, 17).unwrap(),
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn update_item_multiple_copies() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let save_mock = server
+            .mock("POST", "/geekcollection.php")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "objectid": 177_736,
+                "collid": 118_332_975,
+                "own": 1,
+            })))
+            .with_status(200)
+            .with_body(r#"{"collid":"118332975"}"#)
+            .create_async()
+            .await;
+        let get_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_multiple_copies.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let session = session();
+        let update = CollectionItemUpdate::new()
+            .own(true)
+            .collection_id(CollectionId(118_332_975));
+        let item = api
+            .collection()
+            .update_item(
+                &session,
+                GameId(177_736),
+                CollectionItemType::BoardGame,
+                &update,
+            )
+            .await;
+        save_mock.assert_async().await;
+        get_mock.assert_async().await;
+
+        assert!(item.is_ok(), "error returned when okay expected");
+        let item = item.unwrap().expect("saved item not found");
+        assert_eq!(item.collection_id, CollectionId(118_332_975));
+        assert!(item.status.own);
+    }
+
+    #[tokio::test]
+    async fn update_item_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let save_mock = server
+            .mock("POST", "/geekcollection.php")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+        let get_mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("id".to_owned(), "191190".to_owned()),
+                Matcher::UrlEncoded("subtype".to_owned(), "boardgameaccessory".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/empty_collection.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let session = session();
+        let item = api
+            .collection()
+            .update_item(
+                &session,
+                GameId(191_190),
+                CollectionItemType::BoardGameAccessory,
+                &CollectionItemUpdate::new().own(true),
+            )
+            .await;
+        save_mock.assert_async().await;
+        get_mock.assert_async().await;
+
+        assert!(item.is_ok(), "error returned when okay expected");
+        assert_eq!(item.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn update_item_error() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let save_mock = server
+            .mock("POST", "/geekcollection.php")
+            .with_status(200)
+            .with_body(r#"{"error":"Invalid item. Collection not saved."}"#)
+            .create_async()
+            .await;
+        let get_mock = server
+            .mock("GET", "/collection")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let session = session();
+        let item = api
+            .collection()
+            .update_item(
+                &session,
+                GameId(177_736),
+                CollectionItemType::BoardGame,
+                &CollectionItemUpdate::new().own(true),
+            )
+            .await;
+        save_mock.assert_async().await;
+        get_mock.assert_async().await;
+
+        assert!(
+            matches!(item, Err(Error::UnknownApiErrors(_))),
+            "expected unknown API errors but got {item:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn get_private_session_expired() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::Any)
+            .with_status(403)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let session = session();
+        let collection = api
+            .collection()
+            .get_private(&session, &CollectionQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(collection, Err(Error::SessionExpired)),
+            "expected session expired error but got {collection:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn get_private() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_header("cookie", "SessionID=abc123; bggusername=bluebearbgg")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "bluebearbgg".to_owned()),
+                Matcher::UrlEncoded("own".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("showprivate".to_owned(), "1".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_private_info.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let session = session();
+        let collection = api
+            .collection()
+            .get_private(&session, &CollectionQueryParams::new().include_owned(true))
+            .await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 2);
+        assert_eq!(
+            collection.items[0].private_info,
+            Some(CollectionItemPrivateInfo {
+                price_paid: Some(Price {
+                    currency: Currency::GBP,
+                    value: Decimal::new(4499, 2),
+                }),
+                current_value: Some(Price {
+                    currency: Currency::GBP,
+                    value: Decimal::new(5000, 2),
+                }),
+                quantity: 2,
+                acquisition_date: NaiveDate::from_ymd_opt(2021, 3, 14),
+                acquired_from: Some("Local game store".to_owned()),
+                inventory_location: Some("Shelf B, top".to_owned()),
+                private_comment: Some("One copy is the Kickstarter edition.".to_owned()),
+            }),
+        );
+        assert_eq!(
+            collection.items[1].private_info,
+            Some(CollectionItemPrivateInfo {
+                price_paid: None,
+                current_value: None,
+                quantity: 1,
+                acquisition_date: None,
+                acquired_from: None,
+                inventory_location: None,
+                private_comment: None,
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn get_commented() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "somename".to_owned()),
+                Matcher::UrlEncoded("comment".to_owned(), "1".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_comments.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = CollectionQueryParams::new().include_commented(true);
+        let collection = api.collection().get("somename", &query).await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 2);
+        let for_trade = &collection.items[0];
+        assert_eq!(
+            for_trade.comment.as_deref(),
+            Some("Fun but a bit too random for our group."),
+        );
+        assert_eq!(for_trade.wishlist_comment, None);
+        assert_eq!(
+            for_trade.condition_text.as_deref(),
+            Some("Played twice, cards sleeved. Box has a small dent & some shelf wear."),
+        );
+        assert_eq!(
+            for_trade.has_parts_list.as_deref(),
+            Some("Spare room tiles from a second copy."),
+        );
+        assert_eq!(
+            for_trade.want_parts_list.as_deref(),
+            Some("Missing one hero card."),
+        );
+
+        let wishlist = &collection.items[1];
+        assert_eq!(wishlist.comment, None);
+        assert_eq!(
+            wishlist.wishlist_comment.as_deref(),
+            Some("Only the Norwegians expansion edition."),
+        );
+        assert_eq!(wishlist.condition_text, None);
+        assert_eq!(wishlist.has_parts_list, None);
+        assert_eq!(wishlist.want_parts_list, None);
+    }
+
+    #[tokio::test]
+    async fn get_brief_with_parts() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/collection")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "somename".to_owned()),
+                Matcher::UrlEncoded("brief".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("hasparts".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("wantparts".to_owned(), "1".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/collection/collection_brief_comments.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let query = CollectionQueryParams::new()
+            .has_parts(true)
+            .want_parts(true);
+        let collection = api.collection_brief().get("somename", &query).await;
+        mock.assert_async().await;
+
+        assert!(collection.is_ok(), "error returned when okay expected");
+        let collection = collection.unwrap();
+
+        assert_eq!(collection.items.len(), 1);
+        let item = &collection.items[0];
+        assert_eq!(
+            item.comment.as_deref(),
+            Some("Fun but a bit too random for our group."),
+        );
+        assert_eq!(item.wishlist_comment, None);
+        assert_eq!(
+            item.condition_text.as_deref(),
+            Some("Played twice, cards sleeved."),
+        );
+        assert_eq!(
+            item.has_parts_list.as_deref(),
+            Some("Spare room tiles from a second copy."),
+        );
+        assert_eq!(
+            item.want_parts_list.as_deref(),
+            Some("Missing one hero card."),
+        );
+    }
 }
@@ -1,30 +1,35 @@
 use core::fmt;
+use std::borrow::Cow;
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::Deserialize;
 
 use super::{CollectionItemType, GameVersion, ItemFamilyRank, XmlGameVersions};
+use crate::api::parse_response;
 use crate::deserialize::{
-    deserialize_1_0_bool, deserialize_date_time, deserialize_date_time_with_zone,
-    deserialize_minutes, xml_ranks_to_ranks, XmlFloatValue, XmlIntValue, XmlRanks,
+    deserialize_1_0_bool, deserialize_borrowed_str, deserialize_date_time,
+    deserialize_date_time_with_zone, deserialize_maybe_borrowed_str, deserialize_minutes,
+    xml_ranks_to_ranks, BorrowedStr, XmlFloatValue, XmlIntValue, XmlRanks,
 };
+use crate::{CollectionId, Currency, Decimal, ObjectId, Price};
 
 /// A user's collection on boardgamegeek.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Collection<T> {
     /// List of games, expansions, and accessories in the user's collection. Each game
     /// is not necessarily owned but can be preowned, on the user's wishlist etc.
     ///
     /// Note that accessories and games can never be returned together in one collection,
     /// but games and game expansions can.
-    #[serde(default = "Vec::new", rename = "item")]
+    #[serde(default = "Vec::new", rename(deserialize = "item"))]
     pub items: Vec<T>,
     /// Date and time at which this collection was published.
     ///
     /// When a user's collection is requested, if the data is not ready the request will be queued
     /// and a 202 accepted status will be returned, with a message to
     #[serde(
-        rename = "@pubdate",
+        rename(deserialize = "@pubdate"),
         deserialize_with = "deserialize_date_time_with_zone"
     )]
     pub published_date: DateTime<Utc>,
@@ -36,15 +41,16 @@ pub struct Collection<T> {
 /// If requested and applicable, version information is also included,
 /// this will be the same information as is included in the full version.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct CollectionItemBrief {
     /// The ID of the item.
-    #[serde(rename = "@objectid")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@objectid"))]
+    pub id: ObjectId,
     /// The collection ID of the object.
-    #[serde(rename = "@collid")]
-    pub collection_id: u64,
+    #[serde(rename(deserialize = "@collid"))]
+    pub collection_id: CollectionId,
     /// The type of collection item, which will either be boardgame, expansion, or accessory.
-    #[serde(rename = "@subtype")]
+    #[serde(rename(deserialize = "@subtype"))]
     pub item_type: CollectionItemType,
     /// The name of the item.
     pub name: String,
@@ -57,33 +63,74 @@ pub struct CollectionItemBrief {
     /// version of another game.
     #[serde(default, deserialize_with = "deserialize_version_list")]
     pub version: Option<GameVersion>,
+    /// The user's public comment on the item, if they wrote one.
+    #[serde(default, deserialize_with = "deserialize_free_text")]
+    pub comment: Option<String>,
+    /// The user's comment on why the item is on their wishlist, if they wrote one.
+    #[serde(
+        default,
+        rename(deserialize = "wishlistcomment"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub wishlist_comment: Option<String>,
+    /// The user's notes on the condition of their copy of the item, shown alongside it as trade
+    /// notes when it is for trade.
+    #[serde(
+        default,
+        rename(deserialize = "conditiontext"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub condition_text: Option<String>,
+    /// The user's list of parts they have for the item, such as spare pieces, if they listed any.
+    #[serde(
+        default,
+        rename(deserialize = "haspartslist"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub has_parts_list: Option<String>,
+    /// The user's list of parts they want for the item, such as replacements for lost pieces, if
+    /// they listed any.
+    #[serde(
+        default,
+        rename(deserialize = "wantpartslist"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub want_parts_list: Option<String>,
 }
 
 /// A game, game expansion, or game accessory in a collection.
+///
+/// The name, image, and thumbnail are of type `S`, which is [`String`] by default. See
+/// [`CollectionItemRef`] for the same item with these borrowed from the response instead.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
-pub struct CollectionItem {
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(bound(deserialize = "S: From<Cow<'de, str>>"))]
+pub struct CollectionItem<S = String> {
     /// The ID of the item.
-    #[serde(rename = "@objectid")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@objectid"))]
+    pub id: ObjectId,
     /// The collection ID of the object.
-    #[serde(rename = "@collid")]
-    pub collection_id: u64,
+    #[serde(rename(deserialize = "@collid"))]
+    pub collection_id: CollectionId,
     /// The type of collection item, which will either be boardgame, expansion, or accessory.
-    #[serde(rename = "@subtype")]
+    #[serde(rename(deserialize = "@subtype"))]
     pub item_type: CollectionItemType,
     /// The name of the item.
-    pub name: String,
+    #[serde(deserialize_with = "deserialize_borrowed_str_as")]
+    pub name: S,
     /// The year the item was first published. Can be empty.
-    #[serde(rename = "yearpublished")]
+    #[serde(rename(deserialize = "yearpublished"))]
     pub year_published: Option<i64>,
     /// A link to a jpg image for the item. Can be empty.
-    pub image: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_maybe_borrowed_str_as")]
+    pub image: Option<S>,
     /// A link to a jpg thumbnail image for the item. Can be empty.
-    pub thumbnail: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_maybe_borrowed_str_as")]
+    pub thumbnail: Option<S>,
     /// Status of the item in this collection, such as own, preowned, wishlist.
     pub status: CollectionItemStatus,
     /// The number of times the user has played the game.
-    #[serde(rename = "numplays")]
+    #[serde(rename(deserialize = "numplays"))]
     pub number_of_plays: u64,
     /// Game stats such as number of players.
     pub stats: CollectionItemStats,
@@ -92,47 +139,464 @@ pub struct CollectionItem {
     /// version of another game.
     #[serde(default, deserialize_with = "deserialize_version_list")]
     pub version: Option<GameVersion>,
+    /// The user's public comment on the item, if they wrote one.
+    #[serde(default, deserialize_with = "deserialize_free_text")]
+    pub comment: Option<String>,
+    /// The user's comment on why the item is on their wishlist, if they wrote one.
+    #[serde(
+        default,
+        rename(deserialize = "wishlistcomment"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub wishlist_comment: Option<String>,
+    /// The user's notes on the condition of their copy of the item, shown alongside it as trade
+    /// notes when it is for trade.
+    #[serde(
+        default,
+        rename(deserialize = "conditiontext"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub condition_text: Option<String>,
+    /// The user's list of parts they have for the item, such as spare pieces, if they listed any.
+    #[serde(
+        default,
+        rename(deserialize = "haspartslist"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub has_parts_list: Option<String>,
+    /// The user's list of parts they want for the item, such as replacements for lost pieces, if
+    /// they listed any.
+    #[serde(
+        default,
+        rename(deserialize = "wantpartslist"),
+        deserialize_with = "deserialize_free_text"
+    )]
+    pub want_parts_list: Option<String>,
+    /// Private information about the item, such as the price paid for it. Only included if
+    /// requested, and only when the collection is requested by its owner.
+    #[serde(default, rename(deserialize = "privateinfo"))]
+    pub private_info: Option<CollectionItemPrivateInfo>,
+}
+
+/// A borrowed version of [`CollectionItem`], for parsing large collections quickly.
+///
+/// The name, image, and thumbnail borrow from the response text where they can, rather than each
+/// being copied into a new string. These are only copied if they had to be unescaped, for example
+/// if they contain entities such as `&amp;`.
+///
+/// Borrowing only happens with the `quick-xml` feature enabled. With the default parser every
+/// string is copied, so this is no cheaper to parse than [`CollectionItem`].
+///
+/// Parsed from a [`RawCollection`] response. Can be cheaply converted into a [`CollectionItem`]
+/// with [`CollectionItemRef::into_owned`], which only copies the strings that are still borrowed.
+pub type CollectionItemRef<'a> = CollectionItem<Cow<'a, str>>;
+
+impl CollectionItemRef<'_> {
+    /// Converts into an owned [`CollectionItem`], copying any strings that are still borrowed.
+    pub fn into_owned(self) -> CollectionItem {
+        CollectionItem {
+            id: self.id,
+            collection_id: self.collection_id,
+            item_type: self.item_type,
+            name: self.name.into_owned(),
+            year_published: self.year_published,
+            image: self.image.map(Cow::into_owned),
+            thumbnail: self.thumbnail.map(Cow::into_owned),
+            status: self.status,
+            number_of_plays: self.number_of_plays,
+            stats: self.stats,
+            version: self.version,
+            comment: self.comment,
+            wishlist_comment: self.wishlist_comment,
+            condition_text: self.condition_text,
+            has_parts_list: self.has_parts_list,
+            want_parts_list: self.want_parts_list,
+            private_info: self.private_info,
+        }
+    }
+}
+
+impl From<CollectionItemRef<'_>> for CollectionItem {
+    fn from(item: CollectionItemRef<'_>) -> Self {
+        item.into_owned()
+    }
+}
+
+/// The unparsed response to a request for a collection, returned by [`CollectionApi::get_raw`].
+///
+/// The collection can then be parsed from this with items as [`CollectionItemRef`]s, which borrow
+/// from the response text rather than copying it.
+///
+/// [`CollectionApi::get_raw`]: crate::CollectionApi::get_raw
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawCollection {
+    pub(crate) text: String,
+}
+
+impl RawCollection {
+    /// Parses the collection in the response, borrowing from the response text where possible.
+    pub fn parse(&self) -> crate::Result<Collection<CollectionItemRef<'_>>> {
+        parse_response(&self.text)
+    }
+
+    /// The XML text of the response.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
 }
 
 /// The status of the item in the user's collection, such as preowned or
 /// wishlist. Can be any or none of them.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct CollectionItemStatus {
     /// User owns the item.
-    #[serde(rename = "@own", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@own"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub own: bool,
     /// User has previously owned the item.
-    #[serde(rename = "@prevowned", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@prevowned"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub previously_owned: bool,
     /// User wants to trade away the item.
-    #[serde(rename = "@fortrade", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@fortrade"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub for_trade: bool,
     /// User wants to receive the item in a trade.
-    #[serde(rename = "@want", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@want"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub want_in_trade: bool,
     /// User wants to play the item.
-    #[serde(rename = "@wanttoplay", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@wanttoplay"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub want_to_play: bool,
     /// User wants to buy the item.
-    #[serde(rename = "@wanttobuy", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@wanttobuy"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub want_to_buy: bool,
     /// User pre-ordered the item.
-    #[serde(rename = "@preordered", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@preordered"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub pre_ordered: bool,
     /// User has the item on their wishlist.
-    #[serde(rename = "@wishlist", deserialize_with = "deserialize_1_0_bool")]
+    #[serde(
+        rename(deserialize = "@wishlist"),
+        deserialize_with = "deserialize_1_0_bool"
+    )]
     pub wishlist: bool,
     /// The priority of the wishlist.
-    #[serde(default, rename = "@wishlistpriority")]
+    #[serde(default, rename(deserialize = "@wishlistpriority"))]
     pub wishlist_priority: Option<WishlistPriority>,
     /// When the collection status was last modified.
-    #[serde(rename = "@lastmodified", deserialize_with = "deserialize_date_time")]
+    #[serde(
+        rename(deserialize = "@lastmodified"),
+        deserialize_with = "deserialize_date_time"
+    )]
     pub last_modified: DateTime<Utc>,
 }
 
+/// Private information about an item in a collection, that only the owner of the collection can
+/// see, for keeping track of what they paid for it and where it is kept.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct CollectionItemPrivateInfo {
+    /// The price the owner paid for the item, if entered.
+    pub price_paid: Option<Price>,
+    /// What the owner thinks the item is currently worth, if entered.
+    pub current_value: Option<Price>,
+    /// How many copies of the item the owner has.
+    pub quantity: u64,
+    /// The date the owner acquired the item, if entered.
+    pub acquisition_date: Option<NaiveDate>,
+    /// Where the owner acquired the item from, such as a shop, if entered.
+    pub acquired_from: Option<String>,
+    /// Where the owner keeps the item, if entered.
+    pub inventory_location: Option<String>,
+    /// A comment on the item that only the owner can see, if entered.
+    pub private_comment: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for CollectionItemPrivateInfo {
+    fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let xml = XmlPrivateInfo::deserialize(deserializer)?;
+        Ok(Self {
+            price_paid: xml_price(&xml.price_paid, &xml.price_paid_currency)?,
+            current_value: xml_price(&xml.current_value, &xml.current_value_currency)?,
+            quantity: xml.quantity,
+            // Dates that aren't entered are either empty or `0000-00-00`, which fails to parse.
+            acquisition_date: NaiveDate::parse_from_str(&xml.acquisition_date, "%Y-%m-%d").ok(),
+            acquired_from: non_empty(&xml.acquired_from),
+            inventory_location: non_empty(&xml.inventory_location),
+            private_comment: xml.private_comment.as_deref().and_then(non_empty),
+        })
+    }
+}
+
+// XML representation of the private info of a collection item. Every attribute is included even if
+// the owner hasn't entered anything for it, in which case it is empty.
+#[derive(Deserialize)]
+struct XmlPrivateInfo {
+    #[serde(default, rename = "@pricepaid")]
+    price_paid: String,
+    #[serde(default, rename = "@pp_currency")]
+    price_paid_currency: String,
+    #[serde(default, rename = "@currvalue")]
+    current_value: String,
+    #[serde(default, rename = "@cv_currency")]
+    current_value_currency: String,
+    #[serde(default = "default_quantity", rename = "@quantity")]
+    quantity: u64,
+    #[serde(default, rename = "@acquisitiondate")]
+    acquisition_date: String,
+    #[serde(default, rename = "@acquiredfrom")]
+    acquired_from: String,
+    #[serde(default, rename = "@inventorylocation")]
+    inventory_location: String,
+    #[serde(default, rename = "privatecomment")]
+    private_comment: Option<String>,
+}
+
+// The name, image, and thumbnail are borrowed from the response if possible, and then converted
+// into the string type of the item.
+fn deserialize_borrowed_str_as<'de, D, S>(deserializer: D) -> Result<S, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    S: From<Cow<'de, str>>,
+{
+    deserialize_borrowed_str(deserializer).map(S::from)
+}
+
+fn deserialize_maybe_borrowed_str_as<'de, D, S>(deserializer: D) -> Result<Option<S>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    S: From<Cow<'de, str>>,
+{
+    deserialize_maybe_borrowed_str(deserializer).map(|s| s.map(S::from))
+}
+
+// Free text fields are usually left out when the user hasn't written anything, but can also be
+// included with no text.
+fn deserialize_free_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?
+        .as_deref()
+        .and_then(non_empty))
+}
+
+fn default_quantity() -> u64 {
+    1
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_owned())
+}
+
+// A price is only returned if both the value and its currency were entered.
+fn xml_price<E: serde::de::Error>(value: &str, currency: &str) -> Result<Option<Price>, E> {
+    if value.trim().is_empty() || currency.trim().is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(Price {
+        value: value.trim().parse().map_err(E::custom)?,
+        currency: currency.trim().parse().map_err(E::custom)?,
+    }))
+}
+
+/// Changes to make to an item in a user's collection with [`crate::CollectionApi::update_item`],
+/// adding it to the collection if it isn't already in it. Anything not set is left unchanged.
+///
+/// An existing [`CollectionItemStatus`] can be converted into an update that sets every status to
+/// match it.
+///
+/// ```
+/// use arnak::{CollectionItemUpdate, WishlistPriority};
+///
+/// let update = CollectionItemUpdate::new()
+///     .wishlist(true)
+///     .wishlist_priority(WishlistPriority::MustHave)
+///     .comment("Looks great for two players.");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CollectionItemUpdate {
+    pub(crate) own: Option<bool>,
+    pub(crate) previously_owned: Option<bool>,
+    pub(crate) for_trade: Option<bool>,
+    pub(crate) want_in_trade: Option<bool>,
+    pub(crate) want_to_play: Option<bool>,
+    pub(crate) want_to_buy: Option<bool>,
+    pub(crate) pre_ordered: Option<bool>,
+    pub(crate) wishlist: Option<bool>,
+    pub(crate) wishlist_priority: Option<WishlistPriority>,
+    pub(crate) rating: Option<f64>,
+    pub(crate) comment: Option<String>,
+    pub(crate) private_comment: Option<String>,
+    pub(crate) price_paid: Option<(Decimal, Currency)>,
+    pub(crate) acquisition_date: Option<NaiveDate>,
+    pub(crate) acquired_from: Option<String>,
+    pub(crate) inventory_location: Option<String>,
+    pub(crate) quantity: Option<u64>,
+    pub(crate) collection_id: Option<CollectionId>,
+}
+
+impl CollectionItemUpdate {
+    /// Constructs an update with nothing set, which changes nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the user owns the item.
+    pub fn own(mut self, own: bool) -> Self {
+        self.own = Some(own);
+        self
+    }
+
+    /// Sets whether the user has previously owned the item.
+    pub fn previously_owned(mut self, previously_owned: bool) -> Self {
+        self.previously_owned = Some(previously_owned);
+        self
+    }
+
+    /// Sets whether the user wants to trade away the item.
+    pub fn for_trade(mut self, for_trade: bool) -> Self {
+        self.for_trade = Some(for_trade);
+        self
+    }
+
+    /// Sets whether the user wants to receive the item in a trade.
+    pub fn want_in_trade(mut self, want_in_trade: bool) -> Self {
+        self.want_in_trade = Some(want_in_trade);
+        self
+    }
+
+    /// Sets whether the user wants to play the item.
+    pub fn want_to_play(mut self, want_to_play: bool) -> Self {
+        self.want_to_play = Some(want_to_play);
+        self
+    }
+
+    /// Sets whether the user wants to buy the item.
+    pub fn want_to_buy(mut self, want_to_buy: bool) -> Self {
+        self.want_to_buy = Some(want_to_buy);
+        self
+    }
+
+    /// Sets whether the user has pre-ordered the item.
+    pub fn pre_ordered(mut self, pre_ordered: bool) -> Self {
+        self.pre_ordered = Some(pre_ordered);
+        self
+    }
+
+    /// Sets whether the user has the item on their wishlist.
+    pub fn wishlist(mut self, wishlist: bool) -> Self {
+        self.wishlist = Some(wishlist);
+        self
+    }
+
+    /// Sets the priority of the item on the user's wishlist.
+    pub fn wishlist_priority(mut self, wishlist_priority: WishlistPriority) -> Self {
+        self.wishlist_priority = Some(wishlist_priority);
+        self
+    }
+
+    /// Sets the user's rating of the item, from 1 to 10.
+    pub fn rating(mut self, rating: f64) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sets the user's public comment on the item.
+    pub fn comment(mut self, comment: &str) -> Self {
+        self.comment = Some(comment.to_owned());
+        self
+    }
+
+    /// Sets the user's private comment on the item, only visible to them.
+    pub fn private_comment(mut self, private_comment: &str) -> Self {
+        self.private_comment = Some(private_comment.to_owned());
+        self
+    }
+
+    /// Sets the price the user paid for the item. Private info, only visible to them.
+    pub fn price_paid(mut self, price_paid: Decimal, currency: Currency) -> Self {
+        self.price_paid = Some((price_paid, currency));
+        self
+    }
+
+    /// Sets the date the user acquired the item. Private info, only visible to them.
+    pub fn acquisition_date(mut self, acquisition_date: NaiveDate) -> Self {
+        self.acquisition_date = Some(acquisition_date);
+        self
+    }
+
+    /// Sets where the user acquired the item from, such as a shop. Private info, only visible to
+    /// them.
+    pub fn acquired_from(mut self, acquired_from: &str) -> Self {
+        self.acquired_from = Some(acquired_from.to_owned());
+        self
+    }
+
+    /// Sets where the user keeps the item. Private info, only visible to them.
+    pub fn inventory_location(mut self, inventory_location: &str) -> Self {
+        self.inventory_location = Some(inventory_location.to_owned());
+        self
+    }
+
+    /// Sets how many copies of the item the user has. Private info, only visible to them.
+    pub fn quantity(mut self, quantity: u64) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    /// Sets which copy of the item to change, for when the user has more than one copy of it in
+    /// their collection. If unset the changes are made to the item as a whole.
+    pub fn collection_id(mut self, collection_id: CollectionId) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+}
+
+impl From<&CollectionItemStatus> for CollectionItemUpdate {
+    fn from(status: &CollectionItemStatus) -> Self {
+        Self {
+            own: Some(status.own),
+            previously_owned: Some(status.previously_owned),
+            for_trade: Some(status.for_trade),
+            want_in_trade: Some(status.want_in_trade),
+            want_to_play: Some(status.want_to_play),
+            want_to_buy: Some(status.want_to_buy),
+            pre_ordered: Some(status.pre_ordered),
+            wishlist: Some(status.wishlist),
+            wishlist_priority: status.wishlist_priority,
+            ..Self::default()
+        }
+    }
+}
+
 /// The status of the item in the user's collection, such as preowned or
 /// wishlist. Can be any or none of them.
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum WishlistPriority {
     /// Lowest priority.
     DontBuyThis,
@@ -148,9 +612,9 @@ pub enum WishlistPriority {
 
 impl<'de> Deserialize<'de> for WishlistPriority {
     fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+        let s = BorrowedStr::deserialize(deserializer)?.0;
 
-        match s.as_str() {
+        match s.as_ref() {
             "5" => Ok(WishlistPriority::DontBuyThis),
             "4" => Ok(WishlistPriority::ThinkingAboutIt),
             "3" => Ok(WishlistPriority::LikeToHave),
@@ -189,36 +653,49 @@ where
 /// Stats of the game such as player count and duration. Can be omitted from the
 /// response. More stats can be found from the specific game endpoint.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct CollectionItemStatsBrief {
     /// Minimum players the game supports.
-    #[serde(default, rename = "@minplayers")]
+    #[serde(default, rename(deserialize = "@minplayers"))]
     pub min_players: u32,
     /// Maximum players the game supports.
-    #[serde(default, rename = "@maxplayers")]
+    #[serde(default, rename(deserialize = "@maxplayers"))]
     pub max_players: u32,
     /// Minimum amount of time the game is suggested to take to play.
     #[serde(
         default,
-        rename = "@minplaytime",
+        rename(deserialize = "@minplaytime"),
         deserialize_with = "deserialize_minutes"
     )]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub min_playtime: Duration,
     /// Maximum amount of time the game is suggested to take to play.
     #[serde(
         default,
-        rename = "@maxplaytime",
+        rename(deserialize = "@maxplaytime"),
         deserialize_with = "deserialize_minutes"
     )]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub max_playtime: Duration,
     /// The amount of time the game is suggested to take to play.
     #[serde(
         default,
-        rename = "@playingtime",
+        rename(deserialize = "@playingtime"),
         deserialize_with = "deserialize_minutes"
     )]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub playing_time: Duration,
     /// The number of people that own this game.
-    #[serde(rename = "@numowned")]
+    #[serde(rename(deserialize = "@numowned"))]
     pub owned_by: u64,
     /// Information about the rating that this user, as well as all users, have
     /// given this game.
@@ -228,46 +705,60 @@ pub struct CollectionItemStatsBrief {
 /// Stats of the game such as the player count and duration. Can be omitted from the
 /// response. More stats can be found from the specific game endpoint.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct CollectionItemStats {
     /// Minimum players the game supports.
-    #[serde(default, rename = "@minplayers")]
+    #[serde(default, rename(deserialize = "@minplayers"))]
     pub min_players: u32,
     /// Maximum players the game supports.
-    #[serde(default, rename = "@maxplayers")]
+    #[serde(default, rename(deserialize = "@maxplayers"))]
     pub max_players: u32,
     /// Minimum amount of time the game is suggested to take to play.
     #[serde(
         default,
-        rename = "@minplaytime",
+        rename(deserialize = "@minplaytime"),
         deserialize_with = "deserialize_minutes"
     )]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub min_playtime: Duration,
     /// Maximum amount of time the game is suggested to take to play.
     #[serde(
         default,
-        rename = "@maxplaytime",
+        rename(deserialize = "@maxplaytime"),
         deserialize_with = "deserialize_minutes"
     )]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub max_playtime: Duration,
     /// The amount of time the game is suggested to take to play.
     #[serde(
         default,
-        rename = "@playingtime",
+        rename(deserialize = "@playingtime"),
         deserialize_with = "deserialize_minutes"
     )]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub playing_time: Duration,
     /// The number of people that own this game.
-    #[serde(rename = "@numowned")]
+    #[serde(rename(deserialize = "@numowned"))]
     pub owned_by: u64,
     /// Information about the rating that this user, as well as all users, have
     /// given this game.
-    #[serde(rename = "rating")]
+    #[serde(rename(deserialize = "rating"))]
     pub rating: CollectionItemRating,
 }
 
 /// The 0-10 rating that the user gave to this item. Also includes the total
 /// number of users that have rated it, as well as the averages.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct CollectionItemRatingBrief {
     /// The 0-10 rating that the user gave this item.
     pub user_rating: Option<f64>,
@@ -362,6 +853,7 @@ impl<'de> Deserialize<'de> for CollectionItemRatingBrief {
 /// number of users that have rated it, as well as the averages, and standard
 /// deviation.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct CollectionItemRating {
     /// The 0-10 rating that the user gave this item.
     pub user_rating: Option<f64>,
@@ -375,6 +867,7 @@ pub struct CollectionItemRating {
     /// The standard deviation of the average rating.
     pub standard_deviation: f64,
     // Kept private for now since the API always returns 0 for this seemingly.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub(crate) median: f64,
     /// The rank of this item amongst everything of that item type.
     pub rank: ItemFamilyRank,
@@ -520,6 +1013,11 @@ impl<'de> Deserialize<'de> for CollectionItemRating {
 /// A rank a particular board game has on the site, within a subtype. Can be
 /// either Ranked with a u64 for the rank, Or `NotRanked`.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum RankValue {
     /// The rank of a game within a particular family of games, or all games. Where
     /// 1 means that it has the highest overall rank of every game in that category.
@@ -531,7 +1029,7 @@ pub enum RankValue {
 
 impl<'de> Deserialize<'de> for RankValue {
     fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+        let s = BorrowedStr::deserialize(deserializer)?.0;
         if s == "Not Ranked" {
             return Ok(RankValue::NotRanked);
         }
@@ -550,6 +1048,11 @@ impl<'de> Deserialize<'de> for RankValue {
 /// A Bayesian average rating of a boardgame in its family.
 /// Either valued as a f64, or `NotRanked`.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum RatingValue {
     /// The Bayesian average rating of a game within a specific family or category.
     ///
@@ -566,7 +1069,7 @@ pub enum RatingValue {
 
 impl<'de> Deserialize<'de> for RatingValue {
     fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+        let s = BorrowedStr::deserialize(deserializer)?.0;
         if s == "Not Ranked" {
             return Ok(RatingValue::Unrated);
         }
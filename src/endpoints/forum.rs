@@ -1,4 +1,4 @@
-use crate::{BoardGameGeekApi, Forum, IntoQueryParam, QueryParam, Result};
+use crate::{BoardGameGeekApi, Forum, ForumId, IntoQueryParam, QueryParam, Result};
 
 /// All optional query parameters for making a request to the forum endpoint.
 #[derive(Clone, Debug, Default)]
@@ -8,12 +8,12 @@ struct ForumQueryParams {
 
 #[derive(Clone, Debug)]
 struct ForumQueryBuilder<'builder> {
-    forum_id: u64,
+    forum_id: ForumId,
     params: &'builder ForumQueryParams,
 }
 
 impl<'builder> ForumQueryBuilder<'builder> {
-    fn new(forum_id: u64, params: &'builder ForumQueryParams) -> Self {
+    fn new(forum_id: ForumId, params: &'builder ForumQueryParams) -> Self {
         Self { forum_id, params }
     }
 
@@ -42,7 +42,7 @@ impl<'api> ForumApi<'api> {
     }
 
     /// Get metadata for a forum by ID, contains the first page of threads.
-    pub async fn get(&self, id: u64) -> Result<Forum> {
+    pub async fn get(&self, id: ForumId) -> Result<Forum> {
         let params = ForumQueryParams::default();
         let query = ForumQueryBuilder::new(id, &params);
 
@@ -53,7 +53,7 @@ impl<'api> ForumApi<'api> {
     }
 
     /// Get metadata for a forum by ID, and a particular page of threads in this forum.
-    pub async fn get_with_threads_page(&self, id: u64, page: u64) -> Result<Forum> {
+    pub async fn get_with_threads_page(&self, id: ForumId, page: u64) -> Result<Forum> {
         let params = ForumQueryParams { page: Some(page) };
         let query = ForumQueryBuilder::new(id, &params);
 
@@ -66,11 +66,13 @@ impl<'api> ForumApi<'api> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{DateTime, NaiveDate, Utc};
     use mockito::Matcher;
 
     use super::*;
-    use crate::ThreadDetails;
+    use crate::{Domain, ForumId, StaticTokenProvider, ThreadDetails, ThreadId};
 
     #[tokio::test]
     async fn get() {
@@ -78,6 +80,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -94,7 +98,7 @@ mod tests {
             .create_async()
             .await;
 
-        let forum = api.forum().get(1_154_020).await;
+        let forum = api.forum().get(ForumId(1_154_020)).await;
         mock.assert_async().await;
 
         assert!(forum.is_ok(), "error returned when okay expected");
@@ -103,13 +107,13 @@ mod tests {
         assert_eq!(
             forum,
             Forum {
-                id: 1_154_020,
+                id: ForumId(1_154_020),
                 title: "Geek Lobby".to_owned(),
                 number_of_threads: 2874,
                 number_of_posts: 125_454,
                 threads: vec![
                     ThreadDetails {
-                        id: 1_304_539,
+                        id: ThreadId(1_304_539),
                         subject: "Whoever gets in the last word wins.".to_owned(),
                         author: "ravager".to_owned(),
                         number_of_articles: 8598,
@@ -129,7 +133,7 @@ mod tests {
                         ),
                     },
                     ThreadDetails {
-                        id: 3_707_147,
+                        id: ThreadId(3_707_147),
                         subject: "1001 Game generator".to_owned(),
                         author: "Abarbesgaard".to_owned(),
                         number_of_articles: 2,
@@ -159,6 +163,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -175,7 +181,10 @@ mod tests {
             .create_async()
             .await;
 
-        let forum = api.forum().get_with_threads_page(1_154_020, 3).await;
+        let forum = api
+            .forum()
+            .get_with_threads_page(ForumId(1_154_020), 3)
+            .await;
         mock.assert_async().await;
 
         assert!(forum.is_ok(), "error returned when okay expected");
@@ -184,13 +193,13 @@ mod tests {
         assert_eq!(
             forum,
             Forum {
-                id: 1_154_020,
+                id: ForumId(1_154_020),
                 title: "Geek Lobby".to_owned(),
                 number_of_threads: 2874,
                 number_of_posts: 125_454,
                 threads: vec![
                     ThreadDetails {
-                        id: 1_304_539,
+                        id: ThreadId(1_304_539),
                         subject: "Whoever gets in the last word wins.".to_owned(),
                         author: "ravager".to_owned(),
                         number_of_articles: 8598,
@@ -210,7 +219,7 @@ mod tests {
                         ),
                     },
                     ThreadDetails {
-                        id: 3_707_147,
+                        id: ThreadId(3_707_147),
                         subject: "1001 Game generator".to_owned(),
                         author: "Abarbesgaard".to_owned(),
                         number_of_articles: 2,
@@ -1,4 +1,6 @@
-use crate::{BoardGameGeekApi, ForumGroup, IntoQueryParam, ItemDomain, QueryParam, Result};
+use crate::{
+    BoardGameGeekApi, FamilyId, ForumGroup, GameId, IntoQueryParam, ItemDomain, QueryParam, Result,
+};
 
 #[derive(Clone, Debug)]
 struct ForumGroupQueryBuilder {
@@ -35,14 +37,14 @@ impl<'api> ForumGroupApi<'api> {
     }
 
     /// Get the list of forums that belong to a particular game family, by that game's ID.
-    pub async fn get_game_forums(&self, id: u64) -> Result<ForumGroup> {
-        self.get_forum_group_by_id_and_type(id, ItemDomain::Item)
+    pub async fn get_game_forums(&self, id: GameId) -> Result<ForumGroup> {
+        self.get_forum_group_by_id_and_type(id.0, ItemDomain::Item)
             .await
     }
 
     /// Get the list of forums that belong to a particular game family, by that game family's ID.
-    pub async fn get_game_family_forums(&self, id: u64) -> Result<ForumGroup> {
-        self.get_forum_group_by_id_and_type(id, ItemDomain::Family)
+    pub async fn get_game_family_forums(&self, id: FamilyId) -> Result<ForumGroup> {
+        self.get_forum_group_by_id_and_type(id.0, ItemDomain::Family)
             .await
     }
 
@@ -62,11 +64,13 @@ impl<'api> ForumGroupApi<'api> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{DateTime, NaiveDate, Utc};
     use mockito::Matcher;
 
     use super::*;
-    use crate::ForumDetails;
+    use crate::{Domain, ForumDetails, ForumId, StaticTokenProvider};
 
     #[tokio::test]
     async fn get_game_forums() {
@@ -74,6 +78,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -90,7 +96,7 @@ mod tests {
             .create_async()
             .await;
 
-        let forum_group = api.forum_group().get_game_forums(13).await;
+        let forum_group = api.forum_group().get_game_forums(GameId(13)).await;
         mock.assert_async().await;
 
         assert!(forum_group.is_ok(), "error returned when okay expected");
@@ -103,7 +109,7 @@ mod tests {
                 forum_domain: ItemDomain::Item,
                 forums: vec![
                     ForumDetails {
-                        id: 297,
+                        id: ForumId(297),
                         title: "Reviews".to_owned(),
                         description: "Post your game reviews in this forum.".to_owned(),
                         no_posting: false,
@@ -112,7 +118,7 @@ mod tests {
                         last_post_date: None,
                     },
                     ForumDetails {
-                        id: 926,
+                        id: ForumId(926),
                         title: "Sessions".to_owned(),
                         description: "Post your session reports here.".to_owned(),
                         no_posting: false,
@@ -137,6 +143,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -153,7 +161,7 @@ mod tests {
             .create_async()
             .await;
 
-        let forum_group = api.forum_group().get_game_family_forums(1).await;
+        let forum_group = api.forum_group().get_game_family_forums(FamilyId(1)).await;
         mock.assert_async().await;
 
         assert!(forum_group.is_ok(), "error returned when okay expected");
@@ -165,7 +173,7 @@ mod tests {
                 domain_id: 1,
                 forum_domain: ItemDomain::Family,
                 forums: vec![ForumDetails {
-                    id: 123,
+                    id: ForumId(123),
                     title: "General".to_owned(),
                     description: "General discussion about this family of items.".to_owned(),
                     no_posting: false,
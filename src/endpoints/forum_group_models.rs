@@ -2,47 +2,49 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use crate::deserialize::deserialize_maybe_date_time_with_zone;
-use crate::ItemDomain;
+use crate::{ForumId, ItemDomain};
 
 /// All forums specific to a certain game or game family.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ForumGroup {
     /// The ID of the game or game family that these forums pertain to.
-    #[serde(rename = "@id")]
+    #[serde(rename(deserialize = "@id"))]
     pub domain_id: u64,
     /// The type of domain, whether it is for a game or a game family.
-    #[serde(rename = "@type")]
+    #[serde(rename(deserialize = "@type"))]
     pub forum_domain: ItemDomain,
     /// The forums in the group.
-    #[serde(rename = "forum")]
+    #[serde(rename(deserialize = "forum"))]
     pub forums: Vec<ForumDetails>,
 }
 
 /// The details for a particular forum. Threads in the forum can be fetched via the forum endpoint
 /// using the ID of the forum.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ForumDetails {
     /// The unique identifier for this forum.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: ForumId,
     /// The title of the forum.
-    #[serde(rename = "@title")]
+    #[serde(rename(deserialize = "@title"))]
     pub title: String,
     /// A brief description on what the forum is used for.
-    #[serde(rename = "@description")]
+    #[serde(rename(deserialize = "@description"))]
     pub description: String,
     /// True if posting in this forum is not allowed.
-    #[serde(rename = "@noposting")]
+    #[serde(rename(deserialize = "@noposting"))]
     pub no_posting: bool,
     /// The total number of threads in this forum.
-    #[serde(rename = "@numthreads")]
+    #[serde(rename(deserialize = "@numthreads"))]
     pub number_of_threads: u64,
     /// The total number of posts in this forum.
-    #[serde(rename = "@numposts")]
+    #[serde(rename(deserialize = "@numposts"))]
     pub number_of_posts: u64,
     /// The date and time of the last post in this forum, or none if there are no posts yet.
     #[serde(
-        rename = "@lastpostdate",
+        rename(deserialize = "@lastpostdate"),
         deserialize_with = "deserialize_maybe_date_time_with_zone"
     )]
     pub last_post_date: Option<DateTime<Utc>>,
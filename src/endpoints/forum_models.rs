@@ -2,21 +2,23 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use crate::deserialize::deserialize_date_time_with_zone;
+use crate::{ForumId, ThreadId};
 
 /// A forum containing metadata, as well as a single page of threads in the forum.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Forum {
     /// The unique identifier for this forum.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: ForumId,
     /// The title of the forum, containing the topic that the threads should be related to.
-    #[serde(rename = "@title")]
+    #[serde(rename(deserialize = "@title"))]
     pub title: String,
     /// The total number of threads in this forum.
-    #[serde(rename = "@numthreads")]
+    #[serde(rename(deserialize = "@numthreads"))]
     pub number_of_threads: u64,
     /// The total number of posts in all the threads in this forum.
-    #[serde(rename = "@numposts")]
+    #[serde(rename(deserialize = "@numposts"))]
     pub number_of_posts: u64,
     /// Metadata for the threads in this forum
     #[serde(
@@ -34,28 +36,29 @@ pub struct Forum {
 
 /// Metadata for a thread, posts in the thread can be queried from the threads endpoint.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ThreadDetails {
     /// The ID of the thread.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: ThreadId,
     /// The subject of the thread.
-    #[serde(rename = "@subject")]
+    #[serde(rename(deserialize = "@subject"))]
     pub subject: String,
     /// The user that created the thread.
-    #[serde(rename = "@author")]
+    #[serde(rename(deserialize = "@author"))]
     pub author: String,
     /// The number of posts in the thread.
-    #[serde(rename = "@numarticles")]
+    #[serde(rename(deserialize = "@numarticles"))]
     pub number_of_articles: u64,
     /// The date that the thread was posted.
     #[serde(
-        rename = "@postdate",
+        rename(deserialize = "@postdate"),
         deserialize_with = "deserialize_date_time_with_zone"
     )]
     pub post_date: DateTime<Utc>,
     /// The date that the last post in the thread was posted.
     #[serde(
-        rename = "@lastpostdate",
+        rename(deserialize = "@lastpostdate"),
         deserialize_with = "deserialize_date_time_with_zone"
     )]
     pub last_post_date: DateTime<Utc>,
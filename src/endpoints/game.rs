@@ -1,5 +1,10 @@
-use super::{GameDetails, Games, ItemType};
-use crate::{BoardGameGeekApi, Error, IntoQueryParam, QueryParam, Result};
+use super::{
+    parse_raw_items, GameDetails, GameVersion, Games, ItemType, RawGames, RawItem, Versions,
+};
+use crate::api::parse_response_with;
+use crate::{
+    BoardGameGeekApi, Error, GameId, IntoQueryParam, ItemId, QueryParam, Result, VersionId,
+};
 
 /// All optional query parameters for making a request to the game endpoint.
 #[derive(Clone, Debug, Default)]
@@ -94,24 +99,48 @@ impl GameQueryParams {
 // Struct for building a query for the request to the game endpoint.
 #[derive(Clone, Debug)]
 struct GameQueryBuilder<'builder> {
-    game_ids: &'builder [u64],
+    game_ids: Vec<u64>,
+    // Overrides the types of item to request, which are otherwise games and expansions.
+    item_types: Option<&'builder [ItemType]>,
     params: &'builder GameQueryParams,
 }
 
 impl<'builder> GameQueryBuilder<'builder> {
     // Constructs a new query builder from a list of IDs to request, and the rest of the
     // parameters.
-    fn new(game_ids: &'builder [u64], params: &'builder GameQueryParams) -> Self {
-        Self { game_ids, params }
+    fn new(game_ids: &[GameId], params: &'builder GameQueryParams) -> Self {
+        Self {
+            game_ids: game_ids.iter().map(|id| id.0).collect(),
+            item_types: None,
+            params,
+        }
+    }
+
+    // Constructs a new query builder for items of any type, rather than just games and
+    // expansions.
+    fn with_item_types(
+        ids: &[impl ItemId],
+        item_types: &'builder [ItemType],
+        params: &'builder GameQueryParams,
+    ) -> Self {
+        Self {
+            game_ids: ids.iter().map(|&id| id.into()).collect(),
+            item_types: Some(item_types),
+            params,
+        }
     }
 
     // Converts the list of parameters into a vector of
     // key value pairs that reqwest can use as HTTP query parameters.
     fn build(self) -> Vec<QueryParam<'builder>> {
         let mut query_params: Vec<_> = vec![];
-        let default_types = vec![ItemType::BoardGame, ItemType::BoardGameExpansion];
-
-        query_params.push(default_types.into_query_param("type"));
+        match self.item_types {
+            Some(item_types) => query_params.push(item_types.into_query_param("type")),
+            None => {
+                let default_types = vec![ItemType::BoardGame, ItemType::BoardGameExpansion];
+                query_params.push(default_types.into_query_param("type"));
+            },
+        }
         query_params.push(true.into_query_param("stats"));
         query_params.push(self.game_ids.into_query_param("id"));
         if let Some(include_versions) = self.params.include_versions {
@@ -157,7 +186,11 @@ impl<'api> GameApi<'api> {
     }
 
     /// Searches for a board game or expansion by a given ID.
-    pub async fn get_by_id(&self, id: u64, query_params: &GameQueryParams) -> Result<GameDetails> {
+    pub async fn get_by_id(
+        &self,
+        id: GameId,
+        query_params: &GameQueryParams,
+    ) -> Result<GameDetails> {
         let id_vec = &[id];
         let query = GameQueryBuilder::new(id_vec, query_params);
 
@@ -177,7 +210,7 @@ impl<'api> GameApi<'api> {
     /// together.
     pub async fn get_by_ids(
         &self,
-        ids: &[u64],
+        ids: &[GameId],
         query_params: &GameQueryParams,
     ) -> Result<Vec<GameDetails>> {
         let query = GameQueryBuilder::new(ids, query_params);
@@ -187,29 +220,141 @@ impl<'api> GameApi<'api> {
 
         Ok(games.games)
     }
+
+    /// Gets a version of a game, such as a translated edition, by its version ID.
+    ///
+    /// This is the ID of the version itself, as found in [`GameVersion::id`], rather than the ID
+    /// of the game it is a version of.
+    pub async fn get_version_by_id(&self, version_id: VersionId) -> Result<GameVersion> {
+        let mut versions = self.get_versions_by_ids(&[version_id]).await?;
+
+        match versions.len() {
+            0 => Err(Error::ItemNotFound),
+            1 => Ok(versions.remove(0)),
+            len => Err(Error::UnexpectedResponseError(format!(
+                "expected 1 version but got {len}",
+            ))),
+        }
+    }
+
+    /// Gets versions of games by their version IDs. The versions can be of different games.
+    pub async fn get_versions_by_ids(&self, version_ids: &[VersionId]) -> Result<Vec<GameVersion>> {
+        let query_params = GameQueryParams::new();
+        let query = GameQueryBuilder::with_item_types(
+            version_ids,
+            &[ItemType::BoardGameVersion],
+            &query_params,
+        );
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let versions = self.api.execute_request::<Versions>(request).await?;
+
+        Ok(versions.versions)
+    }
+
+    /// Requests board games or expansions by given IDs, without parsing the response.
+    ///
+    /// The games can then be parsed with [`RawGames::parse`], as [`GameDetailsRef`]s that borrow
+    /// from the response rather than copying from it. This is useful for bulk requests where only
+    /// a few fields of each game are needed.
+    ///
+    /// [`GameDetailsRef`]: crate::GameDetailsRef
+    pub async fn get_raw_by_ids(
+        &self,
+        ids: &[GameId],
+        query_params: &GameQueryParams,
+    ) -> Result<RawGames> {
+        let query = GameQueryBuilder::new(ids, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let text = self.api.execute_raw_request(request).await?;
+
+        Ok(RawGames { text })
+    }
+
+    /// Searches for board games or expansions by given IDs, returning each as a generic
+    /// [`RawItem`] rather than [`GameDetails`].
+    ///
+    /// This can be used to read any data in the response that [`GameDetails`] leaves out.
+    pub async fn get_raw_items_by_ids(
+        &self,
+        ids: &[GameId],
+        query_params: &GameQueryParams,
+    ) -> Result<Vec<RawItem>> {
+        let query = GameQueryBuilder::new(ids, query_params);
+        self.execute_raw_items_request(query).await
+    }
+
+    /// Searches for items of any type by given IDs, returning each as a generic [`RawItem`].
+    ///
+    /// Only items of the given types are returned, such as [`ItemType::RpgItem`], so this can be
+    /// used for types of item which don't have their own models.
+    pub async fn get_raw_items_by_ids_and_types(
+        &self,
+        ids: &[impl ItemId],
+        item_types: &[ItemType],
+        query_params: &GameQueryParams,
+    ) -> Result<Vec<RawItem>> {
+        let query = GameQueryBuilder::with_item_types(ids, item_types, query_params);
+        self.execute_raw_items_request(query).await
+    }
+
+    async fn execute_raw_items_request(&self, query: GameQueryBuilder<'_>) -> Result<Vec<RawItem>> {
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let text = self.api.execute_raw_request(request).await?;
+
+        parse_response_with(&text, parse_raw_items)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{Duration, TimeZone, Utc};
     use mockito::Matcher;
 
     use super::*;
+    use crate::deserialize::deserialize_xml_string;
     use crate::{
-        Dimensions, Game, GameAccessory, GameArtist, GameCategory, GameDesigner, GameFamilyName,
-        GameMechanic, GamePublisher, GameStats, GameType, GameVersion, ItemCondition,
-        ItemFamilyRank, Language, LanguageDependence, LanguageDependencePoll, MarketplaceListing,
-        PlayerAge, PlayerCount, PollSummary, Price, RankValue, RatingComment, RatingCommentPage,
-        RatingValue, SuggestedPlayerAge, SuggestedPlayerAgePoll, SuggestedPlayerCount,
-        SuggestedPlayerCountPoll, UserBrief, Video, VideoCategory,
+        sort_listings_by_price, AccessoryId, CategoryId, Currency, Decimal, Dimensions, Domain,
+        ExchangeRates, FamilyId, Game, GameAccessory, GameArtist, GameCategory, GameDesigner,
+        GameDetailsRef, GameFamilyName, GameId, GameImplementation, GameMechanic, GamePublisher,
+        GameStats, GameType, GameVersion, ItemCondition, ItemFamilyRank, ItemLink, Language,
+        LanguageDependence, LanguageDependencePoll, LanguageId, Length, LinkDirection,
+        ListingFilter, MarketplaceListing, Mass, MechanicId, ObjectId, PersonId, PlayerAge,
+        PlayerCount, Poll, PollResult, PollResults, PollSummary, PollSummaryResult, Price,
+        PublisherId, RankId, RankValue, RatingComment, RatingCommentPage, RatingValue, RawLink,
+        RawName, RpgItemId, StaticTokenProvider, SuggestedPlayerAge, SuggestedPlayerAgePoll,
+        SuggestedPlayerCount, SuggestedPlayerCountPoll, UserBrief, UserId, VersionId, Video,
+        VideoCategory, VideoId, XmlElement,
     };
 
+    fn poll_result(value: &str, level: Option<u64>, votes: u64) -> PollResult {
+        PollResult {
+            value: value.to_owned(),
+            level,
+            votes,
+        }
+    }
+
+    fn item_link(link_type: ItemType, id: u64, name: &str, direction: LinkDirection) -> ItemLink {
+        ItemLink {
+            link_type,
+            id: ObjectId(id),
+            name: name.to_owned(),
+            direction,
+        }
+    }
+
     #[tokio::test]
     async fn get_by_id() {
         let mut server = mockito::Server::new_async().await;
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -227,7 +372,10 @@ mod tests {
             .create_async()
             .await;
 
-        let game = api.game().get_by_id(312_484, &GameQueryParams::new()).await;
+        let game = api
+            .game()
+            .get_by_id(GameId(312_484), &GameQueryParams::new())
+            .await;
         mock.assert_async().await;
 
         assert!(game.is_ok(), "error returned when okay expected");
@@ -236,7 +384,7 @@ mod tests {
         assert_eq!(
             game,
             GameDetails {
-                id: 312_484,
+                id: GameId(312_484),
                 game_type: GameType::BoardGame,
                 name: "Lost Ruins of Arnak".to_owned(),
                 alternate_names: vec![
@@ -248,154 +396,144 @@ mod tests {
                 year_published: 2020,
                 min_players: 1,
                 max_players: 4,
-                suggested_player_count: SuggestedPlayerCountPoll {
-                    title: "User Suggested Number of Players".to_owned(),
-                    total_voters: 889,
-                    results: vec![
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(1),
-                            best_votes: 88,
-                            recommended_votes: 337,
-                            not_recommended_votes: 126,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(2),
-                            best_votes: 225,
-                            recommended_votes: 506,
-                            not_recommended_votes: 35,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(3),
-                            best_votes: 512,
-                            recommended_votes: 202,
-                            not_recommended_votes: 12,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(4),
-                            best_votes: 176,
-                            recommended_votes: 385,
-                            not_recommended_votes: 95,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::PlayersOrAbove(4),
-                            best_votes: 1,
-                            recommended_votes: 0,
-                            not_recommended_votes: 361,
-                        },
-                    ],
-                    summary: Some(PollSummary {
-                        best_with: "Best with 3 players".to_owned(),
-                        recommended_with: "Recommended with 1–4 players".to_owned(),
-                    }),
-                },
                 playing_time: Duration::minutes(120),
                 min_play_time: Duration::minutes(30),
                 max_play_time: Duration::minutes(120),
                 min_age: 12,
-                suggested_player_age: SuggestedPlayerAgePoll {
-                    title: "User Suggested Player Age".to_owned(),
-                    total_voters: 178,
-                    results: vec![
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(6),
-                            votes: 3,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(8),
-                            votes: 17,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(10),
-                            votes: 75,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(14),
-                            votes: 10,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(16),
-                            votes: 1,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(18),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::AgeOrAbove(21),
-                            votes: 0,
-                        },
-                    ],
-                    summary: None,
-                },
-                suggested_language_dependence: LanguageDependencePoll {
-                    title: "Language Dependence".to_owned(),
-                    total_voters: 39,
-                    results: vec![
-                        LanguageDependence {
-                            level: 1,
-                            dependence: "No necessary in-game text".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 2,
-                            dependence: "Some necessary text - easily memorized or small crib sheet".to_owned(),
-                            votes: 4,
-                        },
-                        LanguageDependence {
-                            level: 3,
-                            dependence: "Moderate in-game text - needs crib sheet or paste ups".to_owned(),
-                            votes: 28,
-                        },
-                        LanguageDependence {
-                            level: 4,
-                            dependence: "Extensive use of text - massive conversion needed to be playable".to_owned(),
-                            votes: 5,
-                        },
-                        LanguageDependence {
-                            level: 5,
-                            dependence: "Unplayable in another language".to_owned(),
-                            votes: 2,
-                        },
-                    ],
-                    summary: None,
-                },
+                polls: vec![
+                    Poll {
+                        name: "suggested_numplayers".to_owned(),
+                        title: "User Suggested Number of Players".to_owned(),
+                        total_votes: 889,
+                        results: vec![
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(1)),
+                                results: vec![
+                                    poll_result("Best", None, 88),
+                                    poll_result("Recommended", None, 337),
+                                    poll_result("Not Recommended", None, 126),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(2)),
+                                results: vec![
+                                    poll_result("Best", None, 225),
+                                    poll_result("Recommended", None, 506),
+                                    poll_result("Not Recommended", None, 35),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(3)),
+                                results: vec![
+                                    poll_result("Best", None, 512),
+                                    poll_result("Recommended", None, 202),
+                                    poll_result("Not Recommended", None, 12),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(4)),
+                                results: vec![
+                                    poll_result("Best", None, 176),
+                                    poll_result("Recommended", None, 385),
+                                    poll_result("Not Recommended", None, 95),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::PlayersOrAbove(4)),
+                                results: vec![
+                                    poll_result("Best", None, 1),
+                                    poll_result("Recommended", None, 0),
+                                    poll_result("Not Recommended", None, 361),
+                                ],
+                            },
+                        ],
+                        summary: vec![
+                            PollSummaryResult {
+                                name: "bestwith".to_owned(),
+                                value: "Best with 3 players".to_owned(),
+                            },
+                            PollSummaryResult {
+                                name: "recommmendedwith".to_owned(),
+                                value: "Recommended with 1–4 players".to_owned(),
+                            },
+                        ],
+                    },
+                    Poll {
+                        name: "suggested_playerage".to_owned(),
+                        title: "User Suggested Player Age".to_owned(),
+                        total_votes: 178,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("6", None, 3),
+                                    poll_result("8", None, 17),
+                                    poll_result("10", None, 75),
+                                    poll_result("14", None, 10),
+                                    poll_result("16", None, 1),
+                                    poll_result("18", None, 0),
+                                    poll_result("21 and up", None, 0),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "language_dependence".to_owned(),
+                        title: "Language Dependence".to_owned(),
+                        total_votes: 39,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("No necessary in-game text", Some(1), 0),
+                                    poll_result("Some necessary text - easily memorized or small crib sheet", Some(2), 4),
+                                    poll_result("Moderate in-game text - needs crib sheet or paste ups", Some(3), 28),
+                                    poll_result("Extensive use of text - massive conversion needed to be playable", Some(4), 5),
+                                    poll_result("Unplayable in another language", Some(5), 2),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                ],
                 categories: vec![
                     GameCategory {
-                        id: 1020,
+                        id: CategoryId(1020),
                         name: "Exploration".to_owned(),
                     },
                     GameCategory {
-                        id: 1097,
+                        id: CategoryId(1097),
                         name: "Travel".to_owned(),
                     },
                 ],
                 mechanics: vec![
                     GameMechanic {
-                        id: 2664,
+                        id: MechanicId(2664),
                         name: "Deck, Bag, and Pool Building".to_owned(),
                     },
                     GameMechanic {
-                        id: 2041,
+                        id: MechanicId(2041),
                         name: "Open Drafting".to_owned(),
                     },
                     GameMechanic {
-                        id: 2082,
+                        id: MechanicId(2082),
                         name: "Worker Placement".to_owned(),
                     },
                 ],
                 game_families: vec![
                     GameFamilyName {
-                        id: 5666,
+                        id: FamilyId(5666),
                         name: "Players: Games with Solitaire Rules".to_owned(),
                     },
                     GameFamilyName {
-                        id: 21940,
+                        id: FamilyId(21940),
                         name: "Theme: Archaeology / Paleontology".to_owned(),
                     },
                 ],
                 expansions: vec![
                     Game {
-                        id: 341_254,
+                        id: GameId(341_254),
                         name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
                     },
                 ],
@@ -406,30 +544,45 @@ mod tests {
                 reimplementations: vec![],
                 designers: vec![
                     GameDesigner {
-                        id: 127_823,
+                        id: PersonId(127_823),
                         name: "Design".to_owned(),
                     },
                     GameDesigner {
-                        id: 127_822,
+                        id: PersonId(127_822),
                         name: "Er".to_owned(),
                     },
                 ],
                 artists: vec![
                     GameArtist {
-                        id: 152_613,
+                        id: PersonId(152_613),
                         name: "Artist person".to_owned(),
                     },
                     GameArtist {
-                        id: 115_373,
+                        id: PersonId(115_373),
                         name: "Another Artist person".to_owned(),
                     },
                 ],
                 publishers: vec![
                     GamePublisher {
-                        id: 1391,
+                        id: PublisherId(1391),
                         name: "Hobby Japan".to_owned(),
                     },
                 ],
+                links: vec![
+                    item_link(ItemType::BoardGameCategory, 1020, "Exploration", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameCategory, 1097, "Travel", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2664, "Deck, Bag, and Pool Building", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2041, "Open Drafting", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2082, "Worker Placement", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 5666, "Players: Games with Solitaire Rules", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 21_940, "Theme: Archaeology / Paleontology", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameExpansion, 341_254, "Lost Ruins of Arnak: Expedition Leaders", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_823, "Design", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_822, "Er", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 152_613, "Artist person", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 115_373, "Another Artist person", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 1391, "Hobby Japan", LinkDirection::Outbound),
+                ],
                 stats: GameStats {
                     users_rated: 45233,
                     average_rating: 8.07243,
@@ -437,7 +590,7 @@ mod tests {
                     standard_deviation: 1.24187,
                     median: 0.0,
                     rank: ItemFamilyRank {
-                        id: 1,
+                        id: RankId(1),
                         name: "boardgame".to_owned(),
                         friendly_name: "Board Game Rank".to_owned(),
                         value: RankValue::Ranked(28),
@@ -445,7 +598,7 @@ mod tests {
                     },
                     sub_family_ranks: vec![
                         ItemFamilyRank {
-                            id: 5497,
+                            id: RankId(5497),
                             name: "strategygames".to_owned(),
                             friendly_name: "Strategy Game Rank".to_owned(),
                             value: RankValue::Ranked(29),
@@ -466,6 +619,266 @@ mod tests {
                 rating_comments: None,
             },
         );
+
+        assert_eq!(
+            game.suggested_player_count(),
+            Some(SuggestedPlayerCountPoll {
+                title: "User Suggested Number of Players".to_owned(),
+                total_voters: 889,
+                results: vec![
+                    SuggestedPlayerCount {
+                        player_count: PlayerCount::Players(1),
+                        best_votes: 88,
+                        recommended_votes: 337,
+                        not_recommended_votes: 126,
+                    },
+                    SuggestedPlayerCount {
+                        player_count: PlayerCount::Players(2),
+                        best_votes: 225,
+                        recommended_votes: 506,
+                        not_recommended_votes: 35,
+                    },
+                    SuggestedPlayerCount {
+                        player_count: PlayerCount::Players(3),
+                        best_votes: 512,
+                        recommended_votes: 202,
+                        not_recommended_votes: 12,
+                    },
+                    SuggestedPlayerCount {
+                        player_count: PlayerCount::Players(4),
+                        best_votes: 176,
+                        recommended_votes: 385,
+                        not_recommended_votes: 95,
+                    },
+                    SuggestedPlayerCount {
+                        player_count: PlayerCount::PlayersOrAbove(4),
+                        best_votes: 1,
+                        recommended_votes: 0,
+                        not_recommended_votes: 361,
+                    },
+                ],
+                summary: Some(PollSummary {
+                    best_with: "Best with 3 players".to_owned(),
+                    recommended_with: "Recommended with 1–4 players".to_owned(),
+                }),
+            }),
+        );
+        assert_eq!(
+            game.suggested_player_age(),
+            Some(SuggestedPlayerAgePoll {
+                title: "User Suggested Player Age".to_owned(),
+                total_voters: 178,
+                results: vec![
+                    SuggestedPlayerAge {
+                        player_age: PlayerAge::Age(6),
+                        votes: 3,
+                    },
+                    SuggestedPlayerAge {
+                        player_age: PlayerAge::Age(8),
+                        votes: 17,
+                    },
+                    SuggestedPlayerAge {
+                        player_age: PlayerAge::Age(10),
+                        votes: 75,
+                    },
+                    SuggestedPlayerAge {
+                        player_age: PlayerAge::Age(14),
+                        votes: 10,
+                    },
+                    SuggestedPlayerAge {
+                        player_age: PlayerAge::Age(16),
+                        votes: 1,
+                    },
+                    SuggestedPlayerAge {
+                        player_age: PlayerAge::Age(18),
+                        votes: 0,
+                    },
+                    SuggestedPlayerAge {
+                        player_age: PlayerAge::AgeOrAbove(21),
+                        votes: 0,
+                    },
+                ],
+                summary: None,
+            }),
+        );
+        assert_eq!(
+            game.suggested_language_dependence(),
+            Some(LanguageDependencePoll {
+                title: "Language Dependence".to_owned(),
+                total_voters: 39,
+                results: vec![
+                    LanguageDependence {
+                        level: 1,
+                        dependence: "No necessary in-game text".to_owned(),
+                        votes: 0,
+                    },
+                    LanguageDependence {
+                        level: 2,
+                        dependence: "Some necessary text - easily memorized or small crib sheet"
+                            .to_owned(),
+                        votes: 4,
+                    },
+                    LanguageDependence {
+                        level: 3,
+                        dependence: "Moderate in-game text - needs crib sheet or paste ups"
+                            .to_owned(),
+                        votes: 28,
+                    },
+                    LanguageDependence {
+                        level: 4,
+                        dependence:
+                            "Extensive use of text - massive conversion needed to be playable"
+                                .to_owned(),
+                        votes: 5,
+                    },
+                    LanguageDependence {
+                        level: 5,
+                        dependence: "Unplayable in another language".to_owned(),
+                        votes: 2,
+                    },
+                ],
+                summary: None,
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_id_reimplementation() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgame,boardgameexpansion".to_owned()),
+                Matcher::UrlEncoded("stats".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "312484".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game/game_reimplementation.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let game = api
+            .game()
+            .get_by_id(GameId(312_484), &GameQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(game.is_ok(), "error returned when okay expected");
+        let game = game.unwrap();
+
+        // Both directions end up in the same list of reimplementations.
+        assert_eq!(
+            game.reimplementations,
+            vec![
+                GameImplementation {
+                    id: GameId(402_045),
+                    name: "Lost Ruins of Arnak: Pocket Edition".to_owned(),
+                },
+                GameImplementation {
+                    id: GameId(216_132),
+                    name: "Clans of Caledonia".to_owned(),
+                },
+            ],
+        );
+        assert_eq!(
+            game.reimplemented_by(),
+            vec![&item_link(
+                ItemType::BoardGameImplementation,
+                402_045,
+                "Lost Ruins of Arnak: Pocket Edition",
+                LinkDirection::Outbound,
+            )],
+        );
+        assert_eq!(
+            game.reimplements(),
+            vec![&item_link(
+                ItemType::BoardGameImplementation,
+                216_132,
+                "Clans of Caledonia",
+                LinkDirection::Inbound,
+            )],
+        );
+        assert_eq!(
+            game.links_of_type(ItemType::BoardGameImplementation).len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_id_extra_poll() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgame,boardgameexpansion".to_owned()),
+                Matcher::UrlEncoded("stats".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "312484".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game/game_extra_poll.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let game = api
+            .game()
+            .get_by_id(GameId(312_484), &GameQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(game.is_ok(), "error returned when okay expected");
+        let game = game.unwrap();
+
+        // Polls that aren't parsed into a typed poll are still kept, along with their summary.
+        assert_eq!(game.polls.len(), 4);
+        assert_eq!(
+            game.poll("suggested_weight"),
+            Some(&Poll {
+                name: "suggested_weight".to_owned(),
+                title: "User Suggested Game Weight".to_owned(),
+                total_votes: 12,
+                results: vec![PollResults {
+                    player_count: None,
+                    results: vec![
+                        poll_result("Light", Some(1), 1),
+                        poll_result("Medium Light", Some(2), 3),
+                        poll_result("Medium", Some(3), 8),
+                    ],
+                }],
+                summary: vec![PollSummaryResult {
+                    name: "mostvoted".to_owned(),
+                    value: "Medium".to_owned(),
+                }],
+            }),
+        );
+
+        // The typed polls are read from the generic ones, including their summaries.
+        assert_eq!(
+            game.suggested_player_count().and_then(|poll| poll.summary),
+            Some(PollSummary {
+                best_with: "Best with 3 players".to_owned(),
+                recommended_with: "Recommended with 1–4 players".to_owned(),
+            }),
+        );
     }
 
     #[tokio::test]
@@ -474,6 +887,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -491,7 +906,10 @@ mod tests {
             .create_async()
             .await;
 
-        let game = api.game().get_by_id(341_254, &GameQueryParams::new()).await;
+        let game = api
+            .game()
+            .get_by_id(GameId(341_254), &GameQueryParams::new())
+            .await;
         mock.assert_async().await;
 
         assert!(game.is_ok(), "error returned when okay expected");
@@ -500,7 +918,7 @@ mod tests {
         assert_eq!(
             game,
             GameDetails {
-                id: 341_254,
+                id: GameId(341_254),
                 game_type: GameType::BoardGameExpansion,
                 name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
                 alternate_names: vec![
@@ -512,162 +930,146 @@ mod tests {
                 year_published: 2021,
                 min_players: 1,
                 max_players: 4,
-                suggested_player_count: SuggestedPlayerCountPoll {
-                    title: "User Suggested Number of Players".to_owned(),
-                    total_voters: 87,
-                    results: vec![
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(1),
-                            best_votes: 8,
-                            recommended_votes: 45,
-                            not_recommended_votes: 16,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(2),
-                            best_votes: 26,
-                            recommended_votes: 50,
-                            not_recommended_votes: 1,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(3),
-                            best_votes: 53,
-                            recommended_votes: 18,
-                            not_recommended_votes: 1,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(4),
-                            best_votes: 15,
-                            recommended_votes: 44,
-                            not_recommended_votes: 9,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::PlayersOrAbove(4),
-                            best_votes: 0,
-                            recommended_votes: 1,
-                            not_recommended_votes: 42,
-                        },
-                    ],
-                    summary: None,
-                },
                 playing_time: Duration::minutes(120),
                 min_play_time: Duration::minutes(30),
                 max_play_time: Duration::minutes(120),
                 min_age: 12,
-                suggested_player_age: SuggestedPlayerAgePoll {
-                    title: "User Suggested Player Age".to_owned(),
-                    total_voters: 20,
-                    results: vec![
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(6),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(8),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(10),
-                            votes: 6,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(14),
-                            votes: 1,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(16),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(18),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::AgeOrAbove(21),
-                            votes: 0,
-                        },
-                    ],
-                    summary: None,
-                },
-                suggested_language_dependence: LanguageDependencePoll {
-                    title: "Language Dependence".to_owned(),
-                    total_voters: 6,
-                    results: vec![
-                        LanguageDependence {
-                            level: 1,
-                            dependence: "No necessary in-game text".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 2,
-                            dependence: "Some necessary text - easily memorized or small crib sheet".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 3,
-                            dependence: "Moderate in-game text - needs crib sheet or paste ups".to_owned(),
-                            votes: 5,
-                        },
-                        LanguageDependence {
-                            level: 4,
-                            dependence: "Extensive use of text - massive conversion needed to be playable".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 5,
-                            dependence: "Unplayable in another language".to_owned(),
-                            votes: 1,
-                        },
-                    ],
-                    summary: None,
-                },
+                polls: vec![
+                    Poll {
+                        name: "suggested_numplayers".to_owned(),
+                        title: "User Suggested Number of Players".to_owned(),
+                        total_votes: 87,
+                        results: vec![
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(1)),
+                                results: vec![
+                                    poll_result("Best", None, 8),
+                                    poll_result("Recommended", None, 45),
+                                    poll_result("Not Recommended", None, 16),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(2)),
+                                results: vec![
+                                    poll_result("Best", None, 26),
+                                    poll_result("Recommended", None, 50),
+                                    poll_result("Not Recommended", None, 1),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(3)),
+                                results: vec![
+                                    poll_result("Best", None, 53),
+                                    poll_result("Recommended", None, 18),
+                                    poll_result("Not Recommended", None, 1),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(4)),
+                                results: vec![
+                                    poll_result("Best", None, 15),
+                                    poll_result("Recommended", None, 44),
+                                    poll_result("Not Recommended", None, 9),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::PlayersOrAbove(4)),
+                                results: vec![
+                                    poll_result("Best", None, 0),
+                                    poll_result("Recommended", None, 1),
+                                    poll_result("Not Recommended", None, 42),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "suggested_playerage".to_owned(),
+                        title: "User Suggested Player Age".to_owned(),
+                        total_votes: 20,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("6", None, 0),
+                                    poll_result("8", None, 0),
+                                    poll_result("10", None, 6),
+                                    poll_result("14", None, 1),
+                                    poll_result("16", None, 0),
+                                    poll_result("18", None, 0),
+                                    poll_result("21 and up", None, 0),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "language_dependence".to_owned(),
+                        title: "Language Dependence".to_owned(),
+                        total_votes: 6,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("No necessary in-game text", Some(1), 0),
+                                    poll_result("Some necessary text - easily memorized or small crib sheet", Some(2), 0),
+                                    poll_result("Moderate in-game text - needs crib sheet or paste ups", Some(3), 5),
+                                    poll_result("Extensive use of text - massive conversion needed to be playable", Some(4), 0),
+                                    poll_result("Unplayable in another language", Some(5), 1),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                ],
                 categories: vec![
                     GameCategory {
-                        id: 1042,
+                        id: CategoryId(1042),
                         name: "Expansion for Base-game".to_owned(),
                     },
                     GameCategory {
-                        id: 1020,
+                        id: CategoryId(1020),
                         name: "Exploration".to_owned(),
                     },
                     GameCategory {
-                        id: 1097,
+                        id: CategoryId(1097),
                         name: "Travel".to_owned(),
                     },
                 ],
                 mechanics: vec![
                     GameMechanic {
-                        id: 2664,
+                        id: MechanicId(2664),
                         name: "Deck, Bag, and Pool Building".to_owned(),
                     },
                     GameMechanic {
-                        id: 2041,
+                        id: MechanicId(2041),
                         name: "Open Drafting".to_owned(),
                     },
                     GameMechanic {
-                        id: 2082,
+                        id: MechanicId(2082),
                         name: "Worker Placement".to_owned(),
                     },
                 ],
                 game_families: vec![
                     GameFamilyName {
-                        id: 5666,
+                        id: FamilyId(5666),
                         name: "Players: Games with Solitaire Rules".to_owned(),
                     },
                     GameFamilyName {
-                        id: 21940,
+                        id: FamilyId(21940),
                         name: "Theme: Archaeology / Paleontology".to_owned(),
                     },
                 ],
                 expansions: vec![],
                 expansion_for: vec![
                     Game {
-                        id: 312_484,
+                        id: GameId(312_484),
                         name: "Lost Ruins of Arnak".to_owned(),
                     },
                 ],
                 accessories: vec![
                     GameAccessory {
-                        id: 363_147,
+                        id: AccessoryId(363_147),
                         name: "Lost Ruins of Arnak + Expedition Leaders: Insert".to_owned(),
                     },
                 ],
@@ -676,21 +1078,36 @@ mod tests {
                 reimplementations: vec![],
                 designers: vec![
                     GameDesigner {
-                        id: 127_823,
+                        id: PersonId(127_823),
                         name: "Design".to_owned(),
                     },
                     GameDesigner {
-                        id: 127_822,
+                        id: PersonId(127_822),
                         name: "Er".to_owned(),
                     },
                 ],
                 artists: vec![],
                 publishers: vec![
                     GamePublisher {
-                        id: 1391,
+                        id: PublisherId(1391),
                         name: "Hobby Japan".to_owned(),
                     },
                 ],
+                links: vec![
+                    item_link(ItemType::BoardGameCategory, 1042, "Expansion for Base-game", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameCategory, 1020, "Exploration", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameCategory, 1097, "Travel", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2664, "Deck, Bag, and Pool Building", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2041, "Open Drafting", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2082, "Worker Placement", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 5666, "Players: Games with Solitaire Rules", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 21_940, "Theme: Archaeology / Paleontology", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameExpansion, 312_484, "Lost Ruins of Arnak", LinkDirection::Inbound),
+                    item_link(ItemType::BoardGameAccessory, 363_147, "Lost Ruins of Arnak + Expedition Leaders: Insert", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_823, "Design", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_822, "Er", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 1391, "Hobby Japan", LinkDirection::Outbound),
+                ],
                 stats: GameStats {
                     users_rated: 7103,
                     average_rating: 8.7037,
@@ -698,7 +1115,7 @@ mod tests {
                     standard_deviation: 1.00019,
                     median: 0.0,
                     rank: ItemFamilyRank {
-                        id: 1,
+                        id: RankId(1),
                         name: "boardgame".to_owned(),
                         friendly_name: "Board Game Rank".to_owned(),
                         value: RankValue::NotRanked,
@@ -706,7 +1123,7 @@ mod tests {
                     },
                     sub_family_ranks: vec![
                         ItemFamilyRank {
-                            id: 5497,
+                            id: RankId(5497),
                             name: "strategygames".to_owned(),
                             friendly_name: "Strategy Game Rank".to_owned(),
                             value: RankValue::NotRanked,
@@ -735,6 +1152,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -765,7 +1184,7 @@ mod tests {
             .include_comments(true)
             .page(1)
             .page_size(3);
-        let game = api.game().get_by_id(312_484, &params).await;
+        let game = api.game().get_by_id(GameId(312_484), &params).await;
         mock.assert_async().await;
 
         assert!(game.is_ok(), "error returned when okay expected");
@@ -774,7 +1193,7 @@ mod tests {
         assert_eq!(
             game,
             GameDetails {
-                id: 312_484,
+                id: GameId(312_484),
                 game_type: GameType::BoardGame,
                 name: "Lost Ruins of Arnak".to_owned(),
                 alternate_names: vec![
@@ -786,151 +1205,135 @@ mod tests {
                 year_published: 2020,
                 min_players: 1,
                 max_players: 4,
-                suggested_player_count: SuggestedPlayerCountPoll {
-                    title: "User Suggested Number of Players".to_owned(),
-                    total_voters: 889,
-                    results: vec![
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(1),
-                            best_votes: 88,
-                            recommended_votes: 337,
-                            not_recommended_votes: 126,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(2),
-                            best_votes: 225,
-                            recommended_votes: 506,
-                            not_recommended_votes: 35,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(3),
-                            best_votes: 512,
-                            recommended_votes: 202,
-                            not_recommended_votes: 12,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(4),
-                            best_votes: 176,
-                            recommended_votes: 385,
-                            not_recommended_votes: 95,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::PlayersOrAbove(4),
-                            best_votes: 1,
-                            recommended_votes: 0,
-                            not_recommended_votes: 361,
-                        },
-                    ],
-                    summary: None,
-                },
                 playing_time: Duration::minutes(120),
                 min_play_time: Duration::minutes(30),
                 max_play_time: Duration::minutes(120),
                 min_age: 12,
-                suggested_player_age: SuggestedPlayerAgePoll {
-                    title: "User Suggested Player Age".to_owned(),
-                    total_voters: 178,
-                    results: vec![
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(6),
-                            votes: 3,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(8),
-                            votes: 17,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(10),
-                            votes: 75,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(14),
-                            votes: 10,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(16),
-                            votes: 1,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(18),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::AgeOrAbove(21),
-                            votes: 0,
-                        },
-                    ],
-                    summary: None,
-                },
-                suggested_language_dependence: LanguageDependencePoll {
-                    title: "Language Dependence".to_owned(),
-                    total_voters: 39,
-                    results: vec![
-                        LanguageDependence {
-                            level: 1,
-                            dependence: "No necessary in-game text".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 2,
-                            dependence: "Some necessary text - easily memorized or small crib sheet".to_owned(),
-                            votes: 4,
-                        },
-                        LanguageDependence {
-                            level: 3,
-                            dependence: "Moderate in-game text - needs crib sheet or paste ups".to_owned(),
-                            votes: 28,
-                        },
-                        LanguageDependence {
-                            level: 4,
-                            dependence: "Extensive use of text - massive conversion needed to be playable".to_owned(),
-                            votes: 5,
-                        },
-                        LanguageDependence {
-                            level: 5,
-                            dependence: "Unplayable in another language".to_owned(),
-                            votes: 2,
-                        },
-                    ],
-                    summary: None,
-                },
+                polls: vec![
+                    Poll {
+                        name: "suggested_numplayers".to_owned(),
+                        title: "User Suggested Number of Players".to_owned(),
+                        total_votes: 889,
+                        results: vec![
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(1)),
+                                results: vec![
+                                    poll_result("Best", None, 88),
+                                    poll_result("Recommended", None, 337),
+                                    poll_result("Not Recommended", None, 126),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(2)),
+                                results: vec![
+                                    poll_result("Best", None, 225),
+                                    poll_result("Recommended", None, 506),
+                                    poll_result("Not Recommended", None, 35),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(3)),
+                                results: vec![
+                                    poll_result("Best", None, 512),
+                                    poll_result("Recommended", None, 202),
+                                    poll_result("Not Recommended", None, 12),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(4)),
+                                results: vec![
+                                    poll_result("Best", None, 176),
+                                    poll_result("Recommended", None, 385),
+                                    poll_result("Not Recommended", None, 95),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::PlayersOrAbove(4)),
+                                results: vec![
+                                    poll_result("Best", None, 1),
+                                    poll_result("Recommended", None, 0),
+                                    poll_result("Not Recommended", None, 361),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "suggested_playerage".to_owned(),
+                        title: "User Suggested Player Age".to_owned(),
+                        total_votes: 178,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("6", None, 3),
+                                    poll_result("8", None, 17),
+                                    poll_result("10", None, 75),
+                                    poll_result("14", None, 10),
+                                    poll_result("16", None, 1),
+                                    poll_result("18", None, 0),
+                                    poll_result("21 and up", None, 0),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "language_dependence".to_owned(),
+                        title: "Language Dependence".to_owned(),
+                        total_votes: 39,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("No necessary in-game text", Some(1), 0),
+                                    poll_result("Some necessary text - easily memorized or small crib sheet", Some(2), 4),
+                                    poll_result("Moderate in-game text - needs crib sheet or paste ups", Some(3), 28),
+                                    poll_result("Extensive use of text - massive conversion needed to be playable", Some(4), 5),
+                                    poll_result("Unplayable in another language", Some(5), 2),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                ],
                 categories: vec![
                     GameCategory {
-                        id: 1020,
+                        id: CategoryId(1020),
                         name: "Exploration".to_owned(),
                     },
                     GameCategory {
-                        id: 1097,
+                        id: CategoryId(1097),
                         name: "Travel".to_owned(),
                     },
                 ],
                 mechanics: vec![
                     GameMechanic {
-                        id: 2664,
+                        id: MechanicId(2664),
                         name: "Deck, Bag, and Pool Building".to_owned(),
                     },
                     GameMechanic {
-                        id: 2041,
+                        id: MechanicId(2041),
                         name: "Open Drafting".to_owned(),
                     },
                     GameMechanic {
-                        id: 2082,
+                        id: MechanicId(2082),
                         name: "Worker Placement".to_owned(),
                     },
                 ],
                 game_families: vec![
                     GameFamilyName {
-                        id: 5666,
+                        id: FamilyId(5666),
                         name: "Players: Games with Solitaire Rules".to_owned(),
                     },
                     GameFamilyName {
-                        id: 21940,
+                        id: FamilyId(21940),
                         name: "Theme: Archaeology / Paleontology".to_owned(),
                     },
                 ],
                 expansions: vec![
                     Game {
-                        id: 341_254,
+                        id: GameId(341_254),
                         name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
                     },
                 ],
@@ -941,30 +1344,45 @@ mod tests {
                 reimplementations: vec![],
                 designers: vec![
                     GameDesigner {
-                        id: 127_823,
+                        id: PersonId(127_823),
                         name: "Design".to_owned(),
                     },
                     GameDesigner {
-                        id: 127_822,
+                        id: PersonId(127_822),
                         name: "Er".to_owned(),
                     },
                 ],
                 artists: vec![
                     GameArtist {
-                        id: 152_613,
+                        id: PersonId(152_613),
                         name: "Artist person".to_owned(),
                     },
                     GameArtist {
-                        id: 115_373,
+                        id: PersonId(115_373),
                         name: "Another Artist person".to_owned(),
                     },
                 ],
                 publishers: vec![
                     GamePublisher {
-                        id: 1391,
+                        id: PublisherId(1391),
                         name: "Hobby Japan".to_owned(),
                     },
                 ],
+                links: vec![
+                    item_link(ItemType::BoardGameCategory, 1020, "Exploration", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameCategory, 1097, "Travel", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2664, "Deck, Bag, and Pool Building", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2041, "Open Drafting", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2082, "Worker Placement", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 5666, "Players: Games with Solitaire Rules", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 21_940, "Theme: Archaeology / Paleontology", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameExpansion, 341_254, "Lost Ruins of Arnak: Expedition Leaders", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_823, "Design", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_822, "Er", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 152_613, "Artist person", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 115_373, "Another Artist person", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 1391, "Hobby Japan", LinkDirection::Outbound),
+                ],
                 stats: GameStats {
                     users_rated: 45233,
                     average_rating: 8.07243,
@@ -972,7 +1390,7 @@ mod tests {
                     standard_deviation: 1.24187,
                     median: 0.0,
                     rank: ItemFamilyRank {
-                        id: 1,
+                        id: RankId(1),
                         name: "boardgame".to_owned(),
                         friendly_name: "Board Game Rank".to_owned(),
                         value: RankValue::Ranked(28),
@@ -980,7 +1398,7 @@ mod tests {
                     },
                     sub_family_ranks: vec![
                         ItemFamilyRank {
-                            id: 5497,
+                            id: RankId(5497),
                             name: "strategygames".to_owned(),
                             friendly_name: "Strategy Game Rank".to_owned(),
                             value: RankValue::Ranked(29),
@@ -997,63 +1415,63 @@ mod tests {
                 },
                 versions: vec![
                     GameVersion {
-                        id: 595_583,
+                        id: VersionId(595_583),
                         name: "Bulgarian edition".to_owned(),
                         alternate_names: vec![],
                         year_published: 2021,
                         image: Some("https://cf.geekdo-images.com/IE7u66EF0sVXBYFMAqu21g__original/img/M0KZEWD-IUjsvWNEpBxcrB1NmsU=/0x0/filters:format(png)/pic6622620.png".to_owned()),
                         thumbnail: Some("https://cf.geekdo-images.com/IE7u66EF0sVXBYFMAqu21g__thumb/img/kd7nulur0E6B6fvMVpfRH_MxCmg=/fit-in/200x150/filters:strip_icc()/pic6622620.png".to_owned()),
                         original_game: Game {
-                            id: 312_484,
+                            id: GameId(312_484),
                             name: "Lost Ruins of Arnak".to_owned(),
                         },
                         publishers: vec![
                             GamePublisher {
-                                id: 7345,
+                                id: PublisherId(7345),
                                 name: "Games".to_owned(),
                             },
                         ],
                         artists: vec![
                             GameArtist {
-                                id: 11961,
+                                id: PersonId(11961),
                                 name: "Art man".to_owned(),
                             },
                         ],
                         languages: vec![
                             Language {
-                                id: 2675,
+                                id: LanguageId(2675),
                                 name: "Bulgarian".to_owned(),
                             },
                         ],
                         dimensions: Some(Dimensions {
-                            width: 10.0394,
-                            length: 14.3701,
-                            depth: 2.75591,
+                            width: Some(Length::from_inches(10.0394)),
+                            length: Some(Length::from_inches(14.3701)),
+                            depth: Some(Length::from_inches(2.75591)),
                         }),
-                        weight: Some(5.2448),
+                        weight: Some(Mass::from_pounds(5.2448)),
                         product_code: Some("77240-BG".to_owned()),
                     },
                     GameVersion {
-                        id: 517_374,
+                        id: VersionId(517_374),
                         name: "French edition".to_owned(),
                         alternate_names: vec![],
                         year_published: 2021,
                         image: Some("https://cf.geekdo-images.com/RiyIlOey2KYj4Flwl1nOPg__original/img/IU0Aws6_XM22XdEBJJZMLzX8OuM=/0x0/filters:format(jpeg)/pic5531793.jpg".to_owned()),
                         thumbnail: Some("https://cf.geekdo-images.com/RiyIlOey2KYj4Flwl1nOPg__thumb/img/I4LpDfOcfDeF5sT2f9-UpI-k9SM=/fit-in/200x150/filters:strip_icc()/pic5531793.jpg".to_owned()),
                         original_game: Game {
-                            id: 312_484,
+                            id: GameId(312_484),
                             name: "Lost Ruins of Arnak".to_owned(),
                         },
                         publishers: vec![
                             GamePublisher {
-                                id: 7345,
+                                id: PublisherId(7345),
                                 name: "Games".to_owned(),
                             },
                         ],
                         artists: vec![],
                         languages: vec![
                             Language {
-                                id: 2187,
+                                id: LanguageId(2187),
                                 name: "French".to_owned(),
                             },
                         ],
@@ -1064,25 +1482,25 @@ mod tests {
                 ],
                 videos: vec![
                     Video {
-                        id: 510_883,
+                        id: VideoId(510_883),
                         title: "Some video".to_owned(),
                         category: VideoCategory::Other,
                         language: "French".to_owned(),
                         link: "http://www.youtube.com/watch?v=1".to_owned(),
                         uploader: UserBrief {
-                            user_id: 312,
+                            user_id: UserId(312),
                             username: "video_man".to_owned(),
                         },
                         post_date: Utc.with_ymd_and_hms(2024, 8, 25, 14, 57, 57).unwrap(),
                     },
                     Video {
-                        id: 504_090,
+                        id: VideoId(504_090),
                         title: "Arnak gameplay".to_owned(),
                         category: VideoCategory::Session,
                         language: "Portuguese".to_owned(),
                         link: "http://www.youtube.com/watch?v=2".to_owned(),
                         uploader: UserBrief {
-                            user_id: 333,
+                            user_id: UserId(333),
                             username: "video_man_2".to_owned(),
                         },
                         post_date: Utc.with_ymd_and_hms(2024, 7, 11, 21, 42, 52).unwrap(),
@@ -1092,8 +1510,8 @@ mod tests {
                     MarketplaceListing {
                         list_date: Utc.with_ymd_and_hms(2024, 2, 23, 22, 52, 48).unwrap(),
                         price: Price {
-                            currency: "USD".to_owned(),
-                            value: "44.99".to_owned(),
+                            currency: Currency::USD,
+                            value: Decimal::new(4499, 2),
                         },
                         condition: ItemCondition::New,
                         notes: "".to_owned(),
@@ -1102,8 +1520,8 @@ mod tests {
                     MarketplaceListing {
                         list_date: Utc.with_ymd_and_hms(2021, 2, 24, 13, 17, 47).unwrap(),
                         price: Price {
-                            currency: "EUR".to_owned(),
-                            value: "68.00".to_owned(),
+                            currency: Currency::EUR,
+                            value: Decimal::new(6800, 2),
                         },
                         condition: ItemCondition::LikeNew,
                         notes: "new in shrink.".to_owned(),
@@ -1112,8 +1530,8 @@ mod tests {
                     MarketplaceListing {
                         list_date: Utc.with_ymd_and_hms(2024, 7, 4, 15, 32, 15).unwrap(),
                         price: Price {
-                            currency: "USD".to_owned(),
-                            value: "25.00".to_owned(),
+                            currency: Currency::USD,
+                            value: Decimal::new(2500, 2),
                         },
                         condition: ItemCondition::VeryGood,
                         notes: "Buyer to pay shipping.".to_owned(),
@@ -1143,6 +1561,84 @@ mod tests {
                 }),
             },
         );
+
+        let dimensions = game.versions[0].dimensions.as_ref().unwrap();
+        assert!((dimensions.width.unwrap().centimetres() - 25.5).abs() < 0.001);
+        assert!((dimensions.footprint().unwrap().square_centimetres() - 930.754).abs() < 0.001);
+        assert!((dimensions.volume().unwrap().litres() - 6.515).abs() < 0.001);
+        assert!((game.versions[0].weight.unwrap().kilograms() - 2.379).abs() < 0.001);
+        assert_eq!(game.versions[1].weight, None);
+    }
+
+    #[tokio::test]
+    async fn get_by_id_listings_by_price() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game/game_all.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let params = GameQueryParams::new().include_marketplace_data(true);
+        let game = api.game().get_by_id(GameId(312_484), &params).await;
+        mock.assert_async().await;
+
+        assert!(game.is_ok(), "error returned when okay expected");
+        let mut listings = game.unwrap().marketplace_listings;
+
+        // Without a rate for euros, that listing can't be compared so is sorted last.
+        let rates = ExchangeRates::new(Currency::USD);
+        sort_listings_by_price(&mut listings, &rates);
+        let prices: Vec<_> = listings.iter().map(|l| l.price.value).collect();
+        assert_eq!(
+            prices,
+            vec![
+                Decimal::new(2500, 2),
+                Decimal::new(4499, 2),
+                Decimal::new(6800, 2),
+            ],
+        );
+
+        let rates = rates.rate(Currency::EUR, Decimal::new(2, 0));
+        sort_listings_by_price(&mut listings, &rates);
+        let prices: Vec<_> = listings.iter().map(|l| l.price.value).collect();
+        assert_eq!(
+            prices,
+            vec![
+                Decimal::new(2500, 2),
+                Decimal::new(6800, 2),
+                Decimal::new(4499, 2),
+            ],
+        );
+        assert_eq!(
+            listings[1].price.convert(Currency::USD, &rates),
+            Some(Price {
+                currency: Currency::USD,
+                value: Decimal::new(34, 0),
+            }),
+        );
+
+        let filter = ListingFilter::new()
+            .min_condition(ItemCondition::LikeNew)
+            .max_price(Decimal::new(40, 0));
+        let matching: Vec<_> = listings
+            .iter()
+            .filter(|listing| filter.matches(listing, &rates))
+            .map(|listing| listing.price.value)
+            .collect();
+        assert_eq!(matching, vec![Decimal::new(6800, 2)]);
     }
 
     #[tokio::test]
@@ -1151,6 +1647,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -1183,7 +1681,7 @@ mod tests {
             .page_size(3);
         let games = api
             .game()
-            .get_by_ids(&vec![312_484, 341_254], &params)
+            .get_by_ids(&[GameId(312_484), GameId(341_254)], &params)
             .await;
         mock.assert_async().await;
 
@@ -1194,7 +1692,7 @@ mod tests {
         assert_eq!(
             games[0],
             GameDetails {
-                id: 312_484,
+                id: GameId(312_484),
                 game_type: GameType::BoardGame,
                 name: "Lost Ruins of Arnak".to_owned(),
                 alternate_names: vec![
@@ -1206,151 +1704,135 @@ mod tests {
                 year_published: 2020,
                 min_players: 1,
                 max_players: 4,
-                suggested_player_count: SuggestedPlayerCountPoll {
-                    title: "User Suggested Number of Players".to_owned(),
-                    total_voters: 889,
-                    results: vec![
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(1),
-                            best_votes: 88,
-                            recommended_votes: 337,
-                            not_recommended_votes: 126,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(2),
-                            best_votes: 225,
-                            recommended_votes: 506,
-                            not_recommended_votes: 35,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(3),
-                            best_votes: 512,
-                            recommended_votes: 202,
-                            not_recommended_votes: 12,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(4),
-                            best_votes: 176,
-                            recommended_votes: 385,
-                            not_recommended_votes: 95,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::PlayersOrAbove(4),
-                            best_votes: 1,
-                            recommended_votes: 0,
-                            not_recommended_votes: 361,
-                        },
-                    ],
-                    summary: None,
-                },
                 playing_time: Duration::minutes(120),
                 min_play_time: Duration::minutes(30),
                 max_play_time: Duration::minutes(120),
                 min_age: 12,
-                suggested_player_age: SuggestedPlayerAgePoll {
-                    title: "User Suggested Player Age".to_owned(),
-                    total_voters: 178,
-                    results: vec![
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(6),
-                            votes: 3,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(8),
-                            votes: 17,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(10),
-                            votes: 75,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(14),
-                            votes: 10,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(16),
-                            votes: 1,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(18),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::AgeOrAbove(21),
-                            votes: 0,
-                        },
-                    ],
-                    summary: None,
-                },
-                suggested_language_dependence: LanguageDependencePoll {
-                    title: "Language Dependence".to_owned(),
-                    total_voters: 39,
-                    results: vec![
-                        LanguageDependence {
-                            level: 1,
-                            dependence: "No necessary in-game text".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 2,
-                            dependence: "Some necessary text - easily memorized or small crib sheet".to_owned(),
-                            votes: 4,
-                        },
-                        LanguageDependence {
-                            level: 3,
-                            dependence: "Moderate in-game text - needs crib sheet or paste ups".to_owned(),
-                            votes: 28,
-                        },
-                        LanguageDependence {
-                            level: 4,
-                            dependence: "Extensive use of text - massive conversion needed to be playable".to_owned(),
-                            votes: 5,
-                        },
-                        LanguageDependence {
-                            level: 5,
-                            dependence: "Unplayable in another language".to_owned(),
-                            votes: 2,
-                        },
-                    ],
-                    summary: None,
-                },
+                polls: vec![
+                    Poll {
+                        name: "suggested_numplayers".to_owned(),
+                        title: "User Suggested Number of Players".to_owned(),
+                        total_votes: 889,
+                        results: vec![
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(1)),
+                                results: vec![
+                                    poll_result("Best", None, 88),
+                                    poll_result("Recommended", None, 337),
+                                    poll_result("Not Recommended", None, 126),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(2)),
+                                results: vec![
+                                    poll_result("Best", None, 225),
+                                    poll_result("Recommended", None, 506),
+                                    poll_result("Not Recommended", None, 35),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(3)),
+                                results: vec![
+                                    poll_result("Best", None, 512),
+                                    poll_result("Recommended", None, 202),
+                                    poll_result("Not Recommended", None, 12),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(4)),
+                                results: vec![
+                                    poll_result("Best", None, 176),
+                                    poll_result("Recommended", None, 385),
+                                    poll_result("Not Recommended", None, 95),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::PlayersOrAbove(4)),
+                                results: vec![
+                                    poll_result("Best", None, 1),
+                                    poll_result("Recommended", None, 0),
+                                    poll_result("Not Recommended", None, 361),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "suggested_playerage".to_owned(),
+                        title: "User Suggested Player Age".to_owned(),
+                        total_votes: 178,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("6", None, 3),
+                                    poll_result("8", None, 17),
+                                    poll_result("10", None, 75),
+                                    poll_result("14", None, 10),
+                                    poll_result("16", None, 1),
+                                    poll_result("18", None, 0),
+                                    poll_result("21 and up", None, 0),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "language_dependence".to_owned(),
+                        title: "Language Dependence".to_owned(),
+                        total_votes: 39,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("No necessary in-game text", Some(1), 0),
+                                    poll_result("Some necessary text - easily memorized or small crib sheet", Some(2), 4),
+                                    poll_result("Moderate in-game text - needs crib sheet or paste ups", Some(3), 28),
+                                    poll_result("Extensive use of text - massive conversion needed to be playable", Some(4), 5),
+                                    poll_result("Unplayable in another language", Some(5), 2),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                ],
                 categories: vec![
                     GameCategory {
-                        id: 1020,
+                        id: CategoryId(1020),
                         name: "Exploration".to_owned(),
                     },
                     GameCategory {
-                        id: 1097,
+                        id: CategoryId(1097),
                         name: "Travel".to_owned(),
                     },
                 ],
                 mechanics: vec![
                     GameMechanic {
-                        id: 2664,
+                        id: MechanicId(2664),
                         name: "Deck, Bag, and Pool Building".to_owned(),
                     },
                     GameMechanic {
-                        id: 2041,
+                        id: MechanicId(2041),
                         name: "Open Drafting".to_owned(),
                     },
                     GameMechanic {
-                        id: 2082,
+                        id: MechanicId(2082),
                         name: "Worker Placement".to_owned(),
                     },
                 ],
                 game_families: vec![
                     GameFamilyName {
-                        id: 5666,
+                        id: FamilyId(5666),
                         name: "Players: Games with Solitaire Rules".to_owned(),
                     },
                     GameFamilyName {
-                        id: 21940,
+                        id: FamilyId(21940),
                         name: "Theme: Archaeology / Paleontology".to_owned(),
                     },
                 ],
                 expansions: vec![
                     Game {
-                        id: 341_254,
+                        id: GameId(341_254),
                         name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
                     },
                 ],
@@ -1361,30 +1843,45 @@ mod tests {
                 reimplementations: vec![],
                 designers: vec![
                     GameDesigner {
-                        id: 127_823,
+                        id: PersonId(127_823),
                         name: "Design".to_owned(),
                     },
                     GameDesigner {
-                        id: 127_822,
+                        id: PersonId(127_822),
                         name: "Er".to_owned(),
                     },
                 ],
                 artists: vec![
                     GameArtist {
-                        id: 152_613,
+                        id: PersonId(152_613),
                         name: "Artist person".to_owned(),
                     },
                     GameArtist {
-                        id: 115_373,
+                        id: PersonId(115_373),
                         name: "Another Artist person".to_owned(),
                     },
                 ],
                 publishers: vec![
                     GamePublisher {
-                        id: 1391,
+                        id: PublisherId(1391),
                         name: "Hobby Japan".to_owned(),
                     },
                 ],
+                links: vec![
+                    item_link(ItemType::BoardGameCategory, 1020, "Exploration", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameCategory, 1097, "Travel", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2664, "Deck, Bag, and Pool Building", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2041, "Open Drafting", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2082, "Worker Placement", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 5666, "Players: Games with Solitaire Rules", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 21_940, "Theme: Archaeology / Paleontology", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameExpansion, 341_254, "Lost Ruins of Arnak: Expedition Leaders", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_823, "Design", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_822, "Er", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 152_613, "Artist person", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameArtist, 115_373, "Another Artist person", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 1391, "Hobby Japan", LinkDirection::Outbound),
+                ],
                 stats: GameStats {
                     users_rated: 45233,
                     average_rating: 8.07243,
@@ -1392,7 +1889,7 @@ mod tests {
                     standard_deviation: 1.24187,
                     median: 0.0,
                     rank: ItemFamilyRank {
-                        id: 1,
+                        id: RankId(1),
                         name: "boardgame".to_owned(),
                         friendly_name: "Board Game Rank".to_owned(),
                         value: RankValue::Ranked(28),
@@ -1400,7 +1897,7 @@ mod tests {
                     },
                     sub_family_ranks: vec![
                         ItemFamilyRank {
-                            id: 5497,
+                            id: RankId(5497),
                             name: "strategygames".to_owned(),
                             friendly_name: "Strategy Game Rank".to_owned(),
                             value: RankValue::Ranked(29),
@@ -1444,7 +1941,7 @@ mod tests {
         assert_eq!(
             games[1],
             GameDetails {
-                id: 341_254,
+                id: GameId(341_254),
                 game_type: GameType::BoardGameExpansion,
                 name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
                 alternate_names: vec![
@@ -1456,162 +1953,146 @@ mod tests {
                 year_published: 2021,
                 min_players: 1,
                 max_players: 4,
-                suggested_player_count: SuggestedPlayerCountPoll {
-                    title: "User Suggested Number of Players".to_owned(),
-                    total_voters: 87,
-                    results: vec![
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(1),
-                            best_votes: 8,
-                            recommended_votes: 45,
-                            not_recommended_votes: 16,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(2),
-                            best_votes: 26,
-                            recommended_votes: 50,
-                            not_recommended_votes: 1,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(3),
-                            best_votes: 53,
-                            recommended_votes: 18,
-                            not_recommended_votes: 1,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::Players(4),
-                            best_votes: 15,
-                            recommended_votes: 44,
-                            not_recommended_votes: 9,
-                        },
-                        SuggestedPlayerCount {
-                            player_count: PlayerCount::PlayersOrAbove(4),
-                            best_votes: 0,
-                            recommended_votes: 1,
-                            not_recommended_votes: 42,
-                        },
-                    ],
-                    summary: None,
-                },
                 playing_time: Duration::minutes(120),
                 min_play_time: Duration::minutes(30),
                 max_play_time: Duration::minutes(120),
                 min_age: 12,
-                suggested_player_age: SuggestedPlayerAgePoll {
-                    title: "User Suggested Player Age".to_owned(),
-                    total_voters: 20,
-                    results: vec![
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(6),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(8),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(10),
-                            votes: 6,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(14),
-                            votes: 1,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(16),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::Age(18),
-                            votes: 0,
-                        },
-                        SuggestedPlayerAge {
-                            player_age: PlayerAge::AgeOrAbove(21),
-                            votes: 0,
-                        },
-                    ],
-                    summary: None,
-                },
-                suggested_language_dependence: LanguageDependencePoll {
-                    title: "Language Dependence".to_owned(),
-                    total_voters: 6,
-                    results: vec![
-                        LanguageDependence {
-                            level: 1,
-                            dependence: "No necessary in-game text".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 2,
-                            dependence: "Some necessary text - easily memorized or small crib sheet".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 3,
-                            dependence: "Moderate in-game text - needs crib sheet or paste ups".to_owned(),
-                            votes: 5,
-                        },
-                        LanguageDependence {
-                            level: 4,
-                            dependence: "Extensive use of text - massive conversion needed to be playable".to_owned(),
-                            votes: 0,
-                        },
-                        LanguageDependence {
-                            level: 5,
-                            dependence: "Unplayable in another language".to_owned(),
-                            votes: 1,
-                        },
-                    ],
-                    summary: None,
-                },
+                polls: vec![
+                    Poll {
+                        name: "suggested_numplayers".to_owned(),
+                        title: "User Suggested Number of Players".to_owned(),
+                        total_votes: 87,
+                        results: vec![
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(1)),
+                                results: vec![
+                                    poll_result("Best", None, 8),
+                                    poll_result("Recommended", None, 45),
+                                    poll_result("Not Recommended", None, 16),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(2)),
+                                results: vec![
+                                    poll_result("Best", None, 26),
+                                    poll_result("Recommended", None, 50),
+                                    poll_result("Not Recommended", None, 1),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(3)),
+                                results: vec![
+                                    poll_result("Best", None, 53),
+                                    poll_result("Recommended", None, 18),
+                                    poll_result("Not Recommended", None, 1),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::Players(4)),
+                                results: vec![
+                                    poll_result("Best", None, 15),
+                                    poll_result("Recommended", None, 44),
+                                    poll_result("Not Recommended", None, 9),
+                                ],
+                            },
+                            PollResults {
+                                player_count: Some(PlayerCount::PlayersOrAbove(4)),
+                                results: vec![
+                                    poll_result("Best", None, 0),
+                                    poll_result("Recommended", None, 1),
+                                    poll_result("Not Recommended", None, 42),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "suggested_playerage".to_owned(),
+                        title: "User Suggested Player Age".to_owned(),
+                        total_votes: 20,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("6", None, 0),
+                                    poll_result("8", None, 0),
+                                    poll_result("10", None, 6),
+                                    poll_result("14", None, 1),
+                                    poll_result("16", None, 0),
+                                    poll_result("18", None, 0),
+                                    poll_result("21 and up", None, 0),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                    Poll {
+                        name: "language_dependence".to_owned(),
+                        title: "Language Dependence".to_owned(),
+                        total_votes: 6,
+                        results: vec![
+                            PollResults {
+                                player_count: None,
+                                results: vec![
+                                    poll_result("No necessary in-game text", Some(1), 0),
+                                    poll_result("Some necessary text - easily memorized or small crib sheet", Some(2), 0),
+                                    poll_result("Moderate in-game text - needs crib sheet or paste ups", Some(3), 5),
+                                    poll_result("Extensive use of text - massive conversion needed to be playable", Some(4), 0),
+                                    poll_result("Unplayable in another language", Some(5), 1),
+                                ],
+                            },
+                        ],
+                        summary: vec![],
+                    },
+                ],
                 categories: vec![
                     GameCategory {
-                        id: 1042,
+                        id: CategoryId(1042),
                         name: "Expansion for Base-game".to_owned(),
                     },
                     GameCategory {
-                        id: 1020,
+                        id: CategoryId(1020),
                         name: "Exploration".to_owned(),
                     },
                     GameCategory {
-                        id: 1097,
+                        id: CategoryId(1097),
                         name: "Travel".to_owned(),
                     },
                 ],
                 mechanics: vec![
                     GameMechanic {
-                        id: 2664,
+                        id: MechanicId(2664),
                         name: "Deck, Bag, and Pool Building".to_owned(),
                     },
                     GameMechanic {
-                        id: 2041,
+                        id: MechanicId(2041),
                         name: "Open Drafting".to_owned(),
                     },
                     GameMechanic {
-                        id: 2082,
+                        id: MechanicId(2082),
                         name: "Worker Placement".to_owned(),
                     },
                 ],
                 game_families: vec![
                     GameFamilyName {
-                        id: 5666,
+                        id: FamilyId(5666),
                         name: "Players: Games with Solitaire Rules".to_owned(),
                     },
                     GameFamilyName {
-                        id: 21940,
+                        id: FamilyId(21940),
                         name: "Theme: Archaeology / Paleontology".to_owned(),
                     },
                 ],
                 expansions: vec![],
                 expansion_for: vec![
                     Game {
-                        id: 312_484,
+                        id: GameId(312_484),
                         name: "Lost Ruins of Arnak".to_owned(),
                     },
                 ],
                 accessories: vec![
                     GameAccessory {
-                        id: 363_147,
+                        id: AccessoryId(363_147),
                         name: "Lost Ruins of Arnak + Expedition Leaders: Insert".to_owned(),
                     },
                 ],
@@ -1620,21 +2101,36 @@ mod tests {
                 reimplementations: vec![],
                 designers: vec![
                     GameDesigner {
-                        id: 127_823,
+                        id: PersonId(127_823),
                         name: "Design".to_owned(),
                     },
                     GameDesigner {
-                        id: 127_822,
+                        id: PersonId(127_822),
                         name: "Er".to_owned(),
                     },
                 ],
                 artists: vec![],
                 publishers: vec![
                     GamePublisher {
-                        id: 1391,
+                        id: PublisherId(1391),
                         name: "Hobby Japan".to_owned(),
                     },
                 ],
+                links: vec![
+                    item_link(ItemType::BoardGameCategory, 1042, "Expansion for Base-game", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameCategory, 1020, "Exploration", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameCategory, 1097, "Travel", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2664, "Deck, Bag, and Pool Building", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2041, "Open Drafting", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameMechanic, 2082, "Worker Placement", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 5666, "Players: Games with Solitaire Rules", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameFamily, 21_940, "Theme: Archaeology / Paleontology", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameExpansion, 312_484, "Lost Ruins of Arnak", LinkDirection::Inbound),
+                    item_link(ItemType::BoardGameAccessory, 363_147, "Lost Ruins of Arnak + Expedition Leaders: Insert", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_823, "Design", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGameDesigner, 127_822, "Er", LinkDirection::Outbound),
+                    item_link(ItemType::BoardGamePublisher, 1391, "Hobby Japan", LinkDirection::Outbound),
+                ],
                 stats: GameStats {
                     users_rated: 7103,
                     average_rating: 8.7037,
@@ -1642,7 +2138,7 @@ mod tests {
                     standard_deviation: 1.00019,
                     median: 0.0,
                     rank: ItemFamilyRank {
-                        id: 1,
+                        id: RankId(1),
                         name: "boardgame".to_owned(),
                         friendly_name: "Board Game Rank".to_owned(),
                         value: RankValue::NotRanked,
@@ -1650,7 +2146,7 @@ mod tests {
                     },
                     sub_family_ranks: vec![
                         ItemFamilyRank {
-                            id: 5497,
+                            id: RankId(5497),
                             name: "strategygames".to_owned(),
                             friendly_name: "Strategy Game Rank".to_owned(),
                             value: RankValue::NotRanked,
@@ -1682,4 +2178,388 @@ mod tests {
             },
         );
     }
+
+    #[tokio::test]
+    async fn get_versions_by_ids() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgameversion".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "595583,517374".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game/versions.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let versions = api
+            .game()
+            .get_versions_by_ids(&[VersionId(595_583), VersionId(517_374)])
+            .await;
+        mock.assert_async().await;
+
+        assert!(versions.is_ok(), "error returned when okay expected");
+        let versions = versions.unwrap();
+
+        // The versions are the same as when they are included with the game.
+        let game = deserialize_xml_string::<Games>(
+            &std::fs::read_to_string("test_data/game/game_all.xml")
+                .expect("failed to load test data"),
+        )
+        .unwrap()
+        .games
+        .remove(0);
+        assert_eq!(versions, game.versions);
+    }
+
+    #[tokio::test]
+    async fn get_version_by_id_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgameversion".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "1".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"<items termsofuse="https://boardgamegeek.com/xmlapi/termsofuse"></items>"#,
+            )
+            .create_async()
+            .await;
+
+        let version = api.game().get_version_by_id(VersionId(1)).await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(version, Err(Error::ItemNotFound)),
+            "expected item not found error but got {version:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn get_version_by_id_partial_dimensions() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgameversion".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "595583".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game/version_partial_dimensions.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let version = api.game().get_version_by_id(VersionId(595_583)).await;
+        mock.assert_async().await;
+
+        assert!(version.is_ok(), "error returned when okay expected");
+        let version = version.unwrap();
+
+        let dimensions = version.dimensions.unwrap();
+        assert_eq!(
+            dimensions,
+            Dimensions {
+                width: Some(Length::from_inches(10.0394)),
+                length: Some(Length::from_inches(14.3701)),
+                depth: None,
+            },
+        );
+        assert!(dimensions.footprint().is_some());
+        assert_eq!(dimensions.volume(), None);
+        assert_eq!(version.weight, None);
+    }
+
+    #[test]
+    fn find_versions() {
+        let game = deserialize_xml_string::<Games>(
+            &std::fs::read_to_string("test_data/game/game_all.xml")
+                .expect("failed to load test data"),
+        )
+        .unwrap()
+        .games
+        .remove(0);
+
+        let french = Language {
+            id: LanguageId(2187),
+            name: "French".to_owned(),
+        };
+        let french_versions: Vec<_> = game
+            .versions_in_language(&french)
+            .into_iter()
+            .map(|version| version.id)
+            .collect();
+        assert_eq!(french_versions, vec![VersionId(517_374)]);
+
+        assert_eq!(game.versions_by_publisher(PublisherId(7345)).len(), 2);
+        assert!(game.versions_by_publisher(PublisherId(1)).is_empty());
+
+        assert_eq!(
+            game.version_with_product_code(" 77240-bg ")
+                .map(|version| version.id),
+            Some(VersionId(595_583)),
+        );
+        // Versions without a product code are never matched.
+        assert_eq!(game.version_with_product_code(""), None);
+    }
+
+    #[tokio::test]
+    async fn get_raw_by_ids() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let body = std::fs::read_to_string("test_data/game/game_multiple.xml")
+            .expect("failed to load test data");
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgame,boardgameexpansion".to_owned()),
+                Matcher::UrlEncoded("stats".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "312484,341254".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(&body)
+            .create_async()
+            .await;
+
+        let raw_games = api
+            .game()
+            .get_raw_by_ids(&[GameId(312_484), GameId(341_254)], &GameQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(raw_games.is_ok(), "error returned when okay expected");
+        let raw_games = raw_games.unwrap();
+
+        let games = raw_games.parse();
+        assert!(games.is_ok(), "error returned when okay expected");
+        let games = games.unwrap();
+
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].name, "Lost Ruins of Arnak");
+        #[cfg(feature = "quick-xml")]
+        assert!(matches!(games[0].name, std::borrow::Cow::Borrowed(_)));
+
+        let expected = deserialize_xml_string::<Games>(&body).unwrap();
+        let games: Vec<GameDetails> = games.into_iter().map(GameDetailsRef::into_owned).collect();
+        assert_eq!(games, expected.games);
+    }
+
+    #[tokio::test]
+    async fn get_raw_items_by_ids() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgame,boardgameexpansion".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "312484,341254".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game/game_multiple.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let items = api
+            .game()
+            .get_raw_items_by_ids(&[GameId(312_484), GameId(341_254)], &GameQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(items.is_ok(), "error returned when okay expected");
+        let items = items.unwrap();
+        assert_eq!(items.len(), 2);
+
+        let game = &items[0];
+        assert_eq!(game.item_type, "boardgame");
+        assert_eq!(game.id, ObjectId(312_484));
+        assert_eq!(
+            game.names[0],
+            RawName {
+                name_type: "primary".to_owned(),
+                sort_index: Some(1),
+                value: "Lost Ruins of Arnak".to_owned(),
+            },
+        );
+        assert_eq!(game.element.child_value("yearpublished"), Some("2020"));
+        assert_eq!(game.polls[0].name, "suggested_numplayers");
+        assert_eq!(
+            game.polls[0].results[0].player_count,
+            Some(PlayerCount::Players(1)),
+        );
+        assert_eq!(
+            game.polls[0].results[0].results[0],
+            poll_result("Best", None, 88)
+        );
+        // The typed polls can be read from the polls of a raw item too.
+        assert!(SuggestedPlayerCountPoll::try_from(&game.polls[0]).is_ok());
+        let language_poll = game
+            .polls
+            .iter()
+            .find(|poll| poll.name == "language_dependence")
+            .unwrap();
+        assert_eq!(language_poll.results[0].results[0].level, Some(1));
+
+        let expansion = &items[1];
+        assert_eq!(expansion.item_type, "boardgameexpansion");
+        assert!(expansion.links.contains(&RawLink {
+            link_type: "boardgameexpansion".to_owned(),
+            id: ObjectId(312_484),
+            value: "Lost Ruins of Arnak".to_owned(),
+            inbound: true,
+        }));
+    }
+
+    #[tokio::test]
+    async fn get_raw_items_by_ids_and_types() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "rpgitem".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "181361".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game/rpg_item.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let items = api
+            .game()
+            .get_raw_items_by_ids_and_types(
+                &[RpgItemId(181_361)],
+                &[ItemType::RpgItem],
+                &GameQueryParams::new(),
+            )
+            .await;
+        mock.assert_async().await;
+
+        assert!(items.is_ok(), "error returned when okay expected");
+        let items = items.unwrap();
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.item_type, "rpgitem");
+        assert_eq!(item.names[0].value, "Player's Handbook (D&D 5e)");
+        assert_eq!(item.element.child_value("seriescode"), Some("A92170000"));
+        assert_eq!(
+            item.element.child("description").unwrap().text,
+            "Everything a player needs to create heroic characters.\n\n\u{2013}from the back cover",
+        );
+        assert_eq!(
+            item.links,
+            vec![
+                RawLink {
+                    link_type: "rpgdesigner".to_owned(),
+                    id: ObjectId(4398),
+                    value: "Jeremy Crawford".to_owned(),
+                    inbound: false,
+                },
+                RawLink {
+                    link_type: "rpg".to_owned(),
+                    id: ObjectId(35005),
+                    value: "Dungeons & Dragons (5th Edition)".to_owned(),
+                    inbound: false,
+                },
+                RawLink {
+                    link_type: "rpgitemversion".to_owned(),
+                    id: ObjectId(168_342),
+                    value: "English first printing".to_owned(),
+                    inbound: true,
+                },
+            ],
+        );
+        assert!(item.polls.is_empty());
+    }
+
+    #[test]
+    fn raw_item_poll_summary() {
+        let xml =
+            std::fs::read_to_string("test_data/game/game.xml").expect("failed to load test data");
+        let items = parse_raw_items(&xml).unwrap();
+
+        let player_count_poll = items[0]
+            .polls
+            .iter()
+            .find(|poll| poll.name == "suggested_numplayers")
+            .unwrap();
+        assert_eq!(
+            player_count_poll.summary,
+            vec![
+                PollSummaryResult {
+                    name: "bestwith".to_owned(),
+                    value: "Best with 3 players".to_owned(),
+                },
+                PollSummaryResult {
+                    name: "recommmendedwith".to_owned(),
+                    value: "Recommended with 1–4 players".to_owned(),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn raw_item_element_cdata() {
+        let element = XmlElement::parse(
+            "<item><description>Before <![CDATA[<b>bold</b> & more]]> after</description></item>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            element.child("description").unwrap().text,
+            "Before <b>bold</b> & more after",
+        );
+    }
 }
@@ -1,11 +1,12 @@
-use super::{GameFamilies, GameFamily, ItemType};
-use crate::{BoardGameGeekApi, Error, IntoQueryParam, QueryParam, Result};
+use super::{parse_raw_items, GameFamilies, GameFamily, ItemType, RawItem};
+use crate::api::parse_response_with;
+use crate::{BoardGameGeekApi, Domain, Error, FamilyId, IntoQueryParam, QueryParam, Result};
 
 // Query parameters for making a request to the game family endpoint.
 #[derive(Clone, Debug, Default)]
 struct GameFamilyQueryParams {
     // ID for the game families to retrieve.
-    game_family_ids: Vec<u64>,
+    game_family_ids: Vec<FamilyId>,
 }
 
 impl GameFamilyQueryParams {
@@ -15,13 +16,13 @@ impl GameFamilyQueryParams {
     }
 
     // Adds an ID to the list of game family IDs to retrieve.
-    fn game_family_id(mut self, id: u64) -> Self {
+    fn game_family_id(mut self, id: FamilyId) -> Self {
         self.game_family_ids.push(id);
         self
     }
 
     // Adds a list of IDs to the list of game family IDs to retrieve.
-    fn game_family_ids(mut self, ids: Vec<u64>) -> Self {
+    fn game_family_ids(mut self, ids: Vec<FamilyId>) -> Self {
         self.game_family_ids.extend(ids);
         self
     }
@@ -31,20 +32,24 @@ impl GameFamilyQueryParams {
 #[derive(Clone, Debug)]
 struct GameFamilyQueryBuilder<'builder> {
     params: &'builder GameFamilyQueryParams,
+    // The type of family to request, which depends on the domain of the API.
+    family_type: ItemType,
 }
 
 impl<'builder> GameFamilyQueryBuilder<'builder> {
-    // Constructs a new query builder from the query params.
-    fn new(params: &'builder GameFamilyQueryParams) -> Self {
-        Self { params }
+    // Constructs a new query builder from the query params and type of family.
+    fn new(params: &'builder GameFamilyQueryParams, family_type: ItemType) -> Self {
+        Self {
+            params,
+            family_type,
+        }
     }
 
     // Converts the list of parameters into a vector of
     // key value pairs that reqwest can use as HTTP query parameters.
     fn build(self) -> Vec<QueryParam<'builder>> {
         let mut query_params: Vec<_> = vec![];
-        // Underlying endpoint supports RPG and Video game families too but we hide those.
-        query_params.push(ItemType::BoardGameFamily.into_query_param("type"));
+        query_params.push(self.family_type.into_query_param("type"));
 
         if !self.params.game_family_ids.is_empty() {
             query_params.push(self.params.game_family_ids.into_query_param("id"));
@@ -67,10 +72,25 @@ impl<'api> GameFamilyApi<'api> {
         }
     }
 
+    // The type of family for the domain of the API. Board game families, or RPG systems for the
+    // RPG domain. The underlying API has no families of video games.
+    fn family_type(&self) -> Result<ItemType> {
+        match self.api.domain {
+            Domain::BoardGame => Ok(ItemType::BoardGameFamily),
+            Domain::Rpg => Ok(ItemType::Rpg),
+            Domain::VideoGame => Err(Error::UnsupportedDomain(Domain::VideoGame)),
+        }
+    }
+
     /// Gets a family of games by ID.
-    pub async fn get_by_id(&self, id: u64) -> Result<GameFamily> {
+    ///
+    /// If the API is set to the [`Domain::Rpg`] domain this gets an RPG system, such as an edition
+    /// of Dungeons & Dragons, with the RPG items made for it as its games. Families aren't
+    /// supported for the [`Domain::VideoGame`] domain, and an [`Error::UnsupportedDomain`] is
+    /// returned.
+    pub async fn get_by_id(&self, id: FamilyId) -> Result<GameFamily> {
         let params = GameFamilyQueryParams::new().game_family_id(id);
-        let query = GameFamilyQueryBuilder::new(&params);
+        let query = GameFamilyQueryBuilder::new(&params, self.family_type()?);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let mut response = self.api.execute_request::<GameFamilies>(request).await?;
@@ -85,23 +105,39 @@ impl<'api> GameFamilyApi<'api> {
     }
 
     /// Gets families of games by their IDs.
-    pub async fn get_by_ids(&self, ids: Vec<u64>) -> Result<Vec<GameFamily>> {
+    pub async fn get_by_ids(&self, ids: Vec<FamilyId>) -> Result<Vec<GameFamily>> {
         let params = GameFamilyQueryParams::new().game_family_ids(ids);
-        let query = GameFamilyQueryBuilder::new(&params);
+        let query = GameFamilyQueryBuilder::new(&params, self.family_type()?);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let response = self.api.execute_request::<GameFamilies>(request).await?;
 
         Ok(response.game_families)
     }
+
+    /// Gets families of games by their IDs, returning each as a generic [`RawItem`] rather than
+    /// [`GameFamily`].
+    ///
+    /// This can be used to read any data in the response that [`GameFamily`] leaves out.
+    pub async fn get_raw_items_by_ids(&self, ids: Vec<FamilyId>) -> Result<Vec<RawItem>> {
+        let params = GameFamilyQueryParams::new().game_family_ids(ids);
+        let query = GameFamilyQueryBuilder::new(&params, self.family_type()?);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let text = self.api.execute_raw_request(request).await?;
+
+        parse_response_with(&text, parse_raw_items)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use mockito::Matcher;
 
     use super::*;
-    use crate::{Game, GameFamily};
+    use crate::{Domain, FamilyId, Game, GameFamily, GameId, StaticTokenProvider};
 
     #[tokio::test]
     async fn get_by_id() {
@@ -109,6 +145,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -125,7 +163,7 @@ mod tests {
             .create_async()
             .await;
 
-        let game_family = api.game_family().get_by_id(2).await;
+        let game_family = api.game_family().get_by_id(FamilyId(2)).await;
         mock.assert_async().await;
 
         assert!(game_family.is_ok(), "error returned when okay expected");
@@ -134,7 +172,7 @@ mod tests {
         assert_eq!(
             game_family,
             GameFamily {
-                id: 2,
+                id: FamilyId(2),
                 name: "Game: Carcassonne".to_owned(),
                 alternate_names: vec!["Carcassonne: Solo-Variante".to_owned()],
                 image: Some("https://cf.geekdo-images.com/c_pg0WfJKn7_P33AsDS5EA__original/img/k2t0IHkPo0nzLadfSxXhtAzyU5I=/0x0/filters:format(jpeg)/pic453826.jpg".to_owned()),
@@ -142,15 +180,15 @@ mod tests {
                 description: "Games (expansions, promos, etc.) in the \"Carcassonne\" family of games, published by Hans im GlÃ¼ck.\n\n\nSee this Carcassonne Series wiki for more details.".to_owned(),
                 games: vec![
                     Game {
-                        id: 822,
+                        id: GameId(822),
                         name: "Carcassonne".to_owned(),
                     },
                     Game {
-                        id: 142_057,
+                        id: GameId(142_057),
                         name: "Carcassonne Big Box".to_owned(),
                     },
                     Game {
-                        id: 141_008,
+                        id: GameId(141_008),
                         name: "Carcassonne Big Box 2".to_owned(),
                     },
                 ],
@@ -158,12 +196,62 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn get_by_id_html_entities() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/family")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "boardgamefamily".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "3".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/game_family/game_family_html_entities.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let game_family = api.game_family().get_by_id(FamilyId(3)).await;
+        mock.assert_async().await;
+
+        assert!(game_family.is_ok(), "error returned when okay expected");
+        let game_family = game_family.unwrap();
+
+        assert_eq!(
+            game_family.alternate_names,
+            vec!["Les Colons de Catane \u{2013} S\u{e9}rie".to_owned()],
+        );
+        assert_eq!(
+            game_family.description,
+            "The Catan series\u{a0}\u{2013} games set in the world of Catan, starting with the 1995 original\u{2026}\n\nKlaus Teuber\u{2019}s classic has spawned expansions, scenarios and spin-offs:\n\u{2022} Seafarers\n\u{2022} Cities & Knights\n\n\u{201c}Catan\u{201d} is a trademark of Catan GmbH.",
+        );
+        assert_eq!(
+            game_family.description_paragraphs(),
+            vec![
+                "The Catan series\u{a0}\u{2013} games set in the world of Catan, starting with the 1995 original\u{2026}",
+                "Klaus Teuber\u{2019}s classic has spawned expansions, scenarios and spin-offs:\n\u{2022} Seafarers\n\u{2022} Cities & Knights",
+                "\u{201c}Catan\u{201d} is a trademark of Catan GmbH.",
+            ],
+        );
+    }
+
     #[tokio::test]
     async fn get_by_ids() {
         let mut server = mockito::Server::new_async().await;
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -180,7 +268,10 @@ mod tests {
             .create_async()
             .await;
 
-        let game_families = api.game_family().get_by_ids(vec![2, 3]).await;
+        let game_families = api
+            .game_family()
+            .get_by_ids(vec![FamilyId(2), FamilyId(3)])
+            .await;
         mock.assert_async().await;
 
         assert!(game_families.is_ok(), "error returned when okay expected");
@@ -190,7 +281,7 @@ mod tests {
         assert_eq!(
             game_families[0],
             GameFamily {
-                id: 2,
+                id: FamilyId(2),
                 name: "Game: Carcassonne".to_owned(),
                 alternate_names: vec!["Carcassonne: Solo-Variante".to_owned()],
                 image: Some("https://cf.geekdo-images.com/c_pg0WfJKn7_P33AsDS5EA__original/img/k2t0IHkPo0nzLadfSxXhtAzyU5I=/0x0/filters:format(jpeg)/pic453826.jpg".to_owned()),
@@ -198,15 +289,15 @@ mod tests {
                 description: "Games (expansions, promos, etc.) in the \"Carcassonne\" family of games, published by Hans im GlÃ¼ck.\n\n\nSee this Carcassonne Series wiki for more details.".to_owned(),
                 games: vec![
                     Game {
-                        id: 822,
+                        id: GameId(822),
                         name: "Carcassonne".to_owned(),
                     },
                     Game {
-                        id: 142_057,
+                        id: GameId(142_057),
                         name: "Carcassonne Big Box".to_owned(),
                     },
                     Game {
-                        id: 141_008,
+                        id: GameId(141_008),
                         name: "Carcassonne Big Box 2".to_owned(),
                     },
                 ],
@@ -215,7 +306,7 @@ mod tests {
         assert_eq!(
             game_families[1],
             GameFamily {
-                id: 3,
+                id: FamilyId(3),
                 name: "Game: Catan".to_owned(),
                 alternate_names: vec![],
                 image: Some("https://cf.geekdo-images.com/FFUKDbZw6d9mAKaL9U3ymg__original/img/rulpehNOumO24_7WzaHvl7P2aac=/0x0/filters:format(jpeg)/pic1446957.jpg".to_owned()),
@@ -223,11 +314,11 @@ mod tests {
                 description: "This is the family of Settlers of Catan games, meant to include any game in the Game: Catan universe.\n\nA detailed overview is given on the Catan Series wiki.".to_owned(),
                 games: vec![
                     Game {
-                        id: 13,
+                        id: GameId(13),
                         name: "CATAN".to_owned(),
                     },
                     Game {
-                        id: 27710,
+                        id: GameId(27710),
                         name: "Catan Dice Game".to_owned(),
                     },
                 ],
@@ -241,6 +332,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -257,10 +350,31 @@ mod tests {
             .create_async()
             .await;
 
-        let game_families = api.game_family().get_by_id(9000).await;
+        let game_families = api.game_family().get_by_id(FamilyId(9000)).await;
         mock.assert_async().await;
 
         assert!(game_families.is_err());
         assert!(matches!(game_families.err().unwrap(), Error::ItemNotFound));
     }
+
+    #[tokio::test]
+    async fn get_by_id_unsupported_domain() {
+        let server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::VideoGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let game_family = api.game_family().get_by_id(FamilyId(9000)).await;
+
+        assert!(
+            matches!(
+                game_family,
+                Err(Error::UnsupportedDomain(Domain::VideoGame))
+            ),
+            "expected unsupported domain error but got {game_family:?}",
+        );
+    }
 }
@@ -3,8 +3,8 @@ use core::fmt;
 use serde::Deserialize;
 
 use super::Game;
-use crate::deserialize::{XmlLink, XmlName};
-use crate::{ItemType, NameType};
+use crate::deserialize::{decode_html_entities, XmlLink, XmlName};
+use crate::{description_paragraphs, FamilyId, GameId, ItemType, NameType};
 
 // A list of game families. Which are groups of games in a particular series.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -17,9 +17,10 @@ pub(crate) struct GameFamilies {
 /// A family of games in a particular series or group. Contains the description for the
 /// family as well as the list of games.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameFamily {
     /// The ID of the game family.
-    pub id: u64,
+    pub id: FamilyId,
     /// The name of the game family.
     pub name: String,
     /// A list of alternate names for the game family.
@@ -34,6 +35,13 @@ pub struct GameFamily {
     pub games: Vec<Game>,
 }
 
+impl GameFamily {
+    /// The paragraphs of the game family's description, see [`description_paragraphs`].
+    pub fn description_paragraphs(&self) -> Vec<&str> {
+        description_paragraphs(&self.description)
+    }
+}
+
 impl<'de> Deserialize<'de> for GameFamily {
     fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
@@ -111,14 +119,18 @@ impl<'de> Deserialize<'de> for GameFamily {
                             if description.is_some() {
                                 return Err(serde::de::Error::duplicate_field("description"));
                             }
-                            description = Some(map.next_value()?);
+                            description = Some(
+                                decode_html_entities(map.next_value::<String>()?.into())
+                                    .into_owned(),
+                            );
                         },
                         Field::Link => {
                             let link: XmlLink = map.next_value()?;
                             match link.link_type {
-                                ItemType::BoardGameFamily => {
+                                // RPG systems link to the RPG items made for them.
+                                ItemType::BoardGameFamily | ItemType::Rpg => {
                                     games.push(Game {
-                                        id: link.id,
+                                        id: GameId(link.id),
                                         name: link.value,
                                     });
                                 },
@@ -130,8 +142,8 @@ impl<'de> Deserialize<'de> for GameFamily {
                             }
                         },
                         Field::Type => {
-                            // Type is fixed at "boardgamefamily", even for the list of games
-                            // contained so we don't add it. But we need
+                            // Type is fixed by the type of family requested, even for the list
+                            // of games contained so we don't add it. But we need
                             // to consume the value.
                             let _: String = map.next_value()?;
                         },
@@ -1,18 +1,27 @@
 use core::fmt;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::str::FromStr;
 
 use chrono::{DateTime, Duration, Utc};
 use serde::Deserialize;
 
 use super::{
-    Game, GameAccessory, GameArtist, GameCategory, GameCompilation, GameDesigner, GameFamilyName,
-    GameImplementation, GameMechanic, GamePublisher, GameType, GameVersion, ItemFamilyRank,
-    UserBrief,
+    links_matching, Game, GameAccessory, GameArtist, GameCategory, GameCompilation, GameDesigner,
+    GameFamilyName, GameImplementation, GameMechanic, GamePublisher, GameType, GameVersion,
+    ItemFamilyRank, ItemLink, ItemType, LinkDirection, UserBrief,
 };
+use crate::api::parse_response;
 use crate::deserialize::{
-    date_time_with_zone_from_string, xml_ranks_to_ranks, XmlDateTimeValue, XmlFloatValue,
-    XmlIntValue, XmlLink, XmlName, XmlRanks, XmlSignedValue, XmlStringValue,
+    date_time_with_zone_from_string, decode_html_entities, xml_ranks_to_ranks, BorrowedStr,
+    XmlBorrowedName, XmlDateTimeValue, XmlFloatValue, XmlIntValue, XmlLink, XmlRanks,
+    XmlSignedValue, XmlStringValue,
+};
+use crate::{
+    description_paragraphs, AccessoryId, CategoryId, Currency, Decimal, ExchangeRates, FamilyId,
+    GameId, GameIntegration, Language, MechanicId, NameType, PersonId, PublisherId, VideoId,
+    XmlGameVersions,
 };
-use crate::{GameIntegration, NameType, XmlGameVersions};
 
 // A struct containing the list of requested games with the full details.
 #[derive(Clone, Debug, Deserialize)]
@@ -22,6 +31,45 @@ pub(crate) struct Games {
     pub(crate) games: Vec<GameDetails>,
 }
 
+// A struct containing the list of requested versions of games.
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct Versions {
+    // List of versions.
+    #[serde(default, rename = "item")]
+    pub(crate) versions: Vec<GameVersion>,
+}
+
+// The same as `Games`, but with the games borrowing from the response text.
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct GamesRef<'a> {
+    // List of games.
+    #[serde(borrow, default, rename = "item")]
+    pub(crate) games: Vec<GameDetailsRef<'a>>,
+}
+
+/// The unparsed response to a request for games, returned by [`GameApi::get_raw_by_ids`].
+///
+/// Games can then be parsed from this as [`GameDetailsRef`]s, which borrow from the response text
+/// rather than copying it.
+///
+/// [`GameApi::get_raw_by_ids`]: crate::GameApi::get_raw_by_ids
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawGames {
+    pub(crate) text: String,
+}
+
+impl RawGames {
+    /// Parses the games in the response, borrowing from the response text where possible.
+    pub fn parse(&self) -> crate::Result<Vec<GameDetailsRef<'_>>> {
+        parse_response::<GamesRef>(&self.text).map(|games| games.games)
+    }
+
+    /// The XML text of the response.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
 /// A game, or expansion, with full details.
 ///
 /// A game returned from the game endpoint which includes all details about a game.
@@ -30,51 +78,59 @@ pub(crate) struct Games {
 ///
 /// Some information, such as version info, comments, and marketplace data is only
 /// optionally included if requested.
+///
+/// The name, alternate names, description, image, and thumbnail are of type `S`, which is
+/// [`String`] by default. See [`GameDetailsRef`] for the same details with these borrowed from the
+/// response instead.
 #[derive(Clone, Debug, PartialEq)]
-pub struct GameDetails {
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct GameDetails<S = String> {
     /// The ID of the game.
-    pub id: u64,
+    pub id: GameId,
     /// The type of the game, whether it is an expansion or not.
     pub game_type: GameType,
     /// The name of the game.
-    pub name: String,
+    pub name: S,
     /// A list of alternate names for the game, usually translations of the primary name.
-    pub alternate_names: Vec<String>,
+    pub alternate_names: Vec<S>,
     /// A brief description of the game.
-    pub description: String,
+    pub description: S,
     /// A link to a jpg image for the game. Can by empty.
-    pub image: Option<String>,
+    pub image: Option<S>,
     /// A link to a jpg thumbnail image for the game. Can by empty.
-    pub thumbnail: Option<String>,
+    pub thumbnail: Option<S>,
     /// The year the game was first published.
     pub year_published: i64,
     /// The minimum number of players the game supports.
     pub min_players: u64,
     /// The maximum number of players the game supports.
     pub max_players: u64,
-    /// The suggested number of players as selected by voters on the site.
-    ///
-    /// Poll results for whether each number of players is recommended, not recommended,
-    /// or best. Includes options outside of the suggested minimum and maximum player counts.
-    pub suggested_player_count: SuggestedPlayerCountPoll,
     /// The amount of time the game is suggested to take to play.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub playing_time: Duration,
     /// Minimum amount of time the game is suggested to take to play.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub min_play_time: Duration,
     /// Maximum amount of time the game is suggested to take to play.
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub max_play_time: Duration,
     /// The minimum suggested age suitable for playing this game.
     pub min_age: u64,
-    /// The suggested number minimum suitable age for playing this game.
+    /// Every poll returned for the game, along with their summaries.
     ///
-    /// Poll results for at which age is a suitable minimum age for playing this game.
-    pub suggested_player_age: SuggestedPlayerAgePoll,
-    /// The suggested dependence on knowing the game's language in order to be able to play it.
-    ///
-    /// Poll results, with five options, for whether the game can be played without knowing the
-    /// language, if it does not have much in game text. Through being completely unplayable
-    /// due to extensive text in the game.
-    pub suggested_language_dependence: LanguageDependencePoll,
+    /// The suggested player count, player age, and language dependence polls can also be read as
+    /// typed polls with [`GameDetails::suggested_player_count`],
+    /// [`GameDetails::suggested_player_age`], and [`GameDetails::suggested_language_dependence`].
+    pub polls: Vec<Poll>,
     // Categories and mechanics have IDs too, but I don't think it would be beneficial to include
     // them over just the names.
     /// A list of category names that this game belongs to.
@@ -93,7 +149,9 @@ pub struct GameDetails {
     pub compilations: Vec<GameCompilation>,
     /// A list of integratiosn for this game.
     pub integrations: Vec<GameIntegration>,
-    /// A list of reimplementations of this game.
+    /// A list of reimplementations of this game, and the games that it reimplements.
+    ///
+    /// Use [`Self::reimplemented_by`] and [`Self::reimplements`] to tell these apart.
     pub reimplementations: Vec<GameImplementation>,
     /// The designer of this game.
     pub designers: Vec<GameDesigner>,
@@ -101,6 +159,12 @@ pub struct GameDetails {
     pub artists: Vec<GameArtist>,
     /// The list of publishers for this game.
     pub publishers: Vec<GamePublisher>,
+    /// Every link from this game to another item, in the order they are returned, including the
+    /// ones in the lists above.
+    ///
+    /// Unlike the lists above, each link includes which way it points, for example whether a
+    /// linked game is a reimplementation of this one or was reimplemented by it.
+    pub links: Vec<ItemLink>,
     /// Rating statistics for this game.
     ///
     /// Includes the game user average rating, as well as the rank and rating
@@ -127,9 +191,23 @@ pub struct GameDetails {
     pub rating_comments: Option<RatingCommentPage>,
 }
 
+/// A borrowed version of [`GameDetails`], for parsing large numbers of games quickly.
+///
+/// The name, alternate names, description, image, and thumbnail borrow from the response text
+/// where they can, rather than each being copied into a new string. These are only copied if they
+/// had to be unescaped, for example if they contain entities such as `&amp;`.
+///
+/// Borrowing only happens with the `quick-xml` feature enabled. With the default parser every
+/// string is copied, so this is no cheaper to parse than [`GameDetails`].
+///
+/// Parsed from a [`RawGames`] response. Can be cheaply converted into a [`GameDetails`] with
+/// [`GameDetailsRef::into_owned`], which only copies the strings that are still borrowed.
+pub type GameDetailsRef<'a> = GameDetails<Cow<'a, str>>;
+
 /// Various statistics for the game, including the number of users who own the game as
 /// well as the ratings.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameStats {
     /// The number of users who have rated this game.
     pub users_rated: u64,
@@ -144,6 +222,7 @@ pub struct GameStats {
     /// The standard deviation of the ratings.
     pub standard_deviation: f64,
     // Private because it's always 0.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub(crate) median: f64,
     /// The rank of this game amongst all games.
     pub rank: ItemFamilyRank,
@@ -202,6 +281,7 @@ struct StatsRatings {
 /// For each player count users can vote on whether the option is not recommended, recommended, or
 /// best.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct SuggestedPlayerCountPoll {
     /// User friendly name for the poll.
     pub title: String,
@@ -216,6 +296,7 @@ pub struct SuggestedPlayerCountPoll {
 
 /// A brief summary of the poll, outlining the best and recommended options based on the votes.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct PollSummary {
     /// A short description of which is the overall best option.
     pub best_with: String,
@@ -223,58 +304,30 @@ pub struct PollSummary {
     pub recommended_with: String,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-struct PollSummaryWithName {
-    name: String,
-    best_with: String,
-    recommended_with: String,
-}
-
+// A summary of a poll, returned in a separate `poll-summary` tag after the poll.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
-struct PollSummaryXml {
+struct XmlPollSummary {
     #[serde(rename = "@name")]
     name: String,
-    #[serde(rename = "result")]
+    #[serde(default, rename = "result")]
     results: Vec<PollSummaryResult>,
 }
 
+/// A line of the summary of a poll, such as which option was voted the best.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
-struct PollSummaryResult {
-    #[serde(rename = "@name")]
-    name: String,
-    #[serde(rename = "@value")]
-    value: String,
-}
-
-impl<'de> Deserialize<'de> for PollSummaryWithName {
-    fn deserialize<D>(deserializer: D) -> Result<PollSummaryWithName, D::Error>
-    where
-        D: serde::de::Deserializer<'de>,
-    {
-        let summary: PollSummaryXml = serde::de::Deserialize::deserialize(deserializer)?;
-        let mut best_with = None;
-        let mut recommended_with = None;
-        for result in summary.results {
-            if result.name == "bestwith" {
-                best_with = Some(result.value);
-            } else if result.name == "recommmendedwith" {
-                recommended_with = Some(result.value);
-            }
-        }
-
-        Ok(PollSummaryWithName {
-            name: summary.name,
-            best_with: best_with
-                .ok_or_else(|| serde::de::Error::missing_field("result name=\"bestwith\""))?,
-            recommended_with: recommended_with.ok_or_else(|| {
-                serde::de::Error::missing_field("result name=\"recommendedwith\"")
-            })?,
-        })
-    }
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct PollSummaryResult {
+    /// The name of the summary line, such as `bestwith`.
+    #[serde(rename(deserialize = "@name"))]
+    pub name: String,
+    /// A short description of the result, such as `Best with 3 players`.
+    #[serde(rename(deserialize = "@value"))]
+    pub value: String,
 }
 
 /// A suggested player count, along with community votes as to whether it is recommended or not.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct SuggestedPlayerCount {
     /// The number of players this vote result is for.
     pub player_count: PlayerCount,
@@ -286,44 +339,44 @@ pub struct SuggestedPlayerCount {
     pub not_recommended_votes: u64,
 }
 
-impl TryFrom<Poll> for SuggestedPlayerCountPoll {
+impl TryFrom<&Poll> for SuggestedPlayerCountPoll {
     // String as the error type, because this will be wrapped in a serde deserialize error during
     // the deserialise step.
     type Error = String;
 
-    fn try_from(poll: Poll) -> Result<Self, Self::Error> {
+    fn try_from(poll: &Poll) -> Result<Self, Self::Error> {
         Ok(SuggestedPlayerCountPoll {
-            title: poll.title,
-            total_voters: poll.total_voters,
+            title: poll.title.clone(),
+            total_voters: poll.total_votes,
             results: poll
                 .results
-                .into_iter()
+                .iter()
                 .map(SuggestedPlayerCount::try_from)
                 .collect::<Result<Vec<SuggestedPlayerCount>, Self::Error>>()?,
-            summary: None,
+            summary: poll.poll_summary(),
         })
     }
 }
 
-impl TryFrom<PollResults> for SuggestedPlayerCount {
+impl TryFrom<&PollResults> for SuggestedPlayerCount {
     // String as the error type, because this will be wrapped in a serde deserialize error during
     // the deserialise step.
     type Error = String;
 
-    fn try_from(results: PollResults) -> Result<Self, Self::Error> {
+    fn try_from(results: &PollResults) -> Result<Self, Self::Error> {
         let mut best_votes = None;
         let mut recommended_votes = None;
         let mut not_recommended_votes = None;
-        for vote_result in results.results {
+        for vote_result in &results.results {
             match vote_result.value.as_str() {
                 "Best" => {
-                    best_votes = Some(vote_result.number_of_votes);
+                    best_votes = Some(vote_result.votes);
                 },
                 "Recommended" => {
-                    recommended_votes = Some(vote_result.number_of_votes);
+                    recommended_votes = Some(vote_result.votes);
                 },
                 "Not Recommended" => {
-                    not_recommended_votes = Some(vote_result.number_of_votes);
+                    not_recommended_votes = Some(vote_result.votes);
                 },
                 unexpected => {
                     return Err(format!("unexpected player count vote option: {unexpected}"));
@@ -332,7 +385,8 @@ impl TryFrom<PollResults> for SuggestedPlayerCount {
         }
         Ok(SuggestedPlayerCount {
             player_count: results
-                .number_of_players
+                .player_count
+                .clone()
                 .ok_or("player count was `None` when `Some` was expected")?,
             best_votes: best_votes.ok_or("value `Best` missing for player count vote")?,
             recommended_votes: recommended_votes
@@ -348,6 +402,11 @@ impl TryFrom<PollResults> for SuggestedPlayerCount {
 /// Can be either an exact number of players or a number or above. Voting options typically contain
 /// from 1 all the way to the max player count, and then an option for max player count or above.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum PlayerCount {
     /// An exact number of players.
     Players(u64),
@@ -355,39 +414,40 @@ pub enum PlayerCount {
     PlayersOrAbove(u64),
 }
 
+impl FromStr for PlayerCount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_players = |players: &str| {
+            players
+                .parse::<u64>()
+                .map_err(|e| format!("unable to parse player count to u64: {e}"))
+        };
+        if s.is_empty() {
+            return Err("expected player count but got empty string".to_owned());
+        }
+        match s.strip_suffix('+') {
+            Some(players) => Ok(PlayerCount::PlayersOrAbove(parse_players(players)?)),
+            None => Ok(PlayerCount::Players(parse_players(s)?)),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for PlayerCount {
     fn deserialize<D>(deserializer: D) -> Result<PlayerCount, D::Error>
     where
         D: serde::de::Deserializer<'de>,
     {
-        let s: String = serde::de::Deserialize::deserialize(deserializer)?;
-        if s.is_empty() {
-            return Err(serde::de::Error::custom(
-                "expected player count but got empty string",
-            ));
-        }
-        match s.chars().last() {
-            Some('+') => {
-                let players = s.replace('+', "").parse::<u64>().map_err(|e| {
-                    serde::de::Error::custom(format!("unable to parse player count to u64: {e}"))
-                })?;
-                Ok(PlayerCount::PlayersOrAbove(players))
-            },
-            Some(_) => {
-                let players = s.parse::<u64>().map_err(|e| {
-                    serde::de::Error::custom(format!("unable to parse player count to u64: {e}"))
-                })?;
-                Ok(PlayerCount::Players(players))
-            },
-            None => Err(serde::de::Error::custom(
-                "expected player count but got empty string",
-            )),
-        }
+        BorrowedStr::deserialize(deserializer)?
+            .0
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
 /// A user answered poll for the minimum player age this game is best suited for.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct SuggestedPlayerAgePoll {
     /// User friendly name for the poll.
     pub title: String,
@@ -402,6 +462,7 @@ pub struct SuggestedPlayerAgePoll {
 
 /// A suggested minimum player age, along with how many users voted for this age.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct SuggestedPlayerAge {
     /// The number of players this vote result is for.
     pub player_age: PlayerAge,
@@ -413,6 +474,11 @@ pub struct SuggestedPlayerAge {
 ///
 /// Can be either an exact number age or a number or above.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum PlayerAge {
     /// An exact player age.
     Age(u64),
@@ -420,32 +486,32 @@ pub enum PlayerAge {
     AgeOrAbove(u64),
 }
 
-impl TryFrom<Poll> for SuggestedPlayerAgePoll {
+impl TryFrom<&Poll> for SuggestedPlayerAgePoll {
     // String as the error type, because this will be wrapped in a serde deserialize error during
     // the deserialise step.
     type Error = String;
 
-    fn try_from(mut poll: Poll) -> Result<Self, Self::Error> {
+    fn try_from(poll: &Poll) -> Result<Self, Self::Error> {
         if poll.results.len() != 1 {
             return Err(format!(
                 "expected 1 set of results but got {}",
                 poll.results.len(),
             ));
         }
-        let results = poll.results.remove(0).results;
         Ok(SuggestedPlayerAgePoll {
-            title: poll.title,
-            total_voters: poll.total_voters,
-            results: results
-                .into_iter()
+            title: poll.title.clone(),
+            total_voters: poll.total_votes,
+            results: poll.results[0]
+                .results
+                .iter()
                 .map(|result| {
                     Ok(SuggestedPlayerAge {
-                        player_age: result.value.try_into()?,
-                        votes: result.number_of_votes,
+                        player_age: result.value.clone().try_into()?,
+                        votes: result.votes,
                     })
                 })
                 .collect::<Result<Vec<SuggestedPlayerAge>, Self::Error>>()?,
-            summary: None,
+            summary: poll.poll_summary(),
         })
     }
 }
@@ -476,6 +542,7 @@ impl TryFrom<String> for PlayerAge {
 /// A user answered poll for how playable the game would be, should the player not speak the
 /// language.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct LanguageDependencePoll {
     /// User friendly name for the poll.
     pub title: String,
@@ -490,6 +557,7 @@ pub struct LanguageDependencePoll {
 
 /// A suggested minimum player age, along with how many users voted for this age.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct LanguageDependence {
     /// Level of dependence where the higher the value the more dependent the game is on knowing
     /// the language.
@@ -500,78 +568,109 @@ pub struct LanguageDependence {
     pub votes: u64,
 }
 
-impl TryFrom<Poll> for LanguageDependencePoll {
+impl TryFrom<&Poll> for LanguageDependencePoll {
     // String as the error type, because this will be wrapped in a serde deserialize error during
     // the deserialise step.
     type Error = String;
 
-    fn try_from(mut poll: Poll) -> Result<Self, Self::Error> {
+    fn try_from(poll: &Poll) -> Result<Self, Self::Error> {
         if poll.results.len() != 1 {
             return Err(format!(
                 "expected 1 set of results but got {}",
                 poll.results.len()
             ));
         }
-        let results = poll.results.remove(0).results;
         Ok(LanguageDependencePoll {
-            title: poll.title,
-            total_voters: poll.total_voters,
-            results: results
-                .into_iter()
+            title: poll.title.clone(),
+            total_voters: poll.total_votes,
+            results: poll.results[0]
+                .results
+                .iter()
                 .map(|result| {
                     Ok(LanguageDependence {
                         level: result.level.ok_or("missing language dependence level")?,
-                        dependence: result.value,
-                        votes: result.number_of_votes,
+                        dependence: result.value.clone(),
+                        votes: result.votes,
                     })
                 })
                 .collect::<Result<Vec<LanguageDependence>, Self::Error>>()?,
-            summary: None,
+            summary: poll.poll_summary(),
         })
     }
 }
 
-// A poll for users to vote on things such as the best player age and player count for the game.
+/// A poll that users have voted in for a game, such as the suggested player count, with every
+/// result returned by the underlying API.
+///
+/// The polls that are known about are also parsed into typed polls on the game, such as
+/// [`SuggestedPlayerCountPoll`], which can be converted from this with [`TryFrom`]. Any other
+/// polls are only available in this form.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
-struct Poll {
-    // Fixed slug name for the poll.
-    #[serde(rename = "@name")]
-    name: String,
-    // Pretty formatted title for the poll.
-    #[serde(rename = "@title")]
-    title: String,
-    // The total number of users who have voted on this poll.
-    #[serde(rename = "@totalvotes")]
-    total_voters: u64,
-    // List of results.
-    results: Vec<PollResults>,
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Poll {
+    /// The fixed name of the poll, such as `suggested_numplayers`.
+    #[serde(rename(deserialize = "@name"))]
+    pub name: String,
+    /// The title of the poll, as shown on the site.
+    #[serde(rename(deserialize = "@title"))]
+    pub title: String,
+    /// The total number of users who voted in this poll.
+    #[serde(rename(deserialize = "@totalvotes"))]
+    pub total_votes: u64,
+    /// The groups of results in the poll. Most polls have a single group, but the suggested player
+    /// count poll has one for each player count.
+    #[serde(default)]
+    pub results: Vec<PollResults>,
+    /// The lines of the summary of the poll, such as the best and recommended options. Empty if
+    /// the underlying API didn't return a summary for the poll.
+    #[serde(skip_deserializing)]
+    pub summary: Vec<PollSummaryResult>,
 }
 
-// A results for a poll.
+impl Poll {
+    // The best and recommended options from the summary, if it has both.
+    fn poll_summary(&self) -> Option<PollSummary> {
+        let summary_value = |name: &str| {
+            self.summary
+                .iter()
+                .find(|result| result.name == name)
+                .map(|result| result.value.clone())
+        };
+        Some(PollSummary {
+            best_with: summary_value("bestwith")?,
+            // The underlying API misspells this.
+            recommended_with: summary_value("recommmendedwith")?,
+        })
+    }
+}
+
+/// A group of results in a poll.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
-struct PollResults {
-    // Only included in the player count vote, the number of players this vote result is for.
-    #[serde(default, rename = "@numplayers")]
-    number_of_players: Option<PlayerCount>,
-    // List of results.
-    #[serde(rename = "result")]
-    results: Vec<PollResult>,
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct PollResults {
+    /// The number of players that this group of results is for. Only set for the suggested player
+    /// count poll.
+    #[serde(default, rename(deserialize = "@numplayers"))]
+    pub player_count: Option<PlayerCount>,
+    /// The options in this group and their votes.
+    #[serde(default, rename(deserialize = "result"))]
+    pub results: Vec<PollResult>,
 }
 
-// A result for a poll.
+/// A single option in a poll and how many votes it received.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
-struct PollResult {
-    // Only included for language dependence poll, level of dependence where the higher the value
-    // the more dependent the game is on knowing the language.
-    #[serde(default)]
-    #[serde(rename = "@level")]
-    level: Option<u64>,
-    // Name of the vote option.
-    #[serde(rename = "@value")]
-    value: String,
-    // How many people voted for it.
-    #[serde(rename = "@numvotes")]
-    number_of_votes: u64,
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct PollResult {
+    /// The option that was voted for, such as `Best` or `Recommended`.
+    #[serde(rename(deserialize = "@value"))]
+    pub value: String,
+    /// The level of the option, for polls where the options are ranked, such as language
+    /// dependence.
+    #[serde(default, rename(deserialize = "@level"))]
+    pub level: Option<u64>,
+    /// How many users voted for this option.
+    #[serde(rename(deserialize = "@numvotes"))]
+    pub votes: u64,
 }
 
 // A list of videos. Define the type in xml that can be deserialised, but pull out the nested
@@ -585,9 +684,10 @@ struct XmlVideos {
 
 /// A video relating to a game.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Video {
     /// ID of the video.
-    pub id: u64,
+    pub id: VideoId,
     /// The title of the video.
     pub title: String,
     /// Type of video, if it is a play-through or rules teach for example.
@@ -604,6 +704,7 @@ pub struct Video {
 
 /// Type of video for a video related to a particular game.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum VideoCategory {
     /// A video review of a game.
@@ -770,6 +871,7 @@ pub(crate) struct XmlMarketplaceListings {
 
 /// A sale listing, for people selling games or game accessories on the site.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct MarketplaceListing {
     /// The date and time when this listing was listed.
     pub list_date: DateTime<Utc>,
@@ -783,17 +885,35 @@ pub struct MarketplaceListing {
     pub link: String,
 }
 
+impl MarketplaceListing {
+    /// The price of the listing converted to the base currency of the given rates, or `None` if
+    /// its currency is missing from the rates.
+    pub fn normalized_price(&self, rates: &ExchangeRates) -> Option<Decimal> {
+        rates.convert(self.price.value, self.price.currency, rates.base())
+    }
+}
+
 /// The price of a game in a marketplace listing.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Price {
-    /// The name of the currency for this price value.
-    #[serde(rename = "@currency")]
-    pub currency: String,
-    /// The amount the game costs, as a string so the consumer can decide
-    /// to convert to float, or a decimal, or an integer for the dollar/euro/gbp and another
-    /// integer for the cents/pence or keep as a string depending on use case.
-    #[serde(rename = "@value")]
-    pub value: String,
+    /// The currency for this price value.
+    #[serde(rename(deserialize = "@currency"))]
+    pub currency: Currency,
+    /// The exact amount the game costs, in units of the currency such as dollars or euros.
+    #[serde(rename(deserialize = "@value"))]
+    pub value: Decimal,
+}
+
+impl Price {
+    /// Converts the price to another currency using the given rates, or `None` if either
+    /// currency is missing from the rates.
+    pub fn convert(&self, currency: Currency, rates: &ExchangeRates) -> Option<Price> {
+        Some(Price {
+            currency,
+            value: rates.convert(self.value, self.currency, currency)?,
+        })
+    }
 }
 
 // XML representation of the market place listing link
@@ -809,7 +929,10 @@ struct XmlMarketplaceLink {
 }
 
 /// The condition of a game for sale.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Ordered from the worst condition to the best.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum ItemCondition {
     /// Condition good enough to play, but no better.
@@ -923,29 +1046,34 @@ impl<'de> Deserialize<'de> for MarketplaceListing {
 
 /// A page of comments left on a game by a user. Can include a rating or a text comment or both.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct RatingCommentPage {
     /// The total number of comments overall, not the number in this page.
-    #[serde(rename = "@totalitems")]
+    #[serde(rename(deserialize = "@totalitems"))]
     pub total_items: u64,
     /// The index of this page, starting from 1.
-    #[serde(rename = "@page")]
+    #[serde(rename(deserialize = "@page"))]
     pub page_number: u64,
     /// A list of members in this guid.
-    #[serde(rename = "comment")]
+    #[serde(rename(deserialize = "comment"))]
     pub comments: Vec<RatingComment>,
 }
 
 /// A comment left on a game by a user. Can include a rating or a text comment or both.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct RatingComment {
     /// The user who left the comment.
-    #[serde(rename = "@username")]
+    #[serde(rename(deserialize = "@username"))]
     pub username: String,
     /// The rating, between 0 and 10, that the user left on the game.
-    #[serde(rename = "@rating", deserialize_with = "deserialize_rating")]
+    #[serde(
+        rename(deserialize = "@rating"),
+        deserialize_with = "deserialize_rating"
+    )]
     pub rating: Option<f64>,
     /// The text comment the user left on this game, may be empty.
-    #[serde(rename = "@value")]
+    #[serde(rename(deserialize = "@value"))]
     pub comment: String,
 }
 
@@ -953,9 +1081,9 @@ fn deserialize_rating<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    let s: String = serde::de::Deserialize::deserialize(deserializer)?;
+    let s = BorrowedStr::deserialize(deserializer)?.0;
 
-    match s.as_str() {
+    match s.as_ref() {
         "N/A" => Ok(None),
         val => match val.parse() {
             Ok(rating) => Ok(Some(rating)),
@@ -969,7 +1097,172 @@ where
 const PLAYER_COUNT_POLL_NAME: &str = "suggested_numplayers";
 const PLAYER_AGE_POLL_NAME: &str = "suggested_playerage";
 const LANGUAGE_DEPENDENCE_POLL_NAME: &str = "language_dependence";
+impl<S: AsRef<str>> GameDetails<S> {
+    /// The paragraphs of the game's description, see [`description_paragraphs`].
+    pub fn description_paragraphs(&self) -> Vec<&str> {
+        description_paragraphs(self.description.as_ref())
+    }
+
+    /// The poll with the given name, such as `suggested_numplayers`, if it was returned.
+    pub fn poll(&self, name: &str) -> Option<&Poll> {
+        self.polls.iter().find(|poll| poll.name == name)
+    }
+
+    /// The suggested number of players as selected by voters on the site.
+    ///
+    /// Poll results for whether each number of players is recommended, not recommended,
+    /// or best. Includes options outside of the suggested minimum and maximum player counts.
+    ///
+    /// Always returned for a game parsed from a response, only [`None`] if the poll has been
+    /// removed from [`GameDetails::polls`] or can't be read as a player count poll.
+    pub fn suggested_player_count(&self) -> Option<SuggestedPlayerCountPoll> {
+        self.poll(PLAYER_COUNT_POLL_NAME)
+            .and_then(|poll| SuggestedPlayerCountPoll::try_from(poll).ok())
+    }
+
+    /// The suggested number minimum suitable age for playing this game.
+    ///
+    /// Poll results for at which age is a suitable minimum age for playing this game. Like
+    /// [`GameDetails::suggested_player_count`], always returned for a game parsed from a response.
+    pub fn suggested_player_age(&self) -> Option<SuggestedPlayerAgePoll> {
+        self.poll(PLAYER_AGE_POLL_NAME)
+            .and_then(|poll| SuggestedPlayerAgePoll::try_from(poll).ok())
+    }
+
+    /// The suggested dependence on knowing the game's language in order to be able to play it.
+    ///
+    /// Poll results, with five options, for whether the game can be played without knowing the
+    /// language, if it does not have much in game text. Through being completely unplayable
+    /// due to extensive text in the game. Like [`GameDetails::suggested_player_count`], always
+    /// returned for a game parsed from a response.
+    pub fn suggested_language_dependence(&self) -> Option<LanguageDependencePoll> {
+        self.poll(LANGUAGE_DEPENDENCE_POLL_NAME)
+            .and_then(|poll| LanguageDependencePoll::try_from(poll).ok())
+    }
+
+    /// The versions of the game that support the given language, matched by the language's ID.
+    ///
+    /// Versions are only included in the game if they were requested with
+    /// [`crate::GameQueryParams::include_versions`].
+    pub fn versions_in_language(&self, language: &Language) -> Vec<&GameVersion> {
+        self.versions
+            .iter()
+            .filter(|version| {
+                version
+                    .languages
+                    .iter()
+                    .any(|version_language| version_language.id == language.id)
+            })
+            .collect()
+    }
+
+    /// The versions of the game that were published by the publisher with the given ID.
+    pub fn versions_by_publisher(&self, publisher_id: PublisherId) -> Vec<&GameVersion> {
+        self.versions
+            .iter()
+            .filter(|version| {
+                version
+                    .publishers
+                    .iter()
+                    .any(|publisher| publisher.id == publisher_id)
+            })
+            .collect()
+    }
+
+    /// The version of the game with the given product code, such as the code printed on the box.
+    /// Surrounding whitespace and case are ignored.
+    pub fn version_with_product_code(&self, product_code: &str) -> Option<&GameVersion> {
+        let product_code = product_code.trim();
+        self.versions.iter().find(|version| {
+            version
+                .product_code
+                .as_deref()
+                .is_some_and(|code| code.trim().eq_ignore_ascii_case(product_code))
+        })
+    }
+
+    /// The links from this game of the given type, in either direction.
+    pub fn links_of_type(&self, link_type: ItemType) -> Vec<&ItemLink> {
+        links_matching(&self.links, link_type, None)
+    }
+
+    /// The games that are reimplementations of this game.
+    pub fn reimplemented_by(&self) -> Vec<&ItemLink> {
+        links_matching(
+            &self.links,
+            ItemType::BoardGameImplementation,
+            Some(LinkDirection::Outbound),
+        )
+    }
+
+    /// The games that this game is a reimplementation of.
+    pub fn reimplements(&self) -> Vec<&ItemLink> {
+        links_matching(
+            &self.links,
+            ItemType::BoardGameImplementation,
+            Some(LinkDirection::Inbound),
+        )
+    }
+}
+
 impl<'de> Deserialize<'de> for GameDetails {
+    fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        GameDetailsRef::deserialize(deserializer).map(GameDetailsRef::into_owned)
+    }
+}
+
+impl GameDetailsRef<'_> {
+    /// Converts into an owned [`GameDetails`], copying any strings that are still borrowed.
+    pub fn into_owned(self) -> GameDetails {
+        GameDetails {
+            id: self.id,
+            game_type: self.game_type,
+            name: self.name.into_owned(),
+            alternate_names: self
+                .alternate_names
+                .into_iter()
+                .map(Cow::into_owned)
+                .collect(),
+            description: self.description.into_owned(),
+            image: self.image.map(Cow::into_owned),
+            thumbnail: self.thumbnail.map(Cow::into_owned),
+            year_published: self.year_published,
+            min_players: self.min_players,
+            max_players: self.max_players,
+            playing_time: self.playing_time,
+            min_play_time: self.min_play_time,
+            max_play_time: self.max_play_time,
+            min_age: self.min_age,
+            polls: self.polls,
+            categories: self.categories,
+            mechanics: self.mechanics,
+            game_families: self.game_families,
+            expansions: self.expansions,
+            expansion_for: self.expansion_for,
+            accessories: self.accessories,
+            compilations: self.compilations,
+            integrations: self.integrations,
+            reimplementations: self.reimplementations,
+            designers: self.designers,
+            artists: self.artists,
+            publishers: self.publishers,
+            links: self.links,
+            stats: self.stats,
+            versions: self.versions,
+            videos: self.videos,
+            marketplace_listings: self.marketplace_listings,
+            rating_comments: self.rating_comments,
+        }
+    }
+}
+
+impl From<GameDetailsRef<'_>> for GameDetails {
+    fn from(game: GameDetailsRef<'_>) -> Self {
+        game.into_owned()
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for GameDetailsRef<'a> {
     fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "lowercase")]
@@ -1000,10 +1293,12 @@ impl<'de> Deserialize<'de> for GameDetails {
             Comments,
         }
 
-        struct GameDetailsVisitor;
+        struct GameDetailsVisitor<'a> {
+            marker: PhantomData<GameDetailsRef<'a>>,
+        }
 
-        impl<'de> serde::de::Visitor<'de> for GameDetailsVisitor {
-            type Value = GameDetails;
+        impl<'de: 'a, 'a> serde::de::Visitor<'de> for GameDetailsVisitor<'a> {
+            type Value = GameDetailsRef<'a>;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
                 formatter.write_str("an XML object for a board game returned by the `thing` endpoint from boardgamegeek")
@@ -1031,7 +1326,8 @@ impl<'de> Deserialize<'de> for GameDetails {
                 let mut categories = vec![];
                 let mut mechanics = vec![];
                 let mut game_families = vec![];
-                let mut expansion_links = vec![];
+                let mut expansions = vec![];
+                let mut expansion_for = vec![];
                 let mut accessories = vec![];
                 let mut compilations = vec![];
                 let mut integrations = vec![];
@@ -1039,13 +1335,10 @@ impl<'de> Deserialize<'de> for GameDetails {
                 let mut designers = vec![];
                 let mut artists = vec![];
                 let mut publishers = vec![];
+                let mut links = vec![];
                 // Polls
-                let mut suggested_player_count = None;
-                let mut player_count_poll_summary = None;
-                let mut suggested_player_age = None;
-                let mut player_age_poll_summary = None;
-                let mut suggested_language_dependence = None;
-                let mut language_dependence_poll_summary = None;
+                let mut polls: Vec<Poll> = vec![];
+                let mut poll_summaries: Vec<XmlPollSummary> = vec![];
                 // Stats and optional
                 let mut stats = None;
                 let mut versions = None;
@@ -1070,16 +1363,22 @@ impl<'de> Deserialize<'de> for GameDetails {
                             if thumbnail.is_some() {
                                 return Err(serde::de::Error::duplicate_field("thumbnail"));
                             }
-                            thumbnail = Some(map.next_value()?);
+                            thumbnail = Some(
+                                map.next_value::<Option<BorrowedStr>>()?
+                                    .map(|thumbnail| thumbnail.0),
+                            );
                         },
                         Field::Image => {
                             if image.is_some() {
                                 return Err(serde::de::Error::duplicate_field("image"));
                             }
-                            image = Some(map.next_value()?);
+                            image = Some(
+                                map.next_value::<Option<BorrowedStr>>()?
+                                    .map(|image| image.0),
+                            );
                         },
                         Field::Name => {
-                            let name_xml: XmlName = map.next_value()?;
+                            let name_xml: XmlBorrowedName = map.next_value()?;
                             match name_xml.name_type {
                                 NameType::Primary => {
                                     if name.is_some() {
@@ -1087,10 +1386,10 @@ impl<'de> Deserialize<'de> for GameDetails {
                                             "name type=\"primary\"",
                                         ));
                                     }
-                                    name = Some(name_xml.value);
+                                    name = Some(name_xml.value.0);
                                 },
                                 NameType::Alternate => {
-                                    alternate_names.push(name_xml.value);
+                                    alternate_names.push(name_xml.value.0);
                                 },
                             }
                         },
@@ -1098,7 +1397,8 @@ impl<'de> Deserialize<'de> for GameDetails {
                             if description.is_some() {
                                 return Err(serde::de::Error::duplicate_field("description"));
                             }
-                            description = Some(map.next_value()?);
+                            description =
+                                Some(decode_html_entities(map.next_value::<BorrowedStr>()?.0));
                         },
                         Field::YearPublished => {
                             if year_published.is_some() {
@@ -1151,70 +1451,79 @@ impl<'de> Deserialize<'de> for GameDetails {
                         },
                         Field::Link => {
                             let link: XmlLink = map.next_value()?;
+                            links.push(ItemLink::from(&link));
                             match link.link_type {
+                                // An inbound expansion link is to the game this is an expansion
+                                // for, rather than to an expansion of this game.
+                                crate::ItemType::BoardGameExpansion if link.inbound => {
+                                    expansion_for.push(Game {
+                                        id: GameId(link.id),
+                                        name: link.value,
+                                    });
+                                },
                                 crate::ItemType::BoardGameExpansion => {
-                                    expansion_links.push(Game {
-                                        id: link.id,
+                                    expansions.push(Game {
+                                        id: GameId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameAccessory => {
                                     accessories.push(GameAccessory {
-                                        id: link.id,
+                                        id: AccessoryId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameDesigner => {
                                     designers.push(GameDesigner {
-                                        id: link.id,
+                                        id: PersonId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGamePublisher => {
                                     publishers.push(GamePublisher {
-                                        id: link.id,
+                                        id: PublisherId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameArtist => {
                                     artists.push(GameArtist {
-                                        id: link.id,
+                                        id: PersonId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameFamily => {
                                     game_families.push(GameFamilyName {
-                                        id: link.id,
+                                        id: FamilyId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameCategory => {
                                     categories.push(GameCategory {
-                                        id: link.id,
+                                        id: CategoryId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameMechanic => {
                                     mechanics.push(GameMechanic {
-                                        id: link.id,
+                                        id: MechanicId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameCompilation => {
                                     compilations.push(GameCompilation {
-                                        id: link.id,
+                                        id: GameId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameIntegration => {
                                     integrations.push(GameIntegration {
-                                        id: link.id,
+                                        id: GameId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 crate::ItemType::BoardGameImplementation => {
                                     reimplementations.push(GameImplementation {
-                                        id: link.id,
+                                        id: GameId(link.id),
                                         name: link.value,
                                     });
                                 },
@@ -1227,47 +1536,16 @@ impl<'de> Deserialize<'de> for GameDetails {
                         },
                         Field::Poll => {
                             let poll: Poll = map.next_value()?;
-
-                            if poll.name == PLAYER_COUNT_POLL_NAME {
-                                if suggested_player_count.is_some() {
-                                    return Err(serde::de::Error::duplicate_field(
-                                        "poll name=\"suggested_numplayers\"",
-                                    ));
-                                }
-                                suggested_player_count =
-                                    Some(poll.try_into().map_err(serde::de::Error::custom)?);
-                            } else if poll.name == PLAYER_AGE_POLL_NAME {
-                                if suggested_player_age.is_some() {
-                                    return Err(serde::de::Error::duplicate_field(
-                                        "poll name=\"suggested_playerage\"",
-                                    ));
-                                }
-                                suggested_player_age =
-                                    Some(poll.try_into().map_err(serde::de::Error::custom)?);
-                            } else if poll.name == LANGUAGE_DEPENDENCE_POLL_NAME {
-                                if suggested_language_dependence.is_some() {
-                                    return Err(serde::de::Error::duplicate_field(
-                                        "poll name=\"language_dependence\"",
-                                    ));
-                                }
-                                suggested_language_dependence =
-                                    Some(poll.try_into().map_err(serde::de::Error::custom)?);
-                            } else {
+                            if polls.iter().any(|existing| existing.name == poll.name) {
                                 return Err(serde::de::Error::custom(format!(
-                                    "unexpected poll name `{}`",
-                                    poll.name
+                                    "duplicate poll `{}`",
+                                    poll.name,
                                 )));
                             }
+                            polls.push(poll);
                         },
                         Field::PollSummary => {
-                            let poll_summary: PollSummaryWithName = map.next_value()?;
-                            if poll_summary.name == PLAYER_COUNT_POLL_NAME {
-                                player_count_poll_summary = Some(poll_summary);
-                            } else if poll_summary.name == PLAYER_AGE_POLL_NAME {
-                                player_age_poll_summary = Some(poll_summary);
-                            } else if poll_summary.name == LANGUAGE_DEPENDENCE_POLL_NAME {
-                                language_dependence_poll_summary = Some(poll_summary);
-                            }
+                            poll_summaries.push(map.next_value()?);
                         },
                         Field::Statistics => {
                             if stats.is_some() {
@@ -1347,47 +1625,42 @@ impl<'de> Deserialize<'de> for GameDetails {
                     max_play_time.ok_or_else(|| serde::de::Error::missing_field("maxplaytime"))?;
                 let min_age = min_age.ok_or_else(|| serde::de::Error::missing_field("minage"))?;
 
-                let mut suggested_player_count: SuggestedPlayerCountPoll = suggested_player_count
-                    .ok_or_else(|| {
-                    serde::de::Error::missing_field("poll name=\"suggested_numplayers\"")
-                })?;
-                if let Some(summary) = player_count_poll_summary {
-                    suggested_player_count.summary = Some(PollSummary {
-                        recommended_with: summary.recommended_with,
-                        best_with: summary.best_with,
-                    });
-                }
-                let mut suggested_player_age: SuggestedPlayerAgePoll = suggested_player_age
-                    .ok_or_else(|| {
-                        serde::de::Error::missing_field("poll name=\"suggested_playerage\"")
-                    })?;
-                if let Some(summary) = player_age_poll_summary {
-                    suggested_player_age.summary = Some(PollSummary {
-                        recommended_with: summary.recommended_with,
-                        best_with: summary.best_with,
-                    });
-                }
-                let mut suggested_language_dependence: LanguageDependencePoll =
-                    suggested_language_dependence.ok_or_else(|| {
-                        serde::de::Error::missing_field("poll name=\"language_dependence\"")
-                    })?;
-                if let Some(summary) = language_dependence_poll_summary {
-                    suggested_language_dependence.summary = Some(PollSummary {
-                        recommended_with: summary.recommended_with,
-                        best_with: summary.best_with,
-                    });
+                // Summaries come after the polls they are for, so are only added once all the
+                // polls have been read.
+                for summary in poll_summaries {
+                    if let Some(poll) = polls.iter_mut().find(|poll| poll.name == summary.name) {
+                        poll.summary = summary.results;
+                    }
                 }
+                let find_poll = |name: &str, field: &'static str| {
+                    polls
+                        .iter()
+                        .find(|poll| poll.name == name)
+                        .ok_or_else(|| serde::de::Error::missing_field(field))
+                };
+                // The typed polls are only read when asked for, but are checked here so that a
+                // game which parses can always be read from.
+                SuggestedPlayerCountPoll::try_from(find_poll(
+                    PLAYER_COUNT_POLL_NAME,
+                    "poll name=\"suggested_numplayers\"",
+                )?)
+                .map_err(serde::de::Error::custom)?;
+                SuggestedPlayerAgePoll::try_from(find_poll(
+                    PLAYER_AGE_POLL_NAME,
+                    "poll name=\"suggested_playerage\"",
+                )?)
+                .map_err(serde::de::Error::custom)?;
+                LanguageDependencePoll::try_from(find_poll(
+                    LANGUAGE_DEPENDENCE_POLL_NAME,
+                    "poll name=\"language_dependence\"",
+                )?)
+                .map_err(serde::de::Error::custom)?;
 
                 let stats = stats.ok_or_else(|| serde::de::Error::missing_field("statistics"))?;
                 let versions = versions.unwrap_or_default();
                 let videos = videos.unwrap_or_default();
                 let marketplace_listings = marketplace_listings.unwrap_or_default();
 
-                let (expansions, expansion_for) = match game_type {
-                    GameType::BoardGame => (expansion_links, vec![]),
-                    GameType::BoardGameExpansion => (vec![], expansion_links),
-                };
-
                 Ok(Self::Value {
                     id,
                     game_type,
@@ -1399,13 +1672,11 @@ impl<'de> Deserialize<'de> for GameDetails {
                     year_published,
                     min_players,
                     max_players,
-                    suggested_player_count,
                     playing_time,
                     min_play_time,
                     max_play_time,
                     min_age,
-                    suggested_player_age,
-                    suggested_language_dependence,
+                    polls,
                     categories,
                     mechanics,
                     game_families,
@@ -1418,6 +1689,7 @@ impl<'de> Deserialize<'de> for GameDetails {
                     designers,
                     artists,
                     publishers,
+                    links,
                     stats,
                     versions,
                     videos,
@@ -1449,6 +1721,12 @@ impl<'de> Deserialize<'de> for GameDetails {
             "marketplacelistings",
             "comments",
         ];
-        deserializer.deserialize_struct("GameDetails", FIELDS, GameDetailsVisitor)
+        deserializer.deserialize_struct(
+            "GameDetails",
+            FIELDS,
+            GameDetailsVisitor {
+                marker: PhantomData,
+            },
+        )
     }
 }
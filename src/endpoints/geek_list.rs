@@ -0,0 +1,234 @@
+use crate::{BoardGameGeekApi, GeekList, GeekListId, IntoQueryParam, QueryParam, Result};
+
+/// All optional query parameters for making a request to the geek list endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct GeekListQueryParams {
+    include_comments: Option<bool>,
+}
+
+impl GeekListQueryParams {
+    /// Construct a default `GeekListQueryParams` with no parameters set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `comments` query parameter. If set to true then comments on the geek list, and on
+    /// each of its items, are included in the response. Not included by default.
+    pub fn include_comments(mut self, include_comments: bool) -> Self {
+        self.include_comments = Some(include_comments);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct GeekListQueryBuilder<'builder> {
+    params: &'builder GeekListQueryParams,
+}
+
+impl<'builder> GeekListQueryBuilder<'builder> {
+    fn new(params: &'builder GeekListQueryParams) -> Self {
+        Self { params }
+    }
+
+    fn build(self) -> Vec<QueryParam<'builder>> {
+        let mut query_params: Vec<_> = vec![];
+        if let Some(include_comments) = self.params.include_comments {
+            query_params.push(include_comments.into_query_param("comments"));
+        }
+        query_params
+    }
+}
+
+/// Geek list endpoint of the API. Used for returning a geek list, along with its items, by its ID.
+///
+/// Geek lists are only available from the older XML API v1, so unlike the other endpoints the
+/// requests are made to `/xmlapi` rather than `/xmlapi2`.
+pub struct GeekListApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> GeekListApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "geeklist",
+        }
+    }
+
+    /// Get a geek list by ID, with optional query params.
+    pub async fn get(
+        &self,
+        geek_list_id: GeekListId,
+        query_params: &GeekListQueryParams,
+    ) -> Result<GeekList> {
+        let query = GeekListQueryBuilder::new(query_params);
+
+        let request = self.api.build_v1_request(
+            &format!("{}/{}", self.endpoint, geek_list_id),
+            &query.build(),
+        );
+        let geek_list = self.api.execute_request::<GeekList>(request).await?;
+
+        Ok(geek_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, NaiveDate, Utc};
+    use mockito::Matcher;
+
+    use crate::{
+        BoardGameGeekApi, Domain, Error, GeekList, GeekListComment, GeekListId, GeekListItem,
+        GeekListItemId, GeekListQueryParams, ObjectId, StaticTokenProvider,
+    };
+
+    fn date_time(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        DateTime::from_naive_utc_and_offset(
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_hms_opt(hour, min, sec)
+                .unwrap(),
+            Utc,
+        )
+    }
+
+    #[tokio::test]
+    async fn get() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/xmlapi/geeklist/331520")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "comments".to_owned(),
+                "1".to_owned(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/geek_list/geek_list.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let geek_list = api
+            .geek_list()
+            .get(
+                GeekListId(331_520),
+                &GeekListQueryParams::new().include_comments(true),
+            )
+            .await;
+        mock.assert_async().await;
+
+        assert!(geek_list.is_ok(), "error returned when okay expected");
+        let geek_list = geek_list.unwrap();
+
+        assert_eq!(
+            geek_list,
+            GeekList {
+                id: GeekListId(331_520),
+                post_date: date_time(2024, 1, 2, 9, 14, 27),
+                edit_date: date_time(2024, 1, 6, 18, 2, 51),
+                thumbs: 87,
+                number_of_items: 2,
+                username: "bluebearbgg".to_owned(),
+                title: "Best 2-player games of 2024".to_owned(),
+                description: "Games that really shine with two. Thumb the ones you agree with & add a comment if I missed one!".to_owned(),
+                comments: vec![GeekListComment {
+                    username: "Kyzy".to_owned(),
+                    post_date: date_time(2024, 1, 3, 11, 40, 2),
+                    edit_date: date_time(2024, 1, 3, 11, 52, 18),
+                    thumbs: 4,
+                    text: "Great list, thanks for putting it together.".to_owned(),
+                }],
+                items: vec![
+                    GeekListItem {
+                        id: GeekListItemId(10_391_842),
+                        object_type: "thing".to_owned(),
+                        subtype: "boardgame".to_owned(),
+                        object_id: ObjectId(312_484),
+                        object_name: "Lost Ruins of Arnak".to_owned(),
+                        username: "bluebearbgg".to_owned(),
+                        post_date: date_time(2024, 1, 2, 9, 20, 11),
+                        edit_date: date_time(2024, 1, 2, 9, 20, 11),
+                        thumbs: 31,
+                        image_id: Some(6_130_238),
+                        body: "Plays wonderfully at two, the tension over the sites never lets up."
+                            .to_owned(),
+                        comments: vec![
+                            GeekListComment {
+                                username: "Kyzy".to_owned(),
+                                post_date: date_time(2024, 1, 3, 11, 45, 30),
+                                edit_date: date_time(2024, 1, 3, 11, 45, 30),
+                                thumbs: 2,
+                                text: "Agreed, the solo mode is good too.".to_owned(),
+                            },
+                            GeekListComment {
+                                username: "Tarrasque".to_owned(),
+                                post_date: date_time(2024, 1, 4, 20, 1, 9),
+                                edit_date: date_time(2024, 1, 4, 20, 1, 9),
+                                thumbs: 0,
+                                text: "Better with the expansion.".to_owned(),
+                            },
+                        ],
+                    },
+                    GeekListItem {
+                        id: GeekListItemId(10_391_907),
+                        object_type: "thing".to_owned(),
+                        subtype: "boardgameexpansion".to_owned(),
+                        object_id: ObjectId(341_254),
+                        object_name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
+                        username: "bluebearbgg".to_owned(),
+                        post_date: date_time(2024, 1, 2, 9, 26, 45),
+                        edit_date: date_time(2024, 1, 5, 16, 33, 20),
+                        thumbs: 12,
+                        image_id: None,
+                        body: String::new(),
+                        comments: vec![],
+                    },
+                ],
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/xmlapi/geeklist/999999999")
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/geek_list/geek_list_not_found.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let geek_list = api
+            .geek_list()
+            .get(GeekListId(999_999_999), &GeekListQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(geek_list, Err(Error::ItemNotFound)),
+            "expected item not found error but got {geek_list:?}",
+        );
+    }
+}
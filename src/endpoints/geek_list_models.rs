@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::deserialize::deserialize_date_time_with_zone;
+use crate::{GeekListId, GeekListItemId, ObjectId};
+
+/// A geek list, a curated list of items such as games, created by a user. For example "Best
+/// 2-player games of 2024".
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct GeekList {
+    /// The ID of the geek list.
+    #[serde(rename(deserialize = "@id"))]
+    pub id: GeekListId,
+    /// The date that the geek list was posted.
+    #[serde(
+        rename(deserialize = "postdate"),
+        deserialize_with = "deserialize_date_time_with_zone"
+    )]
+    pub post_date: DateTime<Utc>,
+    /// The date that the geek list was last edited, same as the post date if it has not been
+    /// edited.
+    #[serde(
+        rename(deserialize = "editdate"),
+        deserialize_with = "deserialize_date_time_with_zone"
+    )]
+    pub edit_date: DateTime<Utc>,
+    /// The number of thumbs up the geek list has received.
+    pub thumbs: u64,
+    /// The number of items in the geek list.
+    #[serde(rename(deserialize = "numitems"))]
+    pub number_of_items: u64,
+    /// The username of the user who created the geek list.
+    pub username: String,
+    /// The title of the geek list.
+    pub title: String,
+    /// The description of the geek list, written by its creator.
+    pub description: String,
+    /// Comments made on the geek list as a whole. Only included if requested.
+    #[serde(default = "Vec::new", rename(deserialize = "comment"))]
+    pub comments: Vec<GeekListComment>,
+    /// The items in the geek list, in the order they appear.
+    #[serde(default = "Vec::new", rename(deserialize = "item"))]
+    pub items: Vec<GeekListItem>,
+}
+
+/// An entry in a geek list, a link to an item on the site along with some text about it.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct GeekListItem {
+    /// The ID of the entry in the geek list. This is not the ID of the item that it refers to.
+    #[serde(rename(deserialize = "@id"))]
+    pub id: GeekListItemId,
+    /// The type of the item the entry refers to, such as `thing` for games and accessories, or
+    /// `family` for game families.
+    #[serde(rename(deserialize = "@objecttype"))]
+    pub object_type: String,
+    /// The more specific type of the item the entry refers to, such as `boardgame` or
+    /// `boardgameexpansion`.
+    #[serde(rename(deserialize = "@subtype"))]
+    pub subtype: String,
+    /// The ID of the item the entry refers to. What kind of item this is depends on the object
+    /// type and subtype, for a `thing` with a `boardgame` subtype this is the ID of a game.
+    #[serde(rename(deserialize = "@objectid"))]
+    pub object_id: ObjectId,
+    /// The name of the item the entry refers to.
+    #[serde(rename(deserialize = "@objectname"))]
+    pub object_name: String,
+    /// The username of the user who added the entry.
+    #[serde(rename(deserialize = "@username"))]
+    pub username: String,
+    /// The date that the entry was added.
+    #[serde(
+        rename(deserialize = "@postdate"),
+        deserialize_with = "deserialize_date_time_with_zone"
+    )]
+    pub post_date: DateTime<Utc>,
+    /// The date that the entry was last edited, same as the post date if it has not been edited.
+    #[serde(
+        rename(deserialize = "@editdate"),
+        deserialize_with = "deserialize_date_time_with_zone"
+    )]
+    pub edit_date: DateTime<Utc>,
+    /// The number of thumbs up the entry has received.
+    #[serde(rename(deserialize = "@thumbs"))]
+    pub thumbs: u64,
+    /// The ID of the image shown with the entry, if one was chosen.
+    #[serde(
+        rename(deserialize = "@imageid"),
+        deserialize_with = "deserialize_image_id"
+    )]
+    pub image_id: Option<u64>,
+    /// The text written about the item for this entry. Can be empty.
+    #[serde(default)]
+    pub body: String,
+    /// Comments made on this entry. Only included if requested.
+    #[serde(default = "Vec::new", rename(deserialize = "comment"))]
+    pub comments: Vec<GeekListComment>,
+}
+
+/// A comment made by a user on a geek list, or on an entry in a geek list.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct GeekListComment {
+    /// The username of the user who posted the comment.
+    #[serde(rename(deserialize = "@username"))]
+    pub username: String,
+    /// The date that the comment was posted.
+    #[serde(
+        rename(deserialize = "@postdate"),
+        deserialize_with = "deserialize_date_time_with_zone"
+    )]
+    pub post_date: DateTime<Utc>,
+    /// The date that the comment was last edited, same as the post date if it has not been
+    /// edited.
+    #[serde(
+        rename(deserialize = "@editdate"),
+        deserialize_with = "deserialize_date_time_with_zone"
+    )]
+    pub edit_date: DateTime<Utc>,
+    /// The number of thumbs up the comment has received.
+    #[serde(rename(deserialize = "@thumbs"))]
+    pub thumbs: u64,
+    /// The content of the comment.
+    #[serde(default, rename(deserialize = "#text"))]
+    pub text: String,
+}
+
+// The API returns an image ID of 0 when no image was chosen for the entry.
+fn deserialize_image_id<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let image_id = u64::deserialize(deserializer)?;
+    Ok((image_id != 0).then_some(image_id))
+}
@@ -1,5 +1,5 @@
 use super::Guild;
-use crate::{BoardGameGeekApi, IntoQueryParam, QueryParam, Result};
+use crate::{BoardGameGeekApi, GuildId, IntoQueryParam, QueryParam, Result};
 
 /// Which field to sort the list of members by, either username or date joined.
 #[derive(Clone, Copy, Debug)]
@@ -51,12 +51,12 @@ impl GuildQueryParams {
 
 #[derive(Clone, Debug)]
 struct GuildQueryBuilder<'builder> {
-    guild_id: u64,
+    guild_id: GuildId,
     params: &'builder GuildQueryParams,
 }
 
 impl<'builder> GuildQueryBuilder<'builder> {
-    fn new(guild_id: u64, params: &'builder GuildQueryParams) -> Self {
+    fn new(guild_id: GuildId, params: &'builder GuildQueryParams) -> Self {
         Self { guild_id, params }
     }
 
@@ -95,7 +95,7 @@ impl<'api> GuildApi<'api> {
     }
 
     /// Gets a guild via the provided query params.
-    pub async fn get(&self, guild_id: u64, query_params: &GuildQueryParams) -> Result<Guild> {
+    pub async fn get(&self, guild_id: GuildId, query_params: &GuildQueryParams) -> Result<Guild> {
         let query = GuildQueryBuilder::new(guild_id, query_params);
 
         let request = self.api.build_request(self.endpoint, &query.build());
@@ -105,11 +105,13 @@ impl<'api> GuildApi<'api> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{TimeZone, Utc};
     use mockito::Matcher;
 
     use super::*;
-    use crate::{Guild, Location, Member, MemberPage};
+    use crate::{Domain, Guild, GuildId, Location, Member, MemberPage, StaticTokenProvider};
 
     #[tokio::test]
     async fn get_by_id() {
@@ -117,6 +119,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -133,7 +137,7 @@ mod tests {
             .create_async()
             .await;
 
-        let guild = api.guild().get(13, &GuildQueryParams::new()).await;
+        let guild = api.guild().get(GuildId(13), &GuildQueryParams::new()).await;
         mock.assert_async().await;
 
         assert!(guild.is_ok(), "error returned when okay expected");
@@ -142,7 +146,7 @@ mod tests {
         assert_eq!(
             guild,
             Guild {
-                id: 13,
+                id: GuildId(13),
                 name: "Con of the North".to_owned(),
                 created_at: Utc.with_ymd_and_hms(2007, 6, 14, 1, 6, 46).unwrap(),
                 category: "event".to_owned(),
@@ -170,6 +174,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -189,7 +195,7 @@ mod tests {
 
         let guild = api
             .guild()
-            .get(13, &GuildQueryParams::new().include_member_page(2))
+            .get(GuildId(13), &GuildQueryParams::new().include_member_page(2))
             .await;
         mock.assert_async().await;
 
@@ -199,7 +205,7 @@ mod tests {
         assert_eq!(
             guild,
             Guild {
-                id: 13,
+                id: GuildId(13),
                 name: "Con of the North".to_owned(),
                 created_at: Utc.with_ymd_and_hms(2007, 6, 14, 1, 6, 46).unwrap(),
                 category: "event".to_owned(),
@@ -240,6 +246,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -261,7 +269,7 @@ mod tests {
         let params = GuildQueryParams::new()
             .include_member_page(5)
             .sort_by(GuildMemberSortBy::DateJoined);
-        let guild = api.guild().get(13, &params).await;
+        let guild = api.guild().get(GuildId(13), &params).await;
         mock.assert_async().await;
 
         assert!(guild.is_ok(), "error returned when okay expected");
@@ -2,20 +2,22 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use crate::deserialize::deserialize_date_time_with_zone;
+use crate::GuildId;
 
 /// A struct with information for a guild, returned by the guild endpoint of the API.
 /// If requested it can also return the guild members, but only up to 25 at a time.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Guild {
     /// The ID of the guild.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: GuildId,
     /// The name of the guild.
-    #[serde(rename = "@name")]
+    #[serde(rename(deserialize = "@name"))]
     pub name: String,
     /// The date and time the guild was created.
     #[serde(
-        rename = "@created",
+        rename(deserialize = "@created"),
         deserialize_with = "deserialize_date_time_with_zone"
     )]
     pub created_at: DateTime<Utc>,
@@ -33,22 +35,23 @@ pub struct Guild {
     /// A page of the guild's members, up to a maximum of 25. Will be omitted
     /// if not requested, or if the page requested is out of bounds for the
     /// number of members.
-    #[serde(rename = "members")]
+    #[serde(rename(deserialize = "members"))]
     pub member_page: Option<MemberPage>,
 }
 
 /// A page of members in a particular guild, up to 25.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct MemberPage {
     /// The total number of members in the guild. Not the number in this
     /// page.
-    #[serde(rename = "@count")]
+    #[serde(rename(deserialize = "@count"))]
     pub total_members: u64,
     /// The index of this page, starting from 1.
-    #[serde(rename = "@page")]
+    #[serde(rename(deserialize = "@page"))]
     pub page_number: u64,
     /// A list of members in this guid.
-    #[serde(rename = "member")]
+    #[serde(rename(deserialize = "member"))]
     pub members: Vec<Member>,
 }
 
@@ -56,33 +59,38 @@ pub struct MemberPage {
 ///
 /// Includes their boardgamegeek username, and the date they joined the guild.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Member {
     /// The username of the guild member.
-    #[serde(rename = "@name")]
+    #[serde(rename(deserialize = "@name"))]
     pub name: String,
     /// The date and time the user joined the guild, in Utc.
-    #[serde(rename = "@date", deserialize_with = "deserialize_date_time_with_zone")]
+    #[serde(
+        rename(deserialize = "@date"),
+        deserialize_with = "deserialize_date_time_with_zone"
+    )]
     pub date_joined: DateTime<Utc>,
 }
 
 /// A location of a guild. It is optional to set so some or
 /// all values may be empty strings.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Location {
     /// First line of the guild address.
-    #[serde(rename = "addr1")]
+    #[serde(rename(deserialize = "addr1"))]
     pub address_line_1: String,
     /// Second line of the guild address.
-    #[serde(rename = "addr2")]
+    #[serde(rename(deserialize = "addr2"))]
     pub address_line_2: String,
     /// City where the guild is based.
     pub city: String,
     /// State/province/county where the guild is based.
-    #[serde(rename = "stateorprovince")]
+    #[serde(rename(deserialize = "stateorprovince"))]
     pub state: String,
     /// Country where the guild is based.
     pub country: String,
     /// Guild address postal code.
-    #[serde(rename = "postalcode")]
+    #[serde(rename(deserialize = "postalcode"))]
     pub postal_code: String,
 }
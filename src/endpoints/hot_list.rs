@@ -1,8 +1,10 @@
-use super::{HotList, HotListGame};
-use crate::{BoardGameGeekApi, Result};
+use serde::de::DeserializeOwned;
+
+use super::{HotList, HotListCompany, HotListGame, HotListItem, HotListPerson, HotListType};
+use crate::{BoardGameGeekApi, Error, IntoQueryParam, Result};
 
 /// Hot list endpoint of the API. Used for returning the current trending board
-/// games.
+/// games, or the current trending people and companies.
 pub struct HotListApi<'api> {
     pub(crate) api: &'api BoardGameGeekApi,
     endpoint: &'static str,
@@ -17,20 +19,83 @@ impl<'api> HotListApi<'api> {
     }
 
     /// Gets the current list of hot board games.
+    ///
+    /// If the API is set to the [`Domain::Rpg`] domain this is the list of hot role-playing game
+    /// systems, and for the [`Domain::VideoGame`] domain the list of hot video games.
+    ///
+    /// [`Domain::Rpg`]: crate::Domain::Rpg
+    /// [`Domain::VideoGame`]: crate::Domain::VideoGame
     pub async fn get(&self) -> Result<Vec<HotListGame>> {
-        let request = self.api.build_request(self.endpoint, &[]);
-        let response = self.api.execute_request::<HotList>(request).await?;
+        self.get_items(HotListType::Game).await
+    }
+
+    /// Gets the current list of hot people, such as designers and artists.
+    ///
+    /// There is no list of hot people for the [`Domain::VideoGame`] domain, so an
+    /// [`Error::UnsupportedDomain`] is returned.
+    ///
+    /// [`Domain::VideoGame`]: crate::Domain::VideoGame
+    pub async fn get_people(&self) -> Result<Vec<HotListPerson>> {
+        self.get_items(HotListType::Person).await
+    }
+
+    /// Gets the current list of hot companies, such as publishers.
+    pub async fn get_companies(&self) -> Result<Vec<HotListCompany>> {
+        self.get_items(HotListType::Company).await
+    }
+
+    /// Gets the current hot list of the given type, with each item wrapped in a [`HotListItem`] of
+    /// the matching kind.
+    ///
+    /// The same as [`HotListApi::get`], [`HotListApi::get_people`] or
+    /// [`HotListApi::get_companies`], for when the type of list is only known at runtime.
+    pub async fn get_by_type(&self, hot_list_type: HotListType) -> Result<Vec<HotListItem>> {
+        match hot_list_type {
+            HotListType::Game => self.get_items_as(hot_list_type, HotListItem::Game).await,
+            HotListType::Person => self.get_items_as(hot_list_type, HotListItem::Person).await,
+            HotListType::Company => self.get_items_as(hot_list_type, HotListItem::Company).await,
+        }
+    }
+
+    // Gets the hot list of the given type, wrapping each item in the matching kind of hot list
+    // item.
+    async fn get_items_as<T: DeserializeOwned>(
+        &self,
+        hot_list_type: HotListType,
+        wrap: fn(T) -> HotListItem,
+    ) -> Result<Vec<HotListItem>> {
+        Ok(self
+            .get_items(hot_list_type)
+            .await?
+            .into_iter()
+            .map(wrap)
+            .collect())
+    }
+
+    async fn get_items<T: DeserializeOwned>(&self, hot_list_type: HotListType) -> Result<Vec<T>> {
+        let domain = self.api.domain;
+        let hot_list_type = hot_list_type
+            .for_domain(domain)
+            .ok_or(Error::UnsupportedDomain(domain))?;
+        let query = [hot_list_type.into_query_param("type")];
+        let request = self.api.build_request(self.endpoint, &query);
+        let response = self.api.execute_request::<HotList<T>>(request).await?;
 
-        Ok(response.games)
+        Ok(response.items)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use mockito::Matcher;
 
     use super::*;
-    use crate::HotListGame;
+    use crate::{
+        Domain, GameId, HotListCompany, HotListGame, HotListPerson, PersonId, PublisherId,
+        StaticTokenProvider,
+    };
 
     #[tokio::test]
     async fn get() {
@@ -38,6 +103,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -61,7 +128,7 @@ mod tests {
         assert_eq!(
             hot_list[0],
             HotListGame {
-                id: 359_871,
+                id: GameId(359_871),
                 rank: 1,
                 thumbnail: Some("https://cf.geekdo-images.com/XWImAu_3RK61wbzcKboVdA__thumb/img/Ry-6KHwNgERWadyxs1X1_P3dMvY=/fit-in/200x150/filters:strip_icc()/pic8145530.png".to_owned()),
                 name: "Arcs".to_owned(),
@@ -69,4 +136,194 @@ mod tests {
             },
         );
     }
+
+    #[tokio::test]
+    async fn get_people() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/hot")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "type".to_owned(),
+                "boardgameperson".to_owned(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/hot_list/hot_list_people.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let hot_list = api.hot_list().get_people().await;
+        mock.assert_async().await;
+
+        assert!(hot_list.is_ok(), "error returned when okay expected");
+        let hot_list = hot_list.unwrap();
+
+        assert_eq!(hot_list.len(), 3);
+        assert_eq!(
+            hot_list[0],
+            HotListPerson {
+                id: PersonId(2),
+                rank: 1,
+                thumbnail: Some("https://cf.geekdo-images.com/bNAu4x8HROzDNvV3Xp0Cyw__thumb/img/WVM4LLGzGCMqV6DOC4gswn6Fh9k=/fit-in/200x150/filters:strip_icc()/pic8124155.jpg".to_owned()),
+                name: "Reiner Knizia".to_owned(),
+            },
+        );
+        assert_eq!(
+            hot_list[2],
+            HotListPerson {
+                id: PersonId(145_639),
+                rank: 3,
+                thumbnail: None,
+                name: "Mín & Elwen".to_owned(),
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_companies() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/hot")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "type".to_owned(),
+                "boardgamecompany".to_owned(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/hot_list/hot_list_companies.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let hot_list = api.hot_list().get_companies().await;
+        mock.assert_async().await;
+
+        assert!(hot_list.is_ok(), "error returned when okay expected");
+        let hot_list = hot_list.unwrap();
+
+        assert_eq!(
+            hot_list,
+            vec![
+                HotListCompany {
+                    id: PublisherId(7345),
+                    rank: 1,
+                    thumbnail: Some("https://cf.geekdo-images.com/rANTWqFmYm2u7dgKLLfHLQ__thumb/img/xQLb9QAH6Cx4E7Dq3OhFfAV5pTQ=/fit-in/200x150/filters:strip_icc()/pic3396958.png".to_owned()),
+                    name: "Czech Games Edition".to_owned(),
+                },
+                HotListCompany {
+                    id: PublisherId(35886),
+                    rank: 2,
+                    thumbnail: Some("https://cf.geekdo-images.com/9TQ4m6BX_BLNngSwRAeOoA__thumb/img/Xh1cCTLWyeXzzA2Ej1P5WqTBqNE=/fit-in/200x150/filters:strip_icc()/pic4937123.png".to_owned()),
+                    name: "Leder Games".to_owned(),
+                },
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn get_rpg_domain() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::Rpg,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/hot")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "type".to_owned(),
+                "rpg".to_owned(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/hot_list/hot_list.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let hot_list = api.hot_list().get().await;
+        mock.assert_async().await;
+
+        assert!(hot_list.is_ok(), "error returned when okay expected");
+    }
+
+    #[tokio::test]
+    async fn get_by_type() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/hot")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "type".to_owned(),
+                "boardgameperson".to_owned(),
+            )]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/hot_list/hot_list_people.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let hot_list = api.hot_list().get_by_type(HotListType::Person).await;
+        mock.assert_async().await;
+
+        assert!(hot_list.is_ok(), "error returned when okay expected");
+        let hot_list = hot_list.unwrap();
+
+        assert_eq!(hot_list.len(), 3);
+        assert_eq!(
+            hot_list[2],
+            HotListItem::Person(HotListPerson {
+                id: PersonId(145_639),
+                rank: 3,
+                thumbnail: None,
+                name: "Mín & Elwen".to_owned(),
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn get_people_unsupported_domain() {
+        let server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::VideoGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let hot_list = api.hot_list().get_people().await;
+
+        assert!(
+            matches!(hot_list, Err(Error::UnsupportedDomain(Domain::VideoGame))),
+            "expected unsupported domain error but got {hot_list:?}",
+        );
+    }
 }
@@ -3,22 +3,71 @@ use core::fmt;
 use serde::Deserialize;
 
 use crate::deserialize::{XmlSignedValue, XmlStringValue};
+use crate::{Domain, GameId, PersonId, PublisherId};
 
-/// The returned struct containing a list of hot board games.
+/// The returned struct containing a list of hot items, either board games, people or companies.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
-pub(crate) struct HotList {
-    /// The list of trending board games currently on the hot list.
-    #[serde(default, rename = "item")]
-    pub(crate) games: Vec<HotListGame>,
+pub(crate) struct HotList<T> {
+    /// The list of trending items currently on the hot list.
+    #[serde(default = "Vec::new", rename = "item")]
+    pub(crate) items: Vec<T>,
+}
+
+/// The kinds of item that the hot list can be requested for, within the domain of the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HotListType {
+    /// The hot list of games, which are role-playing game systems in the [`Domain::Rpg`] domain
+    /// and video games in the [`Domain::VideoGame`] domain.
+    Game,
+    /// The hot list of people, such as designers and artists. Not available in the
+    /// [`Domain::VideoGame`] domain.
+    Person,
+    /// The hot list of companies, such as publishers.
+    Company,
+}
+
+impl HotListType {
+    // The value of the type parameter for this kind of item in the given domain, if the underlying
+    // API has a hot list for it.
+    pub(crate) fn for_domain(self, domain: Domain) -> Option<&'static str> {
+        match (domain, self) {
+            (Domain::BoardGame, HotListType::Game) => Some("boardgame"),
+            (Domain::BoardGame, HotListType::Person) => Some("boardgameperson"),
+            (Domain::BoardGame, HotListType::Company) => Some("boardgamecompany"),
+            (Domain::Rpg, HotListType::Game) => Some("rpg"),
+            (Domain::Rpg, HotListType::Person) => Some("rpgperson"),
+            (Domain::Rpg, HotListType::Company) => Some("rpgcompany"),
+            (Domain::VideoGame, HotListType::Game) => Some("videogame"),
+            (Domain::VideoGame, HotListType::Person) => None,
+            (Domain::VideoGame, HotListType::Company) => Some("videogamecompany"),
+        }
+    }
+}
+
+/// An item on a hot list of any type, as returned by [`crate::HotListApi::get_by_type`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum HotListItem {
+    /// An item on the hot list of games.
+    Game(HotListGame),
+    /// An item on the hot list of people.
+    Person(HotListPerson),
+    /// An item on the hot list of companies.
+    Company(HotListCompany),
 }
 
 /// An game on the hot list, has the rank from 1 to 50 on the list,
 /// as well as some basic information about the game like the name
 /// and year published.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct HotListGame {
     /// The ID of the game.
-    pub id: u64,
+    pub id: GameId,
     /// The rank within the hot list, should be ordered from 1 to 50.
     pub rank: u64,
     /// A link to a jpg thumbnail image for the game.
@@ -114,7 +163,7 @@ impl<'de> Deserialize<'de> for HotListGame {
                 let year_published = year_published
                     .ok_or_else(|| serde::de::Error::missing_field("yearpublished"))?;
                 Ok(Self::Value {
-                    id,
+                    id: GameId(id),
                     rank,
                     thumbnail,
                     name,
@@ -126,3 +175,69 @@ impl<'de> Deserialize<'de> for HotListGame {
         deserializer.deserialize_struct("HotListGame", FIELDS, HotListGameVisitor)
     }
 }
+
+/// A person on the hot list, such as a designer or artist. Has the rank from 1 to 50 on the list,
+/// as well as the person's name.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(from = "XmlHotListItem")]
+pub struct HotListPerson {
+    /// The ID of the person.
+    pub id: PersonId,
+    /// The rank within the hot list, should be ordered from 1 to 50.
+    pub rank: u64,
+    /// A link to a thumbnail image for the person, if they have one.
+    pub thumbnail: Option<String>,
+    /// The name of the person.
+    pub name: String,
+}
+
+impl From<XmlHotListItem> for HotListPerson {
+    fn from(item: XmlHotListItem) -> Self {
+        Self {
+            id: PersonId(item.id),
+            rank: item.rank,
+            thumbnail: item.thumbnail.map(|thumbnail| thumbnail.value),
+            name: item.name.value,
+        }
+    }
+}
+
+/// A company on the hot list, such as a publisher. Has the rank from 1 to 50 on the list, as well
+/// as the company's name.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[serde(from = "XmlHotListItem")]
+pub struct HotListCompany {
+    /// The ID of the company, the same as its ID as a publisher.
+    pub id: PublisherId,
+    /// The rank within the hot list, should be ordered from 1 to 50.
+    pub rank: u64,
+    /// A link to a thumbnail image for the company, if it has one.
+    pub thumbnail: Option<String>,
+    /// The name of the company.
+    pub name: String,
+}
+
+impl From<XmlHotListItem> for HotListCompany {
+    fn from(item: XmlHotListItem) -> Self {
+        Self {
+            id: PublisherId(item.id),
+            rank: item.rank,
+            thumbnail: item.thumbnail.map(|thumbnail| thumbnail.value),
+            name: item.name.value,
+        }
+    }
+}
+
+// People and companies on the hot list are returned in the same form, an item with just a name and
+// thumbnail.
+#[derive(Debug, Deserialize)]
+struct XmlHotListItem {
+    #[serde(rename = "@id")]
+    id: u64,
+    #[serde(rename = "@rank")]
+    rank: u64,
+    thumbnail: Option<XmlStringValue>,
+    name: XmlStringValue,
+}
@@ -8,6 +8,9 @@
 pub(crate) mod models;
 pub use models::*;
 
+pub(crate) mod raw_item_models;
+pub use raw_item_models::*;
+
 pub(crate) mod accessory_models;
 pub use accessory_models::*;
 pub(crate) mod accessory;
@@ -38,6 +41,11 @@ pub use game_models::*;
 pub(crate) mod game;
 pub use game::*;
 
+pub(crate) mod geek_list_models;
+pub use geek_list_models::*;
+pub(crate) mod geek_list;
+pub use geek_list::*;
+
 pub(crate) mod guild_models;
 pub use guild_models::*;
 pub(crate) mod guild;
@@ -53,11 +61,21 @@ pub use plays_models::*;
 pub(crate) mod plays;
 pub use plays::*;
 
+pub(crate) mod rpg_item_models;
+pub use rpg_item_models::*;
+pub(crate) mod rpg_item;
+pub use rpg_item::*;
+
 pub(crate) mod search_models;
 pub use search_models::*;
 pub(crate) mod search;
 pub use search::*;
 
+pub(crate) mod thing_models;
+pub use thing_models::*;
+pub(crate) mod thing;
+pub use thing::*;
+
 pub(crate) mod thread_models;
 pub use thread_models::*;
 pub(crate) mod thread;
@@ -67,3 +85,13 @@ pub(crate) mod user_models;
 pub use user_models::*;
 pub(crate) mod user;
 pub use user::*;
+
+pub(crate) mod v1_models;
+pub use v1_models::*;
+pub(crate) mod v1;
+pub use v1::*;
+
+pub(crate) mod video_game_models;
+pub use video_game_models::*;
+pub(crate) mod video_game;
+pub use video_game::*;
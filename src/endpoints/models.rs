@@ -4,9 +4,14 @@ use serde::Deserialize;
 
 use super::{RankValue, RatingValue};
 use crate::deserialize::{XmlFloatValue, XmlLink, XmlName, XmlSignedValue, XmlStringValue};
+use crate::{
+    AccessoryId, CategoryId, FamilyId, GameId, LanguageId, MechanicId, ObjectId, PersonId,
+    PublisherId, RankId, UserId, VersionId,
+};
 
 /// The type of the item. Either a board game, a board game expansion, or board game accessory.
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum ItemType {
     /// A board game. In many cases the underlying API will also include
@@ -49,6 +54,13 @@ pub enum ItemType {
     BoardGameVersion,
     /// A language that a game supports.
     Language,
+    /// A role-playing game system, such as an edition of Dungeons & Dragons. Groups together the
+    /// RPG items that are made for it.
+    Rpg,
+    /// An item for a role-playing game, such as a rulebook or adventure.
+    RpgItem,
+    /// A video game.
+    VideoGame,
 }
 
 impl Display for ItemType {
@@ -68,6 +80,9 @@ impl Display for ItemType {
             ItemType::BoardGameImplementation => write!(f, "boardgameimplementation"),
             ItemType::BoardGameVersion => write!(f, "boardgameversion"),
             ItemType::Language => write!(f, "language"),
+            ItemType::Rpg => write!(f, "rpg"),
+            ItemType::RpgItem => write!(f, "rpgitem"),
+            ItemType::VideoGame => write!(f, "videogame"),
         }
     }
 }
@@ -75,6 +90,7 @@ impl Display for ItemType {
 /// A subset of all item types on Boardgamegeek, that includes things that a play can be logged for.
 /// Typically a type of boardgame or an accessory.
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum ItemSubType {
     /// A board game. In many cases the underlying API will also include
@@ -112,6 +128,7 @@ impl Display for ItemSubType {
 
 /// A domain of item that can either be an item (game, expansion, accessory), or a game family.
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub enum ItemDomain {
     /// The item domain is used to refer to things like games, expansions, accessories.
     #[serde(rename = "thing")]
@@ -121,10 +138,52 @@ pub enum ItemDomain {
     Family,
 }
 
+/// The part of the site that requests are made for, either board games, role-playing games or
+/// video games.
+///
+/// Board Game Geek, RPG Geek and Video Game Geek share the same underlying API, and by default only
+/// board games are requested. Setting a different domain with
+/// [`crate::BoardGameGeekApi::with_domain`] opts in to the other sites, which changes which items
+/// the search, game family, collection, user, and hot list endpoints return.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
+pub enum Domain {
+    /// Board games, expansions and accessories from Board Game Geek.
+    #[default]
+    BoardGame,
+    /// Role-playing games from RPG Geek.
+    Rpg,
+    /// Video games from Video Game Geek.
+    VideoGame,
+}
+
+impl Display for Domain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Domain::BoardGame => write!(f, "boardgame"),
+            Domain::Rpg => write!(f, "rpg"),
+            Domain::VideoGame => write!(f, "videogame"),
+        }
+    }
+}
+
+impl Domain {
+    // The type of thing in this domain, used when searching and for collections.
+    pub(crate) fn item_type(self) -> ItemType {
+        match self {
+            Domain::BoardGame => ItemType::BoardGame,
+            Domain::Rpg => ItemType::RpgItem,
+            Domain::VideoGame => ItemType::VideoGame,
+        }
+    }
+}
+
 /// The type of an item that can be returned from the collections endpoint.
 /// Either a board game, a board game expansion, or board game accessory, a subset of
 /// [`ItemType`].
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum CollectionItemType {
     /// A board game. In many cases the underlying API will also include
@@ -135,6 +194,10 @@ pub enum CollectionItemType {
     /// An accessory for a board game. This can include things such as playmats
     /// and miniatures.
     BoardGameAccessory,
+    /// An item for a role-playing game. Only returned when the [`Domain::Rpg`] domain is used.
+    RpgItem,
+    /// A video game. Only returned when the [`Domain::VideoGame`] domain is used.
+    VideoGame,
 }
 
 impl From<CollectionItemType> for ItemType {
@@ -143,6 +206,8 @@ impl From<CollectionItemType> for ItemType {
             CollectionItemType::BoardGame => ItemType::BoardGame,
             CollectionItemType::BoardGameExpansion => ItemType::BoardGameExpansion,
             CollectionItemType::BoardGameAccessory => ItemType::BoardGameAccessory,
+            CollectionItemType::RpgItem => ItemType::RpgItem,
+            CollectionItemType::VideoGame => ItemType::VideoGame,
         }
     }
 }
@@ -159,6 +224,7 @@ impl Display for CollectionItemType {
 /// Either [`GameType::BoardGame`] for a normal board game or [`GameType::BoardGameExpansion`]
 /// for an expansion of another existing board game.
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum GameType {
     /// A board game. In many cases the underlying API will also include
@@ -186,6 +252,7 @@ impl Display for GameType {
 
 /// The type of game, board game or expansion.
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum NameType {
     /// The primary name for a game or game family.
@@ -195,13 +262,92 @@ pub enum NameType {
     Alternate,
 }
 
+/// An item linked from an RPG item or video game, with minimal information, only the name and ID.
+///
+/// What kind of item the ID refers to depends on the field it is found in, for example a designer
+/// or a platform.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct LinkedItem {
+    /// The ID of the linked item.
+    pub id: ObjectId,
+    /// The name of the linked item.
+    pub name: String,
+}
+
+/// Which way a link between two items points.
+///
+/// Links on the site are stored in one direction, such as from a game to its expansion, and are
+/// returned on both items. The item the link was made from has an outbound link, and the item it
+/// was made to has an inbound one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
+pub enum LinkDirection {
+    /// The link is from this item to the linked item.
+    Outbound,
+    /// The link is from the linked item to this item.
+    Inbound,
+}
+
+/// A link from an item to another item, such as a designer, expansion, or reimplementation, with
+/// the type of the linked item and which way the link points.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ItemLink {
+    /// The type of the link, which is also the type of the linked item.
+    pub link_type: ItemType,
+    /// The ID of the linked item.
+    pub id: ObjectId,
+    /// The name of the linked item.
+    pub name: String,
+    /// Whether the link is to or from the linked item.
+    pub direction: LinkDirection,
+}
+
+impl ItemLink {
+    /// Whether the link is from the linked item to this item.
+    pub fn is_inbound(&self) -> bool {
+        self.direction == LinkDirection::Inbound
+    }
+}
+
+impl From<&XmlLink> for ItemLink {
+    fn from(link: &XmlLink) -> Self {
+        Self {
+            link_type: link.link_type,
+            id: ObjectId(link.id),
+            name: link.value.clone(),
+            direction: if link.inbound {
+                LinkDirection::Inbound
+            } else {
+                LinkDirection::Outbound
+            },
+        }
+    }
+}
+
+// The links of a given type and direction, used for the link helpers on item details.
+pub(crate) fn links_matching(
+    links: &[ItemLink],
+    link_type: ItemType,
+    direction: Option<LinkDirection>,
+) -> Vec<&ItemLink> {
+    links
+        .iter()
+        .filter(|link| link.link_type == link_type)
+        .filter(|link| direction.is_none_or(|direction| link.direction == direction))
+        .collect()
+}
+
 /// A game with minimal information, only the name and ID.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Game {
     /// The ID of the game.
-    pub id: u64,
+    pub id: GameId,
     /// The name of the game.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
@@ -209,11 +355,12 @@ pub struct Game {
 ///
 /// More information can be retrieved from the accessory endpoint.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameAccessory {
     /// The ID of the game.
-    pub id: u64,
+    pub id: AccessoryId,
     /// The name of the game.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
@@ -221,11 +368,12 @@ pub struct GameAccessory {
 ///
 /// More information can be retrieved from the accessory endpoint.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameCategory {
-    /// The ID of the game.
-    pub id: u64,
+    /// The ID of the category.
+    pub id: CategoryId,
     /// The name of the game.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
@@ -233,11 +381,12 @@ pub struct GameCategory {
 ///
 /// More information can be retrieved from the accessory endpoint.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameMechanic {
-    /// The ID of the game.
-    pub id: u64,
+    /// The ID of the mechanic.
+    pub id: MechanicId,
     /// The name of the game.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
@@ -247,107 +396,279 @@ pub struct GameMechanic {
 /// game family endpoint. This will include a description and a list
 /// of all games that belong to this game family.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameFamilyName {
     /// The ID of the publisher.
-    pub id: u64,
+    pub id: FamilyId,
     /// The name of the publisher.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
 /// A different edition or compilation of a game.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameCompilation {
     /// The ID of the compilation.
-    pub id: u64,
+    pub id: GameId,
     /// The name of the compilation.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
 /// A different edition or compilation of a game.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameIntegration {
     /// The ID of the compilation.
-    pub id: u64,
+    pub id: GameId,
     /// The name of the compilation.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
 /// A re-implementation of a game.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameImplementation {
     /// The ID of the implementation.
-    pub id: u64,
+    pub id: GameId,
     /// The name of the implementation.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
 /// A designer of a game.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameDesigner {
     /// The ID of the designer.
-    pub id: u64,
+    pub id: PersonId,
     /// The name of the designer.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
 /// A publisher of a game.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GamePublisher {
     /// The ID of the publisher.
-    pub id: u64,
+    pub id: PublisherId,
     /// The name of the publisher.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
 /// An artist for a game.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameArtist {
     /// The ID of the artist.
-    pub id: u64,
+    pub id: PersonId,
     /// The name of the game.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
 /// A language, listed on versions of games that may support
 /// one or more languages.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Language {
     /// The ID of the language.
-    pub id: u64,
+    pub id: LanguageId,
     /// The name of the language, in English.
-    #[serde(rename = "value")]
+    #[serde(rename(deserialize = "value"))]
     pub name: String,
 }
 
-/// The dimensions of a game, in inches.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+/// The dimensions of a game box.
+///
+/// Boardgamegeek returns 0 for each measurement that has not been set. Any measurement can be
+/// missing on its own, so each one is `None` when it has not been set rather than a zero length.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Dimensions {
-    /// The width of the game, in inches.
-    pub width: f64,
-    /// The length of the game, in inches.
-    pub length: f64,
-    /// The depth of the game, in inches.
-    pub depth: f64,
+    /// The width of the game, if set.
+    pub width: Option<Length>,
+    /// The length of the game, if set.
+    pub length: Option<Length>,
+    /// The depth of the game, if set.
+    pub depth: Option<Length>,
+}
+
+impl Dimensions {
+    /// The area taken up by the game when laid flat, its width multiplied by its length.
+    ///
+    /// Returns `None` if either the width or the length has not been set.
+    pub fn footprint(&self) -> Option<Area> {
+        let (width, length) = (self.width?, self.length?);
+        Some(Area::from_square_inches(width.inches() * length.inches()))
+    }
+
+    /// The volume of the game box.
+    ///
+    /// Returns `None` if any of the measurements have not been set.
+    pub fn volume(&self) -> Option<Volume> {
+        let (width, length, depth) = (self.width?, self.length?, self.depth?);
+        Some(Volume::from_cubic_inches(
+            width.inches() * length.inches() * depth.inches(),
+        ))
+    }
+}
+
+// Exact conversion factors to metric, by definition of the international inch and pound.
+const CENTIMETRES_PER_INCH: f64 = 2.54;
+const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+
+/// A length, as returned by boardgamegeek in inches, which can be converted to metric.
+///
+/// ```
+/// use arnak::Length;
+///
+/// let length = Length::from_inches(10.0);
+/// assert!((length.centimetres() - 25.4).abs() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize), serde(transparent))]
+pub struct Length {
+    inches: f64,
+}
+
+impl Length {
+    /// Constructs a length from a number of inches.
+    pub fn from_inches(inches: f64) -> Self {
+        Self { inches }
+    }
+
+    /// Constructs a length from a number of centimetres.
+    pub fn from_centimetres(centimetres: f64) -> Self {
+        Self::from_inches(centimetres / CENTIMETRES_PER_INCH)
+    }
+
+    /// The length in inches.
+    pub fn inches(&self) -> f64 {
+        self.inches
+    }
+
+    /// The length in centimetres.
+    pub fn centimetres(&self) -> f64 {
+        self.inches * CENTIMETRES_PER_INCH
+    }
+
+    /// The length in millimetres.
+    pub fn millimetres(&self) -> f64 {
+        self.centimetres() * 10.0
+    }
+}
+
+/// An area, such as the footprint of a game box.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize), serde(transparent))]
+pub struct Area {
+    square_inches: f64,
+}
+
+impl Area {
+    /// Constructs an area from a number of square inches.
+    pub fn from_square_inches(square_inches: f64) -> Self {
+        Self { square_inches }
+    }
+
+    /// The area in square inches.
+    pub fn square_inches(&self) -> f64 {
+        self.square_inches
+    }
+
+    /// The area in square centimetres.
+    pub fn square_centimetres(&self) -> f64 {
+        self.square_inches * CENTIMETRES_PER_INCH * CENTIMETRES_PER_INCH
+    }
+}
+
+/// A volume, such as the space taken up by a game box.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize), serde(transparent))]
+pub struct Volume {
+    cubic_inches: f64,
+}
+
+impl Volume {
+    /// Constructs a volume from a number of cubic inches.
+    pub fn from_cubic_inches(cubic_inches: f64) -> Self {
+        Self { cubic_inches }
+    }
+
+    /// The volume in cubic inches.
+    pub fn cubic_inches(&self) -> f64 {
+        self.cubic_inches
+    }
+
+    /// The volume in cubic centimetres.
+    pub fn cubic_centimetres(&self) -> f64 {
+        self.cubic_inches * CENTIMETRES_PER_INCH * CENTIMETRES_PER_INCH * CENTIMETRES_PER_INCH
+    }
+
+    /// The volume in litres.
+    pub fn litres(&self) -> f64 {
+        self.cubic_centimetres() / 1000.0
+    }
+}
+
+/// A mass, as returned by boardgamegeek in pounds, which can be converted to metric.
+///
+/// ```
+/// use arnak::Mass;
+///
+/// let mass = Mass::from_pounds(2.0);
+/// assert!((mass.kilograms() - 0.907_184_74).abs() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize), serde(transparent))]
+pub struct Mass {
+    pounds: f64,
+}
+
+impl Mass {
+    /// Constructs a mass from a number of pounds.
+    pub fn from_pounds(pounds: f64) -> Self {
+        Self { pounds }
+    }
+
+    /// Constructs a mass from a number of kilograms.
+    pub fn from_kilograms(kilograms: f64) -> Self {
+        Self::from_pounds(kilograms / KILOGRAMS_PER_POUND)
+    }
+
+    /// The mass in pounds.
+    pub fn pounds(&self) -> f64 {
+        self.pounds
+    }
+
+    /// The mass in kilograms.
+    pub fn kilograms(&self) -> f64 {
+        self.pounds * KILOGRAMS_PER_POUND
+    }
+
+    /// The mass in grams.
+    pub fn grams(&self) -> f64 {
+        self.kilograms() * 1000.0
+    }
 }
 
 /// A struct containing the game's rank within a particular type of game.
 #[derive(Clone, Debug, PartialEq, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ItemFamilyRank {
-    /// ID of the game family.
-    pub id: u64,
+    /// ID of the ranking.
+    pub id: RankId,
     /// Name of the game type. "boardgame" used as the generic subtype that
     /// includes all board games.
     pub name: String,
     /// User friendly name in the format "GENRE game rank" e.g. "Party Game
     /// Rank".
-    #[serde(rename = "friendlyname")]
+    #[serde(rename(deserialize = "friendlyname"))]
     pub friendly_name: String,
     /// The overall rank on the site within this type of game.
     pub value: RankValue,
@@ -356,7 +677,7 @@ pub struct ItemFamilyRank {
     /// This is what boardgamegeek calls a Geek Rating. It is the average rating
     /// that the users have given it along with a few thousand 5.5 ratings added
     /// in too.
-    #[serde(rename = "bayesaverage")]
+    #[serde(rename(deserialize = "bayesaverage"))]
     pub bayesian_average: RatingValue,
 }
 
@@ -373,9 +694,10 @@ pub(crate) struct XmlGameVersions {
 ///
 /// Often this may be a translated version of a game. It is not the same as an expansion for a game.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GameVersion {
-    /// The ID of this game.
-    pub id: u64,
+    /// The ID of this version.
+    pub id: VersionId,
     /// The name of the game.
     pub name: String,
     /// A list of alternate names for the game.
@@ -394,10 +716,16 @@ pub struct GameVersion {
     pub artists: Vec<GameArtist>,
     /// Lists of languages that this version of the game supports.
     pub languages: Vec<Language>,
-    /// The dimensions of the game, in inches, if included.
+    /// The dimensions of the game, if any of them are set.
+    ///
+    /// This is `None` when none of the measurements have been set. If only some have been set, the
+    /// others are `None` within the [`Dimensions`].
     pub dimensions: Option<Dimensions>,
-    /// The weight of the game, in pounds, if included.
-    pub weight: Option<f64>,
+    /// The weight of the game, if set.
+    ///
+    /// Boardgamegeek returns 0 when the weight has not been set, which is returned here as `None`
+    /// rather than as a zero mass.
+    pub weight: Option<Mass>,
     /// Product code for the game, if included.
     pub product_code: Option<String>,
 }
@@ -500,25 +828,25 @@ impl<'de> Deserialize<'de> for GameVersion {
                                         ));
                                     }
                                     original_game = Some(Game {
-                                        id: link.id,
+                                        id: GameId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 ItemType::BoardGamePublisher => {
                                     publishers.push(GamePublisher {
-                                        id: link.id,
+                                        id: PublisherId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 ItemType::BoardGameArtist => {
                                     artists.push(GameArtist {
-                                        id: link.id,
+                                        id: PersonId(link.id),
                                         name: link.value,
                                     });
                                 },
                                 ItemType::Language => {
                                     languages.push(Language {
-                                        id: link.id,
+                                        id: LanguageId(link.id),
                                         name: link.value,
                                     });
                                 },
@@ -537,7 +865,7 @@ impl<'de> Deserialize<'de> for GameVersion {
                             if width_xml.value == 0.0 {
                                 width = Some(None);
                             } else {
-                                width = Some(Some(width_xml.value));
+                                width = Some(Some(Length::from_inches(width_xml.value)));
                             }
                         },
                         Field::Depth => {
@@ -548,7 +876,7 @@ impl<'de> Deserialize<'de> for GameVersion {
                             if depth_xml.value == 0.0 {
                                 depth = Some(None);
                             } else {
-                                depth = Some(Some(depth_xml.value));
+                                depth = Some(Some(Length::from_inches(depth_xml.value)));
                             }
                         },
                         Field::Length => {
@@ -559,7 +887,7 @@ impl<'de> Deserialize<'de> for GameVersion {
                             if length_xml.value == 0.0 {
                                 length = Some(None);
                             } else {
-                                length = Some(Some(length_xml.value));
+                                length = Some(Some(Length::from_inches(length_xml.value)));
                             }
                         },
                         Field::Weight => {
@@ -570,7 +898,7 @@ impl<'de> Deserialize<'de> for GameVersion {
                             if weight_xml.value == 0.0 {
                                 weight = Some(None);
                             } else {
-                                weight = Some(Some(weight_xml.value));
+                                weight = Some(Some(Mass::from_pounds(weight_xml.value)));
                             }
                         },
                         Field::ProductCode => {
@@ -616,18 +944,15 @@ impl<'de> Deserialize<'de> for GameVersion {
                 let product_code =
                     product_code.ok_or_else(|| serde::de::Error::missing_field("productcode"))?;
 
-                let dimensions;
-                if let (Some(width), Some(length), Some(depth)) = (width, length, depth) {
-                    dimensions = Some(Dimensions {
+                let dimensions = if width.is_none() && length.is_none() && depth.is_none() {
+                    None
+                } else {
+                    Some(Dimensions {
                         width,
                         length,
                         depth,
-                    });
-                } else if let (None, None, None) = (width, depth, length) {
-                    dimensions = None;
-                } else {
-                    return Err(serde::de::Error::custom("Invalid game dimensions, some but not all of width, length, depth, were set."));
-                }
+                    })
+                };
 
                 Ok(Self::Value {
                     id,
@@ -666,9 +991,90 @@ impl<'de> Deserialize<'de> for GameVersion {
 
 /// A user's username and ID.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct UserBrief {
     /// ID for the user.
-    pub user_id: u64,
+    pub user_id: UserId,
     /// Username, used to request collection information.
     pub username: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected} but got {actual}",
+        );
+    }
+
+    #[test]
+    fn length_conversions() {
+        let length = Length::from_inches(1.0);
+        assert_close(length.inches(), 1.0);
+        assert_close(length.centimetres(), 2.54);
+        assert_close(length.millimetres(), 25.4);
+
+        let length = Length::from_centimetres(12.7);
+        assert_close(length.inches(), 5.0);
+        assert_close(length.centimetres(), 12.7);
+    }
+
+    #[test]
+    fn mass_conversions() {
+        let mass = Mass::from_pounds(1.0);
+        assert_close(mass.pounds(), 1.0);
+        assert_close(mass.kilograms(), 0.453_592_37);
+        assert_close(mass.grams(), 453.592_37);
+
+        let mass = Mass::from_kilograms(0.907_184_74);
+        assert_close(mass.pounds(), 2.0);
+    }
+
+    #[test]
+    fn area_conversions() {
+        let area = Area::from_square_inches(2.0);
+        assert_close(area.square_inches(), 2.0);
+        assert_close(area.square_centimetres(), 12.903_2);
+    }
+
+    #[test]
+    fn volume_conversions() {
+        let volume = Volume::from_cubic_inches(10.0);
+        assert_close(volume.cubic_inches(), 10.0);
+        assert_close(volume.cubic_centimetres(), 163.870_64);
+        assert_close(volume.litres(), 0.163_870_64);
+    }
+
+    #[test]
+    fn dimensions_footprint_and_volume() {
+        let dimensions = Dimensions {
+            width: Some(Length::from_inches(2.0)),
+            length: Some(Length::from_inches(3.0)),
+            depth: Some(Length::from_inches(4.0)),
+        };
+        assert_close(dimensions.footprint().unwrap().square_inches(), 6.0);
+        assert_close(dimensions.volume().unwrap().cubic_inches(), 24.0);
+    }
+
+    #[test]
+    fn dimensions_missing_measurements() {
+        let dimensions = Dimensions {
+            width: Some(Length::from_inches(2.0)),
+            length: Some(Length::from_inches(3.0)),
+            depth: None,
+        };
+        assert_close(dimensions.footprint().unwrap().square_inches(), 6.0);
+        assert_eq!(dimensions.volume(), None);
+
+        let dimensions = Dimensions {
+            width: None,
+            length: Some(Length::from_inches(3.0)),
+            depth: Some(Length::from_inches(4.0)),
+        };
+        assert_eq!(dimensions.footprint(), None);
+        assert_eq!(dimensions.volume(), None);
+    }
+}
@@ -1,22 +1,76 @@
 use chrono::NaiveDate;
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize};
 
-use crate::{BoardGameGeekApi, IntoQueryParam, ItemDomain, ItemSubType, Plays, QueryParam, Result};
+use crate::api::parse_site_response;
+use crate::stream::forward_items;
+use crate::{
+    BoardGameGeekApi, Error, FamilyId, IntoQueryParam, ItemDomain, ItemStream, ItemSubType,
+    ObjectId, Play, PlayId, PlayLog, Player, Plays, QueryParam, Result, Session,
+};
+
+// The number of plays returned in each page by the underlying API. Only used to count the plays on
+// the pages before the first one requested, the total in each response is used to find the end.
+const PLAYS_PAGE_SIZE: u64 = 100;
+
+// The page of the site that plays are logged, edited and deleted with. This isn't part of the XML
+// API, it is what the site itself uses, and takes and returns JSON.
+const LOG_PLAY_PATH: &str = "geekplay.php";
 
 /// All optional query parameters for making a request to the plays endpoint.
+///
+/// Plays can be filtered by a user, by an item or game family, or by both a user and an item or
+/// family to get one user's plays of one game. The methods of [`PlaysApi`] set the user, item or
+/// family that they are for, replacing any set here.
 #[derive(Clone, Debug, Default)]
 pub struct PlaysQueryParams {
+    username: Option<String>,
+    item: Option<PlaysItem>,
     min_date: Option<NaiveDate>,
     max_date: Option<NaiveDate>,
     sub_type: Option<ItemSubType>,
     page: Option<u64>,
 }
 
+// The item or game family that plays are requested for.
+#[derive(Clone, Copy, Debug)]
+struct PlaysItem {
+    id: u64,
+    plays_item_domain: ItemDomain,
+}
+
 impl PlaysQueryParams {
     /// Constructs a new plays query with parameters set to None.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Sets the `username` parameter. Will only return plays logged by this user.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Sets the `id` parameter to an item. Will only return plays of this item. Replaces any game
+    /// family set with [`PlaysQueryParams::family_id`].
+    pub fn item_id(mut self, item_id: impl Into<ObjectId>) -> Self {
+        self.item = Some(PlaysItem {
+            id: item_id.into().0,
+            plays_item_domain: ItemDomain::Item,
+        });
+        self
+    }
+
+    /// Sets the `id` parameter to a game family. Will only return plays of items in this family.
+    /// Replaces any item set with [`PlaysQueryParams::item_id`].
+    pub fn family_id(mut self, family_id: FamilyId) -> Self {
+        self.item = Some(PlaysItem {
+            id: family_id.0,
+            plays_item_domain: ItemDomain::Family,
+        });
+        self
+    }
+
     /// Sets the `min_date` parameter. Will only return plays from after this date.
     pub fn min_date(mut self, min_date: NaiveDate) -> Self {
         self.min_date = Some(min_date);
@@ -29,56 +83,47 @@ impl PlaysQueryParams {
         self
     }
 
+    /// Sets both the `min_date` and `max_date` parameters. Will only return plays from within this
+    /// range of dates.
+    pub fn date_range(self, min_date: NaiveDate, max_date: NaiveDate) -> Self {
+        self.min_date(min_date).max_date(max_date)
+    }
+
     /// Sets the `sub_type` parameter. Will only return items of the specified type.
     pub fn sub_type(mut self, sub_type: ItemSubType) -> Self {
         self.sub_type = Some(sub_type);
         self
     }
 
-    /// The page of results to return, if unset defaults to the first page.
+    /// The page of results to return, if unset defaults to the first page. Pages start from 1, so
+    /// page 0 is the same as the first page.
     pub fn page(mut self, page: u64) -> Self {
         self.page = Some(page);
         self
     }
 }
 
-#[derive(Clone, Debug)]
-enum PlaysQuery<'q> {
-    QueryByUser(&'q str),
-    QueryById {
-        id: u64,
-        plays_item_domain: ItemDomain,
-    },
-}
-
 #[derive(Clone, Debug)]
 struct PlaysQueryBuilder<'builder> {
-    query: PlaysQuery<'builder>,
     params: &'builder PlaysQueryParams,
 }
 
 impl<'builder> PlaysQueryBuilder<'builder> {
-    fn new(query: PlaysQuery<'builder>, params: &'builder PlaysQueryParams) -> Self {
-        Self { query, params }
+    fn new(params: &'builder PlaysQueryParams) -> Self {
+        Self { params }
     }
 
     fn build(self) -> Vec<QueryParam<'builder>> {
         let mut query_params = vec![];
-        // The endpoint requires either a username param, or both an ID and a type, for it to return
-        // anything.
-        match self.query {
-            PlaysQuery::QueryByUser(username) => {
-                query_params.push(username.into_query_param("username"));
-            },
-            PlaysQuery::QueryById {
-                id,
-                plays_item_domain,
-            } => {
-                query_params.push(id.into_query_param("id"));
-                query_params.push(plays_item_domain.into_query_param("type"));
-            },
+        // The endpoint requires either a username param, or both an ID and a type, or all three,
+        // for it to return anything.
+        if let Some(username) = &self.params.username {
+            query_params.push(username.as_str().into_query_param("username"));
+        }
+        if let Some(item) = self.params.item {
+            query_params.push(item.id.into_query_param("id"));
+            query_params.push(item.plays_item_domain.into_query_param("type"));
         }
-
         if let Some(min_date) = self.params.min_date {
             query_params.push(min_date.into_query_param("mindate"));
         }
@@ -95,9 +140,115 @@ impl<'builder> PlaysQueryBuilder<'builder> {
     }
 }
 
+// The body of a request to log a new play, or to replace the details of an existing one.
+#[derive(Serialize)]
+struct SavePlayRequest<'a> {
+    action: &'static str,
+    ajax: u8,
+    #[serde(rename = "objecttype")]
+    object_type: &'static str,
+    #[serde(rename = "objectid")]
+    object_id: u64,
+    #[serde(rename = "playid", skip_serializing_if = "Option::is_none")]
+    play_id: Option<u64>,
+    #[serde(rename = "playdate")]
+    date: NaiveDate,
+    quantity: u64,
+    #[serde(rename = "length")]
+    duration_minutes: i64,
+    location: &'a str,
+    incomplete: u8,
+    #[serde(rename = "nowinstats")]
+    do_not_count_win_stats: u8,
+    comments: &'a str,
+    players: Vec<SavePlayPlayer<'a>>,
+}
+
+impl<'a> SavePlayRequest<'a> {
+    fn new(play_id: Option<PlayId>, play: &'a PlayLog) -> Self {
+        Self {
+            action: "save",
+            ajax: 1,
+            object_type: "thing",
+            object_id: play.item_id.0,
+            play_id: play_id.map(u64::from),
+            date: play.date,
+            quantity: play.quantity,
+            duration_minutes: play.duration.num_minutes(),
+            location: &play.location,
+            incomplete: play.incomplete.into(),
+            do_not_count_win_stats: play.do_not_count_win_stats.into(),
+            comments: &play.comments,
+            players: play.players.iter().map(SavePlayPlayer::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SavePlayPlayer<'a> {
+    username: &'a str,
+    #[serde(rename = "userid")]
+    user_id: u64,
+    name: &'a str,
+    #[serde(rename = "position")]
+    start_position: &'a str,
+    color: &'a str,
+    score: &'a str,
+    // A rating of 0 means the player didn't rate the game, the same as when plays are returned.
+    rating: u64,
+    #[serde(rename = "new")]
+    first_time_playing: u8,
+    #[serde(rename = "win")]
+    won: u8,
+}
+
+impl<'a> From<&'a Player> for SavePlayPlayer<'a> {
+    fn from(player: &'a Player) -> Self {
+        Self {
+            username: player.username.as_deref().unwrap_or_default(),
+            user_id: player.user_id.map_or(0, u64::from),
+            name: &player.name,
+            start_position: &player.start_position,
+            color: &player.color,
+            score: &player.score,
+            rating: player.rating.unwrap_or(0),
+            first_time_playing: player.first_time_playing.into(),
+            won: player.won.into(),
+        }
+    }
+}
+
+// The body of a request to delete a play.
+#[derive(Serialize)]
+struct DeletePlayRequest {
+    action: &'static str,
+    ajax: u8,
+    finalize: u8,
+    #[serde(rename = "playid")]
+    play_id: u64,
+}
+
+// The response to saving a play, or deleting one, in which case there is no play ID.
+#[derive(Deserialize)]
+struct SavePlayResponse {
+    // The ID is returned as a string when a play is saved.
+    #[serde(default, rename = "playid")]
+    play_id: Option<serde_json::Value>,
+}
+
+impl SavePlayResponse {
+    fn play_id(&self) -> Option<PlayId> {
+        match self.play_id.as_ref()? {
+            serde_json::Value::String(play_id) => play_id.parse().ok(),
+            serde_json::Value::Number(play_id) => play_id.as_u64().map(PlayId),
+            _ => None,
+        }
+    }
+}
+
 /// Plays endpoint of the API. Used for returning information about recordings of instances of games
-/// being played. Plays can be queried either by user or by item ID, either way they are returned in
-/// reverse chronological order.
+/// being played. Plays can be queried by user, by item ID, or by both, either way they are returned
+/// in reverse chronological order.
 pub struct PlaysApi<'api> {
     pub(crate) api: &'api BoardGameGeekApi,
     endpoint: &'static str,
@@ -117,62 +268,222 @@ impl<'api> PlaysApi<'api> {
         username: &str,
         query_params: &PlaysQueryParams,
     ) -> Result<Plays> {
-        let query = PlaysQueryBuilder::new(PlaysQuery::QueryByUser(username), query_params);
-
-        let request = self.api.build_request(self.endpoint, &query.build());
-        let response = self.api.execute_request::<Plays>(request).await?;
-
-        Ok(response)
+        self.get(&query_params.clone().username(username)).await
     }
 
     /// Get a list of recorded game plays for a specific item that can be played.
     pub async fn get_by_item_id(
         &self,
-        item_id: u64,
+        item_id: impl Into<ObjectId>,
         query_params: &PlaysQueryParams,
     ) -> Result<Plays> {
-        let query = PlaysQueryBuilder::new(
-            PlaysQuery::QueryById {
-                id: item_id,
-                plays_item_domain: ItemDomain::Item,
-            },
-            query_params,
-        );
-
-        let request = self.api.build_request(self.endpoint, &query.build());
-        let response = self.api.execute_request::<Plays>(request).await?;
-
-        Ok(response)
+        self.get(&query_params.clone().item_id(item_id)).await
     }
 
     /// Get a list of recorded game plays for a specific game family.
     pub async fn get_by_family_id(
         &self,
-        family_id: u64,
+        family_id: FamilyId,
         query_params: &PlaysQueryParams,
     ) -> Result<Plays> {
-        let query = PlaysQueryBuilder::new(
-            PlaysQuery::QueryById {
-                id: family_id,
-                plays_item_domain: ItemDomain::Family,
-            },
-            query_params,
-        );
+        self.get(&query_params.clone().family_id(family_id)).await
+    }
+
+    /// Get a list of the recorded game plays of a specific user, for a specific item that can be
+    /// played.
+    pub async fn get_by_username_and_item(
+        &self,
+        username: &str,
+        item_id: impl Into<ObjectId>,
+        query_params: &PlaysQueryParams,
+    ) -> Result<Plays> {
+        let query_params = query_params.clone().username(username).item_id(item_id);
+        self.get(&query_params).await
+    }
+
+    /// Get a stream of all recorded game plays for a specific user.
+    ///
+    /// Unlike [`PlaysApi::get_by_username`], which returns a single page, this continues through
+    /// every page of plays, starting from the page in the query params if one is set. Plays are
+    /// parsed one at a time while each page is being read.
+    pub async fn stream_by_username(
+        &self,
+        username: &str,
+        query_params: &PlaysQueryParams,
+    ) -> Result<ItemStream<Play>> {
+        self.stream(&query_params.clone().username(username)).await
+    }
+
+    /// Get a stream of all recorded game plays for a specific item that can be played.
+    ///
+    /// Unlike [`PlaysApi::get_by_item_id`], which returns a single page, this continues through
+    /// every page of plays, starting from the page in the query params if one is set. Plays are
+    /// parsed one at a time while each page is being read.
+    pub async fn stream_by_item_id(
+        &self,
+        item_id: impl Into<ObjectId>,
+        query_params: &PlaysQueryParams,
+    ) -> Result<ItemStream<Play>> {
+        self.stream(&query_params.clone().item_id(item_id)).await
+    }
+
+    /// Get a stream of all recorded game plays for a specific game family.
+    ///
+    /// Unlike [`PlaysApi::get_by_family_id`], which returns a single page, this continues through
+    /// every page of plays, starting from the page in the query params if one is set. Plays are
+    /// parsed one at a time while each page is being read.
+    pub async fn stream_by_family_id(
+        &self,
+        family_id: FamilyId,
+        query_params: &PlaysQueryParams,
+    ) -> Result<ItemStream<Play>> {
+        self.stream(&query_params.clone().family_id(family_id))
+            .await
+    }
+
+    /// Get a stream of all the recorded game plays of a specific user, for a specific item that
+    /// can be played.
+    ///
+    /// Unlike [`PlaysApi::get_by_username_and_item`], which returns a single page, this continues
+    /// through every page of plays, starting from the page in the query params if one is set.
+    /// Plays are parsed one at a time while each page is being read.
+    pub async fn stream_by_username_and_item(
+        &self,
+        username: &str,
+        item_id: impl Into<ObjectId>,
+        query_params: &PlaysQueryParams,
+    ) -> Result<ItemStream<Play>> {
+        let query_params = query_params.clone().username(username).item_id(item_id);
+        self.stream(&query_params).await
+    }
+
+    /// Logs a new play as the user of the session, returning the ID of the recorded play.
+    ///
+    /// Unlike requests for plays this is not part of the XML API, so it needs a logged in
+    /// [`Session`] from [`BoardGameGeekApi::log_in`].
+    pub async fn log_play(&self, session: &Session, play: &PlayLog) -> Result<PlayId> {
+        self.save_play(session, None, play).await
+    }
+
+    /// Replaces all the details of an existing play logged by the user of the session.
+    ///
+    /// To change only some details, convert the existing [`Play`] into a [`PlayLog`] and update
+    /// that.
+    pub async fn edit_play(
+        &self,
+        session: &Session,
+        play_id: PlayId,
+        play: &PlayLog,
+    ) -> Result<()> {
+        self.save_play(session, Some(play_id), play).await?;
+        Ok(())
+    }
+
+    /// Deletes a play logged by the user of the session.
+    pub async fn delete_play(&self, session: &Session, play_id: PlayId) -> Result<()> {
+        let request = self
+            .api
+            .build_site_post_request(LOG_PLAY_PATH, session)?
+            .json(&DeletePlayRequest {
+                action: "delete",
+                ajax: 1,
+                finalize: 1,
+                play_id: play_id.0,
+            });
+        let response_text = self.api.execute_site_request(request).await?;
+
+        parse_site_response::<SavePlayResponse>(&response_text)?;
+        Ok(())
+    }
+
+    async fn save_play(
+        &self,
+        session: &Session,
+        play_id: Option<PlayId>,
+        play: &PlayLog,
+    ) -> Result<PlayId> {
+        let request = self
+            .api
+            .build_site_post_request(LOG_PLAY_PATH, session)?
+            .json(&SavePlayRequest::new(play_id, play));
+        let response_text = self.api.execute_site_request(request).await?;
+
+        parse_site_response::<SavePlayResponse>(&response_text)?
+            .play_id()
+            .ok_or_else(|| {
+                Error::UnexpectedResponseError("no play ID returned for saved play".to_owned())
+            })
+    }
+
+    async fn get(&self, query_params: &PlaysQueryParams) -> Result<Plays> {
+        let query = PlaysQueryBuilder::new(query_params);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let response = self.api.execute_request::<Plays>(request).await?;
 
         Ok(response)
     }
+
+    async fn stream(&self, query_params: &PlaysQueryParams) -> Result<ItemStream<Play>> {
+        // Pages start from 1, and the underlying API treats page 0 the same as page 1.
+        let mut page = query_params.page.unwrap_or(1).max(1);
+        // The page is added to a copy of the request for each page requested.
+        let query_params = PlaysQueryParams {
+            page: None,
+            ..query_params.clone()
+        };
+        let query = PlaysQueryBuilder::new(&query_params);
+        let request = self.api.build_request(self.endpoint, &query.build());
+
+        let mut response = self.api.send_request(page_request(&request, page)).await?;
+
+        let api = self.api.clone();
+        let (sender, stream) = ItemStream::channel();
+        tokio::spawn(async move {
+            // The number of plays up to the end of the current page, including any pages skipped.
+            let mut play_count = (page - 1) * PLAYS_PAGE_SIZE;
+            while let Some(forwarded) = forward_items(response, "plays", "play", &sender).await {
+                play_count += forwarded.count as u64;
+                let total = forwarded
+                    .root_attribute("total")
+                    .and_then(|total| total.parse::<u64>().ok());
+                // An empty page also ends the stream, in case the total is missing or is more
+                // than the number of plays that can actually be returned.
+                if forwarded.count == 0 || total.is_none_or(|total| play_count >= total) {
+                    break;
+                }
+                page += 1;
+                response = match api.send_request(page_request(&request, page)).await {
+                    Ok(response) => response,
+                    Err(e) => {
+                        let _ = sender.send(Err(e)).await;
+                        break;
+                    },
+                };
+            }
+        });
+        Ok(stream)
+    }
+}
+
+fn page_request(request: &RequestBuilder, page: u64) -> RequestBuilder {
+    request
+        .try_clone()
+        .expect("Couldn't clone request")
+        .query(&[page.into_query_param("page")])
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::Duration;
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
     use mockito::Matcher;
 
     use super::*;
-    use crate::{Play, PlayedItem, Player};
+    use crate::{
+        Decimal, Domain, GameId, Play, PlayId, PlayedItem, Player, StaticTokenProvider, UserId,
+    };
 
     #[tokio::test]
     async fn get_by_username() {
@@ -180,6 +491,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -209,12 +522,12 @@ mod tests {
             plays,
             Plays {
                 username: "bluebearbgg".to_owned(),
-                user_id: 3_855_477,
+                user_id: Some(UserId(3_855_477)),
                 total: 3,
                 page: 1,
                 plays: vec![
                     Play {
-                        id: 113_391_260,
+                        id: PlayId(113_391_260),
                         date: NaiveDate::from_ymd_opt(2026, 4, 30).unwrap(),
                         quantity: 1,
                         duration: Duration::minutes(60),
@@ -224,7 +537,7 @@ mod tests {
                         comments: Some("blah".to_owned()),
                         played_item: PlayedItem {
                             name: "Lost Ruins of Arnak: The Missing Expedition".to_owned(),
-                            id: 382_350,
+                            id: ObjectId(382_350),
                             sub_types: vec![
                                 ItemSubType::BoardGame,
                                 ItemSubType::BoardGameExpansion
@@ -233,7 +546,7 @@ mod tests {
                         players: vec![],
                     },
                     Play {
-                        id: 112_947_972,
+                        id: PlayId(112_947_972),
                         date: NaiveDate::from_ymd_opt(2026, 4, 18).unwrap(),
                         quantity: 2,
                         duration: Duration::minutes(1310),
@@ -243,23 +556,23 @@ mod tests {
                         comments: None,
                         played_item: PlayedItem {
                             name: "Lost Ruins of Arnak".to_owned(),
-                            id: 312_484,
+                            id: ObjectId(312_484),
                             sub_types: vec![ItemSubType::BoardGame],
                         },
                         players: vec![Player {
                             username: Some("BluebearBGG".to_owned()),
-                            user_id: Some(3_855_477),
+                            user_id: Some(UserId(3_855_477)),
                             name: "Matthew Thompson".to_owned(),
                             start_position: "1".to_owned(),
                             color: "blue".to_owned(),
                             score: "999".to_owned(),
                             first_time_playing: true,
-                            rating: 0,
+                            rating: None,
                             won: true,
                         },],
                     },
                     Play {
-                        id: 83_820_037,
+                        id: PlayId(83_820_037),
                         date: NaiveDate::from_ymd_opt(2024, 4, 13).unwrap(),
                         quantity: 1,
                         duration: Duration::minutes(120),
@@ -271,18 +584,18 @@ mod tests {
                         ),
                         played_item: PlayedItem {
                             name: "Lost Ruins of Arnak".to_owned(),
-                            id: 312_484,
+                            id: ObjectId(312_484),
                             sub_types: vec![ItemSubType::BoardGame],
                         },
                         players: vec![Player {
                             username: Some("BluebearBGG".to_owned()),
-                            user_id: Some(3_855_477),
+                            user_id: Some(UserId(3_855_477)),
                             name: "Matthew".to_owned(),
                             start_position: "".to_owned(),
                             color: "".to_owned(),
                             score: "".to_owned(),
                             first_time_playing: false,
-                            rating: 0,
+                            rating: None,
                             won: false,
                         },],
                     },
@@ -291,12 +604,176 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn stream_by_username() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let last_page = std::fs::read_to_string("test_data/plays/user_plays.xml")
+            .expect("failed to load test data");
+        // Build a full first page from copies of the first play in the test data.
+        let play_start = last_page.find("<play ").unwrap();
+        let play_end = last_page.find("</play>").unwrap() + "</play>".len();
+        let first_page = format!(
+            r#"<plays username="bluebearbgg" userid="3855477" total="103" page="1">{}</plays>"#,
+            last_page[play_start..play_end].repeat(100),
+        );
+
+        let first_page_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "BluebearBgg".to_owned()),
+                Matcher::UrlEncoded("page".to_owned(), "1".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(first_page)
+            .create_async()
+            .await;
+        let last_page_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "BluebearBgg".to_owned()),
+                Matcher::UrlEncoded("page".to_owned(), "2".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(last_page)
+            .create_async()
+            .await;
+
+        let stream = api
+            .plays()
+            .stream_by_username("BluebearBgg", &PlaysQueryParams::new())
+            .await;
+
+        assert!(stream.is_ok(), "error returned when okay expected");
+        let mut stream = stream.unwrap();
+
+        let mut plays = vec![];
+        while let Some(play) = stream.next().await {
+            assert!(play.is_ok(), "error returned when okay expected");
+            plays.push(play.unwrap());
+        }
+        first_page_mock.assert_async().await;
+        last_page_mock.assert_async().await;
+
+        assert_eq!(plays.len(), 103);
+        assert!(plays[..100]
+            .iter()
+            .all(|play| play.id == PlayId(113_391_260)));
+        assert_eq!(plays[100].id, PlayId(113_391_260));
+        assert_eq!(plays[102].id, PlayId(83_820_037));
+    }
+
+    #[tokio::test]
+    async fn stream_by_username_stops_at_total() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let plays_xml = std::fs::read_to_string("test_data/plays/user_plays.xml")
+            .expect("failed to load test data");
+        // A full page which holds every play, so there is no need to request the next page.
+        let play_start = plays_xml.find("<play ").unwrap();
+        let play_end = plays_xml.find("</play>").unwrap() + "</play>".len();
+        let only_page = format!(
+            r#"<plays username="bluebearbgg" userid="3855477" total="100" page="1">{}</plays>"#,
+            plays_xml[play_start..play_end].repeat(100),
+        );
+
+        let only_page_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::UrlEncoded("page".to_owned(), "1".to_owned()))
+            .with_status(200)
+            .with_body(only_page)
+            .create_async()
+            .await;
+        let next_page_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::UrlEncoded("page".to_owned(), "2".to_owned()))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let stream = api
+            .plays()
+            .stream_by_username("BluebearBgg", &PlaysQueryParams::new())
+            .await;
+
+        assert!(stream.is_ok(), "error returned when okay expected");
+        let mut stream = stream.unwrap();
+
+        let mut play_count = 0;
+        while let Some(play) = stream.next().await {
+            assert!(play.is_ok(), "error returned when okay expected");
+            play_count += 1;
+        }
+        only_page_mock.assert_async().await;
+        next_page_mock.assert_async().await;
+        assert_eq!(play_count, 100);
+    }
+
+    #[tokio::test]
+    async fn stream_by_username_page_zero() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let first_page_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::UrlEncoded("page".to_owned(), "1".to_owned()))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/user_plays.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+        let zero_page_mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::UrlEncoded("page".to_owned(), "0".to_owned()))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let stream = api
+            .plays()
+            .stream_by_username("BluebearBgg", &PlaysQueryParams::new().page(0))
+            .await;
+
+        assert!(stream.is_ok(), "error returned when okay expected");
+        let mut stream = stream.unwrap();
+
+        let mut play_count = 0;
+        while let Some(play) = stream.next().await {
+            assert!(play.is_ok(), "error returned when okay expected");
+            play_count += 1;
+        }
+        first_page_mock.assert_async().await;
+        zero_page_mock.assert_async().await;
+        assert_eq!(play_count, 3);
+    }
+
     #[tokio::test]
     async fn get_by_item_id() {
         let mut server = mockito::Server::new_async().await;
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -322,7 +799,7 @@ mod tests {
             .max_date(NaiveDate::from_ymd_opt(2026, 6, 2).unwrap())
             .sub_type(ItemSubType::BoardGameExpansion)
             .page(1);
-        let plays = api.plays().get_by_item_id(382_350, &params).await;
+        let plays = api.plays().get_by_item_id(GameId(382_350), &params).await;
         mock.assert_async().await;
 
         assert!(plays.is_ok(), "error returned when okay expected");
@@ -332,11 +809,81 @@ mod tests {
             plays,
             Plays {
                 username: "".to_owned(),
-                user_id: 0,
+                user_id: None,
+                total: 1,
+                page: 1,
+                plays: vec![Play {
+                    id: PlayId(113_391_260),
+                    date: NaiveDate::from_ymd_opt(2026, 4, 30).unwrap(),
+                    quantity: 1,
+                    duration: Duration::minutes(60),
+                    incomplete: false,
+                    location: "kitchen".to_owned(),
+                    do_not_count_win_stats: false,
+                    comments: Some("blah".to_owned()),
+                    played_item: PlayedItem {
+                        name: "Lost Ruins of Arnak: The Missing Expedition".to_owned(),
+                        id: ObjectId(382_350),
+                        sub_types: vec![ItemSubType::BoardGame, ItemSubType::BoardGameExpansion],
+                    },
+                    players: vec![],
+                },],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_username_and_item() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("username".to_owned(), "BluebearBgg".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "382350".to_owned()),
+                Matcher::UrlEncoded("type".to_owned(), "thing".to_owned()),
+                Matcher::UrlEncoded("mindate".to_owned(), "2026-01-01".to_owned()),
+                Matcher::UrlEncoded("maxdate".to_owned(), "2026-06-02".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/user_thing_plays.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        // The family set in the params is replaced by the item.
+        let params = PlaysQueryParams::new()
+            .family_id(FamilyId(70_360))
+            .date_range(
+                NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 6, 2).unwrap(),
+            );
+        let plays = api
+            .plays()
+            .get_by_username_and_item("BluebearBgg", GameId(382_350), &params)
+            .await;
+        mock.assert_async().await;
+
+        assert!(plays.is_ok(), "error returned when okay expected");
+        let plays = plays.unwrap();
+
+        assert_eq!(
+            plays,
+            Plays {
+                username: "BluebearBgg".to_owned(),
+                user_id: Some(UserId(3_855_477)),
                 total: 1,
                 page: 1,
                 plays: vec![Play {
-                    id: 113_391_260,
+                    id: PlayId(113_391_260),
                     date: NaiveDate::from_ymd_opt(2026, 4, 30).unwrap(),
                     quantity: 1,
                     duration: Duration::minutes(60),
@@ -346,7 +893,7 @@ mod tests {
                     comments: Some("blah".to_owned()),
                     played_item: PlayedItem {
                         name: "Lost Ruins of Arnak: The Missing Expedition".to_owned(),
-                        id: 382_350,
+                        id: ObjectId(382_350),
                         sub_types: vec![ItemSubType::BoardGame, ItemSubType::BoardGameExpansion],
                     },
                     players: vec![],
@@ -354,4 +901,386 @@ mod tests {
             }
         );
     }
+
+    #[tokio::test]
+    async fn get_by_item_id_scores() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/thing_plays_scores.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let plays = api
+            .plays()
+            .get_by_item_id(GameId(312_484), &PlaysQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(plays.is_ok(), "error returned when okay expected");
+        let play = &plays.unwrap().plays[0];
+
+        let scores: Vec<_> = play.players.iter().map(Player::score_value).collect();
+        assert_eq!(
+            scores,
+            vec![
+                Some(Decimal::new(52, 0)),
+                None,
+                Some(Decimal::new(475, 1)),
+                Some(Decimal::new(52, 0)),
+                None,
+                Some(Decimal::new(-3, 0)),
+            ],
+        );
+        assert_eq!(play.players[1].score, "12/20");
+
+        let start_positions: Vec<_> = play
+            .players
+            .iter()
+            .map(Player::start_position_value)
+            .collect();
+        assert_eq!(
+            start_positions,
+            vec![Some(2), None, Some(1), None, Some(4), None],
+        );
+
+        let ratings: Vec<_> = play.players.iter().map(|player| player.rating).collect();
+        assert_eq!(ratings, vec![Some(8), None, None, None, Some(6), None]);
+
+        assert_eq!(play.average_score(), Some(Decimal::new(37125, 3)));
+        let ranked: Vec<_> = play
+            .ranked_players()
+            .into_iter()
+            .map(|(rank, player)| (rank, player.name.as_str()))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![(1, "Anna"), (1, "Dan"), (3, "Cat"), (4, "Finn")],
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_item_id_huge_scores() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/plays")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/plays/thing_plays_huge_scores.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let plays = api
+            .plays()
+            .get_by_item_id(GameId(312_484), &PlaysQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(plays.is_ok(), "error returned when okay expected");
+        let play = &plays.unwrap().plays[0];
+
+        // Each score fits in a decimal, but their total does not.
+        assert_eq!(play.players[0].score_value(), Some(Decimal::MAX));
+        assert_eq!(play.average_score(), None);
+        assert_eq!(play.ranked_players().len(), 2);
+    }
+
+    fn session() -> Session {
+        Session {
+            username: "bluebearbgg".to_owned(),
+            cookie: reqwest::header::HeaderValue::from_static(
+                "SessionID=abc123; bggusername=bluebearbgg",
+            ),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn log_play() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .match_header("cookie", "SessionID=abc123; bggusername=bluebearbgg")
+            .match_body(Matcher::Json(serde_json::json!({
+                "action": "save",
+                "ajax": 1,
+                "objecttype": "thing",
+                "objectid": 312_484,
+                "playdate": "2024-01-02",
+                "quantity": 2,
+                "length": 90,
+                "location": "Home",
+                "incomplete": 0,
+                "nowinstats": 1,
+                "comments": "Close game!",
+                "players": [
+                    {
+                        "username": "bluebearbgg",
+                        "userid": 2_353_874,
+                        "name": "Alice",
+                        "position": "1",
+                        "color": "Red",
+                        "score": "52",
+                        "rating": 8,
+                        "new": 0,
+                        "win": 1,
+                    },
+                    {
+                        "username": "",
+                        "userid": 0,
+                        "name": "Bob",
+                        "position": "",
+                        "color": "",
+                        "score": "",
+                        "rating": 0,
+                        "new": 1,
+                        "win": 0,
+                    },
+                ],
+            })))
+            .with_status(200)
+            .with_body(r#"{"playid":"87654321","numplays":2,"html":"<div></div>"}"#)
+            .create_async()
+            .await;
+
+        let mut winner = Player::new("Alice");
+        winner.username = Some("bluebearbgg".to_owned());
+        winner.user_id = Some(UserId(2_353_874));
+        winner.start_position = "1".to_owned();
+        winner.color = "Red".to_owned();
+        winner.score = "52".to_owned();
+        winner.rating = Some(8);
+        winner.won = true;
+        let mut new_player = Player::new("Bob");
+        new_player.first_time_playing = true;
+
+        let play = PlayLog::new(
+            GameId(312_484),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        )
+        .quantity(2)
+        .duration(Duration::minutes(90))
+        .location("Home")
+        .do_not_count_win_stats(true)
+        .player(winner)
+        .player(new_player)
+        .comments("Close game!");
+        let play_id = api.plays().log_play(&session(), &play).await;
+        mock.assert_async().await;
+
+        assert!(play_id.is_ok(), "error returned when okay expected");
+        assert_eq!(play_id.unwrap(), PlayId(87_654_321));
+    }
+
+    #[tokio::test]
+    async fn log_play_error() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .with_status(200)
+            .with_body(r#"{"error":"Invalid item. Play not saved."}"#)
+            .create_async()
+            .await;
+
+        let play = PlayLog::new(GameId(1), NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+        let play_id = api.plays().log_play(&session(), &play).await;
+        mock.assert_async().await;
+
+        match play_id {
+            Err(Error::UnknownApiErrors(messages)) => {
+                assert_eq!(messages, vec!["Invalid item. Play not saved.".to_owned()]);
+            },
+            _ => panic!("expected unknown API errors but got {play_id:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn log_play_expired_session() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let session = Session {
+            expires_at: Some(Utc::now() - Duration::hours(1)),
+            ..session()
+        };
+        let play = PlayLog::new(GameId(1), NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+        let play_id = api.plays().log_play(&session, &play).await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(play_id, Err(Error::SessionExpired)),
+            "expected session expired error but got {play_id:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn log_play_session_ended() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let forbidden_mock = server
+            .mock("POST", "/geekplay.php")
+            .with_status(403)
+            .create_async()
+            .await;
+
+        let play = PlayLog::new(GameId(1), NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+        let play_id = api.plays().log_play(&session(), &play).await;
+        forbidden_mock.assert_async().await;
+        forbidden_mock.remove_async().await;
+
+        assert!(
+            matches!(play_id, Err(Error::SessionExpired)),
+            "expected session expired error but got {play_id:?}",
+        );
+
+        let login_mock = server
+            .mock("POST", "/geekplay.php")
+            .with_status(200)
+            .with_body(r#"{"error":"You must login to save plays"}"#)
+            .create_async()
+            .await;
+
+        let play_id = api.plays().log_play(&session(), &play).await;
+        login_mock.assert_async().await;
+
+        assert!(
+            matches!(play_id, Err(Error::SessionExpired)),
+            "expected session expired error but got {play_id:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn edit_play() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "action": "save",
+                "playid": 87_654_321,
+                "objectid": 312_484,
+                "incomplete": 1,
+                "comments": "Ran out of time.",
+            })))
+            .with_status(200)
+            .with_body(r#"{"playid":"87654321","numplays":2,"html":"<div></div>"}"#)
+            .create_async()
+            .await;
+
+        let play = Play {
+            id: PlayId(87_654_321),
+            date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+            quantity: 1,
+            duration: Duration::minutes(60),
+            incomplete: false,
+            location: "Home".to_owned(),
+            do_not_count_win_stats: false,
+            played_item: PlayedItem {
+                name: "Lost Ruins of Arnak".to_owned(),
+                id: ObjectId(312_484),
+                sub_types: vec![ItemSubType::BoardGame],
+            },
+            players: vec![Player::new("Alice")],
+            comments: None,
+        };
+        let edited = PlayLog::from(&play)
+            .incomplete(true)
+            .comments("Ran out of time.");
+        let result = api.plays().edit_play(&session(), play.id, &edited).await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok(), "error returned when okay expected");
+    }
+
+    #[tokio::test]
+    async fn delete_play() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("POST", "/geekplay.php")
+            .match_header("cookie", "SessionID=abc123; bggusername=bluebearbgg")
+            .match_body(Matcher::Json(serde_json::json!({
+                "action": "delete",
+                "ajax": 1,
+                "finalize": 1,
+                "playid": 87_654_321,
+            })))
+            .with_status(200)
+            .with_body(r#"{"numplays":1}"#)
+            .create_async()
+            .await;
+
+        let result = api
+            .plays()
+            .delete_play(&session(), PlayId(87_654_321))
+            .await;
+        mock.assert_async().await;
+
+        assert!(result.is_ok(), "error returned when okay expected");
+    }
 }
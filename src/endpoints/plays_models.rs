@@ -1,61 +1,73 @@
+use std::str::FromStr;
+
 use chrono::{Duration, NaiveDate};
 use serde::Deserialize;
 
 use crate::deserialize::deserialize_minutes;
-use crate::ItemSubType;
+use crate::{Decimal, ItemSubType, ObjectId, PlayId, UserId};
 
 /// A play is a recorded instance of someone playing a game. This struct includes one page of a list
 /// of plays, along with the total number in the list.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Plays {
     /// The username of the user that these plays were requested for. Not included if the plays for
     /// a particular game was requested.
-    #[serde(default, rename = "@username")]
+    #[serde(default, rename(deserialize = "@username"))]
     pub username: String,
-    /// The ID of the user that these plays were requested for. Not included if the plays for a
+    /// The ID of the user that these plays were requested for. `None` if the plays for a
     /// particular game was requested.
-    #[serde(default, rename = "@userid")]
-    pub user_id: u64,
+    #[serde(default, rename(deserialize = "@userid"))]
+    pub user_id: Option<UserId>,
     /// The total number of plays for the query, only up to 100 being returned depending on the
     /// requested page.
-    #[serde(rename = "@total")]
+    #[serde(rename(deserialize = "@total"))]
     pub total: u64,
     /// The page of plays that is returned in the `plays` field of this object. Page size is 100.
-    #[serde(rename = "@page")]
+    #[serde(rename(deserialize = "@page"))]
     pub page: u64,
     /// The list of plays, each item in the list being a recorded instance of someone playing a
     /// game or other item. Includes various information about the session including who played and
     /// how long it lasted.
-    #[serde(default = "Vec::new", rename = "play")]
+    #[serde(default = "Vec::new", rename(deserialize = "play"))]
     pub plays: Vec<Play>,
 }
 
 /// A recorded instance of a game being played, the date it was played on and the players involved.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Play {
     /// ID of this play.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: PlayId,
     /// The date that the session happened.
-    #[serde(rename = "@date")]
+    #[serde(rename(deserialize = "@date"))]
     pub date: NaiveDate,
     /// The number of times the game was played in this session.
-    #[serde(rename = "@quantity")]
+    #[serde(rename(deserialize = "@quantity"))]
     pub quantity: u64,
     /// How long the play session lasted for.
-    #[serde(default, rename = "@length", deserialize_with = "deserialize_minutes")]
+    #[serde(
+        default,
+        rename(deserialize = "@length"),
+        deserialize_with = "deserialize_minutes"
+    )]
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::serialize::serialize_minutes")
+    )]
     pub duration: Duration,
     /// True if the game was not finished in this play.
-    #[serde(rename = "@incomplete")]
+    #[serde(rename(deserialize = "@incomplete"))]
     pub incomplete: bool,
     /// Where the game was played.
-    #[serde(rename = "@location")]
+    #[serde(rename(deserialize = "@location"))]
     pub location: String,
     /// An option to "Save play details but don't use when analyzing plays.".
-    #[serde(rename = "@nowinstats")]
+    #[serde(rename(deserialize = "@nowinstats"))]
     pub do_not_count_win_stats: bool,
     /// The game or other item that was played in this session.
-    #[serde(rename = "item")]
+    #[serde(rename(deserialize = "item"))]
     pub played_item: PlayedItem,
     /// The players who played.
     #[serde(
@@ -68,20 +80,66 @@ pub struct Play {
     pub comments: Option<String>,
 }
 
+impl Play {
+    /// The average of the players' scores, only including those whose score is a number.
+    ///
+    /// Returns `None` if no player has a numeric score, or if the scores are too large to add up.
+    pub fn average_score(&self) -> Option<Decimal> {
+        let scores: Vec<Decimal> = self
+            .players
+            .iter()
+            .filter_map(Player::score_value)
+            .collect();
+        if scores.is_empty() {
+            return None;
+        }
+        scores
+            .iter()
+            .try_fold(Decimal::ZERO, |total, score| total.checked_add(*score))?
+            .checked_div(Decimal::from(scores.len()))
+    }
+
+    /// The players ranked by score, highest first, along with their rank.
+    ///
+    /// Players with equal scores share a rank, with the next rank skipped for each tie, so scores
+    /// of 10, 8, 8 and 5 are ranked 1, 2, 2 and 4. Players without a numeric score are left out.
+    pub fn ranked_players(&self) -> Vec<(u64, &Player)> {
+        let mut players: Vec<(Decimal, &Player)> = self
+            .players
+            .iter()
+            .filter_map(|player| Some((player.score_value()?, player)))
+            .collect();
+        players.sort_by(|(score, _), (other_score, _)| other_score.cmp(score));
+
+        let mut ranked = Vec::with_capacity(players.len());
+        let mut previous: Option<(Decimal, u64)> = None;
+        for (position, (score, player)) in (1..).zip(players) {
+            let rank = match previous {
+                Some((previous_score, previous_rank)) if previous_score == score => previous_rank,
+                _ => position,
+            };
+            previous = Some((score, rank));
+            ranked.push((rank, player));
+        }
+        ranked
+    }
+}
+
 /// The item, usually a game, that was played.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct PlayedItem {
     /// The name of the item that was played.
-    #[serde(rename = "@name")]
+    #[serde(rename(deserialize = "@name"))]
     pub name: String,
     /// The unique identifier for this item.
-    #[serde(rename = "@objectid")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@objectid"))]
+    pub id: ObjectId,
     /// The list of types the item is, such as board game, or board game and board game expansion.
     #[serde(
         default = "Vec::new",
         deserialize_with = "deserialize_nested_sub_types_list",
-        rename = "subtypes"
+        rename(deserialize = "subtypes")
     )]
     pub sub_types: Vec<ItemSubType>,
 }
@@ -131,33 +189,192 @@ where
 /// Details for a player for a game session, as well as information involving that particular game
 /// play such as whether or not they won.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Player {
     /// The player's Boardgamegeek username, if they have one.
-    #[serde(rename = "@username")]
+    #[serde(rename(deserialize = "@username"))]
     pub username: Option<String>,
     /// The player's Boardgamegeek user ID, if they have one.
-    #[serde(rename = "@userid")]
-    pub user_id: Option<u64>,
+    #[serde(rename(deserialize = "@userid"))]
+    pub user_id: Option<UserId>,
     /// The player's name.
-    #[serde(rename = "@name")]
+    #[serde(rename(deserialize = "@name"))]
     pub name: String,
     /// The player's in game start position. Arbitrary user input is allowed so this is a string
-    /// not a number.
-    #[serde(rename = "@startposition")]
+    /// not a number, see [`Player::start_position_value`] for it parsed as a number.
+    #[serde(rename(deserialize = "@startposition"))]
     pub start_position: String,
     /// The player's in game color.
-    #[serde(rename = "@color")]
+    #[serde(rename(deserialize = "@color"))]
     pub color: String,
-    /// The player's in game score.
-    #[serde(rename = "@score")]
+    /// The player's in game score, exactly as entered. Arbitrary user input is allowed, see
+    /// [`Player::score_value`] for it parsed as a number.
+    #[serde(rename(deserialize = "@score"))]
     pub score: String,
     /// True if this was the first time this player played this game.
-    #[serde(rename = "@new")]
+    #[serde(rename(deserialize = "@new"))]
     pub first_time_playing: bool,
-    /// The player's rating of the game.
-    #[serde(rename = "@rating")]
-    pub rating: u64,
+    /// The player's rating of the game, if they gave one.
+    #[serde(
+        rename(deserialize = "@rating"),
+        deserialize_with = "deserialize_player_rating"
+    )]
+    pub rating: Option<u64>,
     /// True if this player won the game.
-    #[serde(rename = "@win")]
+    #[serde(rename(deserialize = "@win"))]
     pub won: bool,
 }
+
+impl Player {
+    /// Creates a player with just a name, for logging a play with [`PlayLog`]. Set the other fields
+    /// directly, such as linking the player to their account with the `username` field.
+    pub fn new(name: &str) -> Self {
+        Self {
+            username: None,
+            user_id: None,
+            name: name.to_owned(),
+            start_position: String::new(),
+            color: String::new(),
+            score: String::new(),
+            first_time_playing: false,
+            rating: None,
+            won: false,
+        }
+    }
+
+    /// The player's score parsed as a number, either an integer or a decimal.
+    ///
+    /// Returns `None` if no score was entered, or if it is not a plain number, such as `12/20`.
+    /// In which case the original input is still available in the `score` field.
+    pub fn score_value(&self) -> Option<Decimal> {
+        Decimal::from_str(self.score.trim()).ok()
+    }
+
+    /// The player's start position parsed as a number, or `None` if no start position was
+    /// entered or it is not a whole number.
+    pub fn start_position_value(&self) -> Option<u64> {
+        self.start_position.trim().parse().ok()
+    }
+}
+
+/// The details of a play to be logged, or to replace those of an existing play, with
+/// [`crate::PlaysApi::log_play`] and [`crate::PlaysApi::edit_play`].
+///
+/// An existing [`Play`] can be converted into a `PlayLog` to edit only some of its details.
+///
+/// ```
+/// use arnak::{GameId, PlayLog, Player};
+/// use chrono::{Duration, NaiveDate};
+///
+/// let mut winner = Player::new("Alice");
+/// winner.score = "52".to_owned();
+/// winner.won = true;
+///
+/// let play = PlayLog::new(GameId(312_484), NaiveDate::from_ymd_opt(2024, 1, 2).unwrap())
+///     .duration(Duration::minutes(90))
+///     .location("Home")
+///     .player(winner)
+///     .player(Player::new("Bob"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayLog {
+    pub(crate) item_id: ObjectId,
+    pub(crate) date: NaiveDate,
+    pub(crate) quantity: u64,
+    pub(crate) duration: Duration,
+    pub(crate) location: String,
+    pub(crate) incomplete: bool,
+    pub(crate) do_not_count_win_stats: bool,
+    pub(crate) players: Vec<Player>,
+    pub(crate) comments: String,
+}
+
+impl PlayLog {
+    /// Constructs a play of a game on the given date, played once, with no other details set.
+    pub fn new(item_id: impl Into<ObjectId>, date: NaiveDate) -> Self {
+        Self {
+            item_id: item_id.into(),
+            date,
+            quantity: 1,
+            duration: Duration::zero(),
+            location: String::new(),
+            incomplete: false,
+            do_not_count_win_stats: false,
+            players: vec![],
+            comments: String::new(),
+        }
+    }
+
+    /// Sets the number of times the game was played in this session. Defaults to 1.
+    pub fn quantity(mut self, quantity: u64) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /// Sets how long the play session lasted for, to the nearest minute.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets where the game was played.
+    pub fn location(mut self, location: &str) -> Self {
+        location.clone_into(&mut self.location);
+        self
+    }
+
+    /// Sets whether the game was not finished in this play.
+    pub fn incomplete(mut self, incomplete: bool) -> Self {
+        self.incomplete = incomplete;
+        self
+    }
+
+    /// Sets the option to "Save play details but don't use when analyzing plays.".
+    pub fn do_not_count_win_stats(mut self, do_not_count_win_stats: bool) -> Self {
+        self.do_not_count_win_stats = do_not_count_win_stats;
+        self
+    }
+
+    /// Adds a player to the play.
+    pub fn player(mut self, player: Player) -> Self {
+        self.players.push(player);
+        self
+    }
+
+    /// Sets all of the players of the play, replacing any already added.
+    pub fn players(mut self, players: Vec<Player>) -> Self {
+        self.players = players;
+        self
+    }
+
+    /// Sets the comments about this session.
+    pub fn comments(mut self, comments: &str) -> Self {
+        comments.clone_into(&mut self.comments);
+        self
+    }
+}
+
+impl From<&Play> for PlayLog {
+    fn from(play: &Play) -> Self {
+        Self {
+            item_id: play.played_item.id,
+            date: play.date,
+            quantity: play.quantity,
+            duration: play.duration,
+            location: play.location.clone(),
+            incomplete: play.incomplete,
+            do_not_count_win_stats: play.do_not_count_win_stats,
+            players: play.players.clone(),
+            comments: play.comments.clone().unwrap_or_default(),
+        }
+    }
+}
+
+// The API returns a rating of 0 when the player did not rate the game.
+fn deserialize_player_rating<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let rating = u64::deserialize(deserializer)?;
+    Ok((rating != 0).then_some(rating))
+}
@@ -0,0 +1,325 @@
+use std::str::FromStr;
+
+use xml::reader::XmlEvent;
+
+use crate::deserialize::{
+    decode_html_entities, html_entities_to_char_references, xml_reader_config,
+};
+use crate::{LinkedItem, ObjectId, Poll, PollResult, PollResults, PollSummaryResult};
+
+/// An element of an XML response, with all of its attributes, text and child elements.
+///
+/// Used by [`RawItem`] to give access to data from the underlying API that isn't included in any
+/// of the other models.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct XmlElement {
+    /// The tag name of the element.
+    pub name: String,
+    /// The attributes of the element, as name and value pairs in the order they appear.
+    pub attributes: Vec<(String, String)>,
+    /// The text inside the element, with surrounding whitespace trimmed and any entities decoded.
+    /// Empty if there is none.
+    pub text: String,
+    /// The child elements, in the order they appear.
+    pub children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    /// Returns the value of an attribute, if the element has it.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute_name, _)| attribute_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the first child element with the given tag name.
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Returns all child elements with the given tag name.
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Returns the `value` attribute of the first child with the given tag name.
+    ///
+    /// Most fields in responses from the underlying API are returned in this form, such as
+    /// `<yearpublished value="2020"/>`.
+    pub fn child_value(&self, name: &str) -> Option<&str> {
+        self.child(name)?.attribute("value")
+    }
+
+    // Parses an XML document into the tree of its root element.
+    pub(crate) fn parse(xml: &str) -> Result<Self, serde_xml_rs::Error> {
+        let xml = html_entities_to_char_references(xml);
+        let mut events = xml_reader_config().create_reader(xml.as_bytes());
+        // Elements which have been started but not yet ended, the root first.
+        let mut open_elements: Vec<XmlElement> = vec![];
+        loop {
+            match events.next()? {
+                XmlEvent::StartElement {
+                    name, attributes, ..
+                } => open_elements.push(XmlElement {
+                    name: name.local_name,
+                    attributes: attributes
+                        .into_iter()
+                        .map(|attribute| (attribute.name.local_name, attribute.value))
+                        .collect(),
+                    text: String::new(),
+                    children: vec![],
+                }),
+                XmlEvent::Characters(text) | XmlEvent::CData(text) => {
+                    if let Some(element) = open_elements.last_mut() {
+                        element.text.push_str(&text);
+                    }
+                },
+                XmlEvent::EndElement { .. } => {
+                    // The reader errors on unbalanced tags, so there is always an open element.
+                    let Some(element) = open_elements.pop() else {
+                        continue;
+                    };
+                    match open_elements.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                },
+                XmlEvent::EndDocument => {
+                    return Err(serde::de::Error::custom("document has no root element"));
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+/// An item returned by the underlying `thing` or `family` endpoints, without being parsed into one
+/// of the specific models.
+///
+/// This can be used for item types that aren't otherwise supported, or to read data that the other
+/// models leave out. The common parts of an item are pulled out into fields, and the full element
+/// is kept so anything else can still be read from it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct RawItem {
+    /// The type of the item, such as `boardgame` or `boardgamefamily`.
+    pub item_type: String,
+    /// The ID of the item. What kind of item this is depends on the item type.
+    pub id: ObjectId,
+    /// The names of the item, the primary name along with any alternate names.
+    pub names: Vec<RawName>,
+    /// Links from this item to other items, such as designers, categories or expansions.
+    pub links: Vec<RawLink>,
+    /// Polls that users have voted in for this item, along with their summaries.
+    pub polls: Vec<Poll>,
+    /// The full XML element for the item.
+    pub element: XmlElement,
+}
+
+/// A name of an item.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct RawName {
+    /// The type of the name, usually either `primary` or `alternate`.
+    pub name_type: String,
+    /// The index of the first character to use when sorting by this name, if included. Used to
+    /// skip words such as "The".
+    pub sort_index: Option<u64>,
+    /// The name itself.
+    pub value: String,
+}
+
+/// A link from an item to another item.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct RawLink {
+    /// The type of the link, such as `boardgamedesigner` or `boardgameexpansion`.
+    pub link_type: String,
+    /// The ID of the linked item. What kind of item this is depends on the link type.
+    pub id: ObjectId,
+    /// The name of the linked item.
+    pub value: String,
+    /// True if the link is from the other item to this one, rather than from this item. For
+    /// example, the base game on an expansion is linked as an inbound expansion.
+    pub inbound: bool,
+}
+
+// Parses the items out of the response from the `thing` or `family` endpoints.
+pub(crate) fn parse_raw_items(xml: &str) -> Result<Vec<RawItem>, serde_xml_rs::Error> {
+    let root = XmlElement::parse(xml)?;
+    if root.name != "items" {
+        return Err(serde::de::Error::custom(format!(
+            "expected root element `items` but got `{}`",
+            root.name,
+        )));
+    }
+    root.children
+        .into_iter()
+        .filter(|child| child.name == "item")
+        .map(RawItem::from_element)
+        .collect()
+}
+
+impl RawItem {
+    fn from_element(element: XmlElement) -> Result<Self, serde_xml_rs::Error> {
+        let item_type = required_attribute(&element, "type")?.to_owned();
+        let id = parse_attribute(&element, "id")?;
+        let names = element
+            .children_named("name")
+            .map(|name| {
+                Ok(RawName {
+                    name_type: required_attribute(name, "type")?.to_owned(),
+                    sort_index: name
+                        .attribute("sortindex")
+                        .map(|_| parse_attribute(name, "sortindex"))
+                        .transpose()?,
+                    value: required_attribute(name, "value")?.to_owned(),
+                })
+            })
+            .collect::<Result<_, serde_xml_rs::Error>>()?;
+        let links = element
+            .children_named("link")
+            .map(|link| {
+                Ok(RawLink {
+                    link_type: required_attribute(link, "type")?.to_owned(),
+                    id: parse_attribute(link, "id")?,
+                    value: required_attribute(link, "value")?.to_owned(),
+                    inbound: link.attribute("inbound") == Some("true"),
+                })
+            })
+            .collect::<Result<_, serde_xml_rs::Error>>()?;
+        let mut polls = element
+            .children_named("poll")
+            .map(poll_from_element)
+            .collect::<Result<Vec<_>, _>>()?;
+        // Summaries are returned in separate elements after the polls they are for.
+        for summary in element.children_named("poll-summary") {
+            let name = required_attribute(summary, "name")?;
+            if let Some(poll) = polls.iter_mut().find(|poll| poll.name == name) {
+                poll.summary = summary
+                    .children_named("result")
+                    .map(|result| {
+                        Ok(PollSummaryResult {
+                            name: required_attribute(result, "name")?.to_owned(),
+                            value: required_attribute(result, "value")?.to_owned(),
+                        })
+                    })
+                    .collect::<Result<_, serde_xml_rs::Error>>()?;
+            }
+        }
+        Ok(Self {
+            item_type,
+            id,
+            names,
+            links,
+            polls,
+            element,
+        })
+    }
+}
+
+// Helpers for building the models for the less common item types from a raw item.
+impl RawItem {
+    // The primary name of the item.
+    pub(crate) fn primary_name(&self) -> Result<String, serde_xml_rs::Error> {
+        self.names
+            .iter()
+            .find(|name| name.name_type == "primary")
+            .map(|name| name.value.clone())
+            .ok_or_else(|| serde::de::Error::missing_field("name type=\"primary\""))
+    }
+
+    // All names of the item other than the primary name.
+    pub(crate) fn alternate_names(&self) -> Vec<String> {
+        self.names
+            .iter()
+            .filter(|name| name.name_type == "alternate")
+            .map(|name| name.value.clone())
+            .collect()
+    }
+
+    // The text of a child element, such as an image link, if it is present and not empty.
+    pub(crate) fn child_text(&self, name: &str) -> Option<String> {
+        self.element
+            .child(name)
+            .filter(|child| !child.text.is_empty())
+            .map(|child| child.text.clone())
+    }
+
+    // The description of the item, with the entities that were escaped twice decoded.
+    pub(crate) fn description(&self) -> String {
+        self.child_text("description")
+            .map(|description| decode_html_entities(description.into()).into_owned())
+            .unwrap_or_default()
+    }
+
+    // All items linked from this one with the given link type.
+    pub(crate) fn linked_items(&self, link_type: &str) -> Vec<LinkedItem> {
+        self.links
+            .iter()
+            .filter(|link| link.link_type == link_type)
+            .map(|link| LinkedItem {
+                id: link.id,
+                name: link.value.clone(),
+            })
+            .collect()
+    }
+}
+
+fn poll_from_element(poll: &XmlElement) -> Result<Poll, serde_xml_rs::Error> {
+    let results = poll
+        .children_named("results")
+        .map(|results| {
+            Ok(PollResults {
+                player_count: results
+                    .attribute("numplayers")
+                    .map(|_| parse_attribute(results, "numplayers"))
+                    .transpose()?,
+                results: results
+                    .children_named("result")
+                    .map(|result| {
+                        Ok(PollResult {
+                            value: required_attribute(result, "value")?.to_owned(),
+                            level: result
+                                .attribute("level")
+                                .map(|_| parse_attribute(result, "level"))
+                                .transpose()?,
+                            votes: parse_attribute(result, "numvotes")?,
+                        })
+                    })
+                    .collect::<Result<_, serde_xml_rs::Error>>()?,
+            })
+        })
+        .collect::<Result<_, serde_xml_rs::Error>>()?;
+    Ok(Poll {
+        name: required_attribute(poll, "name")?.to_owned(),
+        title: required_attribute(poll, "title")?.to_owned(),
+        total_votes: parse_attribute(poll, "totalvotes")?,
+        results,
+        summary: vec![],
+    })
+}
+
+fn required_attribute<'a>(
+    element: &'a XmlElement,
+    name: &str,
+) -> Result<&'a str, serde_xml_rs::Error> {
+    element.attribute(name).ok_or_else(|| {
+        serde::de::Error::custom(format!(
+            "missing attribute `{name}` on element `{}`",
+            element.name,
+        ))
+    })
+}
+
+fn parse_attribute<T: FromStr>(element: &XmlElement, name: &str) -> Result<T, serde_xml_rs::Error> {
+    let value = required_attribute(element, name)?;
+    value.parse().map_err(|_| {
+        serde::de::Error::custom(format!(
+            "invalid value `{value}` for attribute `{name}` on element `{}`",
+            element.name,
+        ))
+    })
+}
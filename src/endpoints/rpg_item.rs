@@ -0,0 +1,182 @@
+use super::{parse_raw_items, ItemType, RpgItem};
+use crate::api::parse_response_with;
+use crate::{BoardGameGeekApi, Error, IntoQueryParam, QueryParam, Result, RpgItemId};
+
+#[derive(Clone, Debug)]
+struct RpgItemQueryBuilder<'builder> {
+    rpg_item_ids: &'builder [RpgItemId],
+}
+
+impl<'builder> RpgItemQueryBuilder<'builder> {
+    fn new(rpg_item_ids: &'builder [RpgItemId]) -> Self {
+        Self { rpg_item_ids }
+    }
+
+    fn build(self) -> Vec<QueryParam<'builder>> {
+        vec![
+            ItemType::RpgItem.into_query_param("type"),
+            self.rpg_item_ids.into_query_param("id"),
+        ]
+    }
+}
+
+/// RPG item endpoint of the API. Used for retrieving items for role-playing games from RPG Geek,
+/// such as rulebooks and adventures, by their IDs.
+///
+/// This uses the same underlying "thing" API endpoint as the game and accessory endpoints, and
+/// can be used regardless of the [`crate::Domain`] that the API is set to.
+pub struct RpgItemApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> RpgItemApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "thing",
+        }
+    }
+
+    /// Gets an RPG item by ID.
+    pub async fn get_by_id(&self, id: RpgItemId) -> Result<RpgItem> {
+        let mut rpg_items = self.get_by_ids(&[id]).await?;
+
+        match rpg_items.len() {
+            0 => Err(Error::ItemNotFound),
+            1 => Ok(rpg_items.remove(0)),
+            len => Err(Error::UnexpectedResponseError(format!(
+                "expected 1 RPG item but got {len}",
+            ))),
+        }
+    }
+
+    /// Gets RPG items by their IDs, up to a max of 20 at once.
+    pub async fn get_by_ids(&self, ids: &[RpgItemId]) -> Result<Vec<RpgItem>> {
+        let query = RpgItemQueryBuilder::new(ids);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let text = self.api.execute_raw_request(request).await?;
+
+        parse_response_with(&text, |xml| {
+            parse_raw_items(xml)?
+                .iter()
+                .map(RpgItem::from_raw)
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockito::Matcher;
+
+    use crate::{
+        BoardGameGeekApi, Domain, Error, LinkedItem, ObjectId, RpgItem, RpgItemId,
+        StaticTokenProvider,
+    };
+
+    fn linked_item(id: u64, name: &str) -> LinkedItem {
+        LinkedItem {
+            id: ObjectId(id),
+            name: name.to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_by_id() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "rpgitem".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "181361".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/rpg_item/rpg_item.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let rpg_item = api.rpg_item().get_by_id(RpgItemId(181_361)).await;
+        mock.assert_async().await;
+
+        assert!(rpg_item.is_ok(), "error returned when okay expected");
+        let rpg_item = rpg_item.unwrap();
+
+        assert_eq!(
+            rpg_item,
+            RpgItem {
+                id: RpgItemId(181_361),
+                name: "Player's Handbook (D&D 5e)".to_owned(),
+                alternate_names: vec!["Manuel des Joueurs".to_owned()],
+                description: "Everything a player needs to create heroic characters.\n\n\u{2013}from the back cover".to_owned(),
+                image: Some("https://cf.geekdo-images.com/ZxK-ejx_bB2hGQ4xQ6qShQ__original/img/yT4yKpuMNXRA-sn7zPZ8wjpJ4xI=/0x0/filters:format(jpeg)/pic2365021.jpg".to_owned()),
+                thumbnail: Some("https://cf.geekdo-images.com/ZxK-ejx_bB2hGQ4xQ6qShQ__thumb/img/Mp5fvChD4IOIw1yXNQ0lrEFQ2Yg=/fit-in/200x150/filters:strip_icc()/pic2365021.jpg".to_owned()),
+                year_published: Some(2014),
+                series_code: Some("A92170000".to_owned()),
+                systems: vec![linked_item(35005, "Dungeons & Dragons (5th Edition)")],
+                designers: vec![
+                    linked_item(4398, "Jeremy Crawford"),
+                    linked_item(1241, "Mike Mearls"),
+                ],
+                artists: vec![linked_item(24612, "Tyler Jacobson")],
+                publishers: vec![linked_item(3, "Wizards of the Coast")],
+                genres: vec![linked_item(22, "Fantasy")],
+                settings: vec![],
+                series: vec![linked_item(60, "D&D Core Rules")],
+            },
+        );
+        assert_eq!(
+            rpg_item.description_paragraphs(),
+            vec![
+                "Everything a player needs to create heroic characters.",
+                "\u{2013}from the back cover",
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn get_by_id_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "rpgitem".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "999999999".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/rpg_item/rpg_item_not_found.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let rpg_item = api.rpg_item().get_by_id(RpgItemId(999_999_999)).await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(rpg_item, Err(Error::ItemNotFound)),
+            "expected item not found error but got {rpg_item:?}",
+        );
+    }
+}
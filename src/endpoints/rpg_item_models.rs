@@ -0,0 +1,83 @@
+use super::RawItem;
+use crate::{description_paragraphs, LinkedItem, RpgItemId};
+
+/// An item for a role-playing game from RPG Geek, such as a rulebook, adventure or supplement.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct RpgItem {
+    /// The ID of the RPG item.
+    pub id: RpgItemId,
+    /// The name of the RPG item.
+    pub name: String,
+    /// A list of alternate names for the RPG item, often translations.
+    pub alternate_names: Vec<String>,
+    /// A description of the RPG item.
+    pub description: String,
+    /// A link to a jpg image for the RPG item.
+    pub image: Option<String>,
+    /// A link to a jpg thumbnail image for the RPG item.
+    pub thumbnail: Option<String>,
+    /// The year the RPG item was first published, if known.
+    pub year_published: Option<i64>,
+    /// The code the publisher gives the RPG item within a series, if it has one.
+    pub series_code: Option<String>,
+    /// The role-playing game systems that the item is for, such as an edition of Dungeons &
+    /// Dragons.
+    pub systems: Vec<LinkedItem>,
+    /// The designers of the RPG item.
+    pub designers: Vec<LinkedItem>,
+    /// The artists of the RPG item.
+    pub artists: Vec<LinkedItem>,
+    /// The publishers of the RPG item.
+    pub publishers: Vec<LinkedItem>,
+    /// The genres of the RPG item, such as fantasy or horror.
+    pub genres: Vec<LinkedItem>,
+    /// The settings that the RPG item takes place in.
+    pub settings: Vec<LinkedItem>,
+    /// The series that the RPG item is part of.
+    pub series: Vec<LinkedItem>,
+}
+
+impl RpgItem {
+    /// The paragraphs of the RPG item's description, see [`description_paragraphs`].
+    pub fn description_paragraphs(&self) -> Vec<&str> {
+        description_paragraphs(&self.description)
+    }
+
+    pub(crate) fn from_raw(item: &RawItem) -> Result<Self, serde_xml_rs::Error> {
+        if item.item_type != "rpgitem" {
+            return Err(serde::de::Error::custom(format!(
+                "expected item of type `rpgitem` but got `{}`",
+                item.item_type,
+            )));
+        }
+        let year_published = item
+            .element
+            .child_value("yearpublished")
+            .map(str::parse::<i64>)
+            .transpose()
+            .map_err(<serde_xml_rs::Error as serde::de::Error>::custom)?
+            .filter(|year| *year != 0);
+        Ok(Self {
+            id: RpgItemId(item.id.0),
+            name: item.primary_name()?,
+            alternate_names: item.alternate_names(),
+            description: item.description(),
+            image: item.child_text("image"),
+            thumbnail: item.child_text("thumbnail"),
+            year_published,
+            series_code: item
+                .element
+                .child_value("seriescode")
+                .filter(|series_code| !series_code.is_empty())
+                .map(str::to_owned),
+            systems: item.linked_items("rpg"),
+            designers: item.linked_items("rpgdesigner"),
+            artists: item.linked_items("rpgartist"),
+            publishers: item.linked_items("rpgpublisher"),
+            genres: item.linked_items("rpggenre"),
+            settings: item.linked_items("rpgsetting"),
+            series: item.linked_items("rpgseries"),
+        })
+    }
+}
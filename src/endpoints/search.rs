@@ -1,5 +1,5 @@
 use super::{ItemType, SearchResult, SearchResults};
-use crate::{BoardGameGeekApi, IntoQueryParam, QueryParam, Result};
+use crate::{BoardGameGeekApi, Domain, IntoQueryParam, QueryParam, Result};
 
 // All optional query parameters for making a request to the
 // search endpoint.
@@ -51,15 +51,22 @@ impl SearchQueryParams {
 struct SearchQueryBuilder<'builder> {
     search_query: &'builder str,
     params: &'builder SearchQueryParams,
+    // Used to pick the type of item searched for when none are provided.
+    domain: Domain,
 }
 
 impl<'builder> SearchQueryBuilder<'builder> {
-    // Constructs a new query builder from a search query, and the rest of the
-    // parameters.
-    fn new(search_query: &'builder str, params: &'builder SearchQueryParams) -> Self {
+    // Constructs a new query builder from a search query, the rest of the
+    // parameters, and the domain of the API.
+    fn new(
+        search_query: &'builder str,
+        params: &'builder SearchQueryParams,
+        domain: Domain,
+    ) -> Self {
         Self {
             search_query,
             params,
+            domain,
         }
     }
 
@@ -73,7 +80,7 @@ impl<'builder> SearchQueryBuilder<'builder> {
             query_params.push(value.into_query_param("exact"));
         }
         if self.params.item_types.is_empty() {
-            query_params.push(ItemType::BoardGame.into_query_param("type"));
+            query_params.push(self.domain.item_type().into_query_param("type"));
         } else {
             query_params.push(self.params.item_types.into_query_param("type"));
         }
@@ -104,9 +111,12 @@ impl<'api> SearchApi<'api> {
     /// and expansions. However, expansions will be included in the results twice,
     /// once with the type [`ItemType::BoardGame`] and once with the type
     /// [`ItemType::BoardGameExpansion`].
+    ///
+    /// If the API is set to the [`Domain::Rpg`] or [`Domain::VideoGame`] domain, this instead
+    /// searches for RPG items or video games.
     pub async fn search_games(&self, query: &str) -> Result<Vec<SearchResult>> {
         let params = SearchQueryParams::new();
-        let query = SearchQueryBuilder::new(query, &params);
+        let query = SearchQueryBuilder::new(query, &params, self.api.domain);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let response = self.api.execute_request::<SearchResults>(request).await?;
@@ -119,9 +129,12 @@ impl<'api> SearchApi<'api> {
     /// and expansions. However, expansions will be included in the results twice,
     /// once with the type [`ItemType::BoardGame`] and once with the type
     /// [`ItemType::BoardGameExpansion`].
+    ///
+    /// If the API is set to the [`Domain::Rpg`] or [`Domain::VideoGame`] domain, this instead
+    /// searches for RPG items or video games.
     pub async fn search_games_exact(&self, query: &str) -> Result<Vec<SearchResult>> {
         let params = SearchQueryParams::new().exact(true);
-        let query = SearchQueryBuilder::new(query, &params);
+        let query = SearchQueryBuilder::new(query, &params, self.api.domain);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let response = self.api.execute_request::<SearchResults>(request).await?;
@@ -131,14 +144,14 @@ impl<'api> SearchApi<'api> {
 
     /// Searches with a given query, only searching for items with the provided types. If none are
     /// provided then it will default to searching within board games and board game expansions,
-    /// the same functionality as calling `search_games`
+    /// or the items of the API's domain, the same functionality as calling `search_games`
     pub async fn search(
         &self,
         query: &str,
         item_types: Vec<ItemType>,
     ) -> Result<Vec<SearchResult>> {
         let params = SearchQueryParams::new().item_types(item_types);
-        let query = SearchQueryBuilder::new(query, &params);
+        let query = SearchQueryBuilder::new(query, &params, self.api.domain);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let response = self.api.execute_request::<SearchResults>(request).await?;
@@ -148,14 +161,15 @@ impl<'api> SearchApi<'api> {
 
     /// Searches for exact matches to a given query, only searching for items with the provided
     /// types. If none are provided then it will default to searching within board games and
-    /// board game expansions, the same functionality as calling `search_games_exact`
+    /// board game expansions, or the items of the API's domain, the same functionality as calling
+    /// `search_games_exact`
     pub async fn search_exact(
         &self,
         query: &str,
         item_types: Vec<ItemType>,
     ) -> Result<Vec<SearchResult>> {
         let params = SearchQueryParams::new().item_types(item_types).exact(true);
-        let query = SearchQueryBuilder::new(query, &params);
+        let query = SearchQueryBuilder::new(query, &params, self.api.domain);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let response = self.api.execute_request::<SearchResults>(request).await?;
@@ -166,10 +180,12 @@ impl<'api> SearchApi<'api> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use mockito::Matcher;
 
     use super::*;
-    use crate::{ItemType, SearchResult};
+    use crate::{Domain, GameId, ItemType, SearchResult, StaticTokenProvider};
 
     #[tokio::test]
     async fn search_games() {
@@ -177,6 +193,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -203,7 +221,7 @@ mod tests {
         assert_eq!(
             search_results[0],
             SearchResult {
-                id: 312_484,
+                id: GameId(312_484),
                 item_type: ItemType::BoardGame,
                 name: "Lost Ruins of Arnak".to_owned(),
                 year_published: Some(2020),
@@ -212,7 +230,7 @@ mod tests {
         assert_eq!(
             search_results[1],
             SearchResult {
-                id: 341_254,
+                id: GameId(341_254),
                 item_type: ItemType::BoardGameExpansion,
                 name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
                 year_published: Some(2021),
@@ -226,6 +244,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -252,7 +272,7 @@ mod tests {
         assert_eq!(
             search_results[0],
             SearchResult {
-                id: 312_484,
+                id: GameId(312_484),
                 item_type: ItemType::BoardGame,
                 name: "Lost Ruins of Arnak".to_owned(),
                 year_published: Some(2020),
@@ -266,6 +286,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -292,7 +314,7 @@ mod tests {
         assert_eq!(
             search_results[0],
             SearchResult {
-                id: 12668,
+                id: GameId(12668),
                 item_type: ItemType::BoardGame,
                 name: "\"Get Smart\"".to_owned(),
                 year_published: Some(1965),
@@ -301,7 +323,7 @@ mod tests {
         assert_eq!(
             search_results[1],
             SearchResult {
-                id: 30346,
+                id: GameId(30346),
                 item_type: ItemType::BoardGame,
                 name: "\"Get Smart\" Card Game".to_owned(),
                 year_published: None,
@@ -315,6 +337,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -344,7 +368,7 @@ mod tests {
         assert_eq!(
             search_results[0],
             SearchResult {
-                id: 341_254,
+                id: GameId(341_254),
                 item_type: ItemType::BoardGameExpansion,
                 name: "Lost Ruins of Arnak: Expedition Leaders".to_owned(),
                 year_published: Some(2021),
@@ -358,6 +382,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -388,7 +414,7 @@ mod tests {
         assert_eq!(
             search_results[0],
             SearchResult {
-                id: 312_484,
+                id: GameId(312_484),
                 item_type: ItemType::BoardGame,
                 name: "Lost Ruins of Arnak".to_owned(),
                 year_published: Some(2020),
@@ -402,6 +428,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -441,7 +469,7 @@ mod tests {
         assert_eq!(
             search_results[0],
             SearchResult {
-                id: 403_238,
+                id: GameId(403_238),
                 item_type: ItemType::BoardGameAccessory,
                 name: "Lost Ruins of Arnak + Expansions: The GiftForge Insert".to_owned(),
                 year_published: Some(2023),
@@ -450,11 +478,52 @@ mod tests {
         assert_eq!(
             search_results[1],
             SearchResult {
-                id: 312_484,
+                id: GameId(312_484),
                 item_type: ItemType::BoardGame,
                 name: "Lost Ruins of Arnak".to_owned(),
                 year_published: Some(2020),
             },
         );
     }
+
+    #[tokio::test]
+    async fn search_games_rpg_domain() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::Rpg,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("query".to_owned(), "players handbook".to_owned()),
+                Matcher::UrlEncoded("type".to_owned(), "rpgitem".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/search/search_rpg_items.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let search_results = api.search().search_games("players handbook").await;
+        mock.assert_async().await;
+
+        assert!(search_results.is_ok(), "error returned when okay expected");
+        let search_results = search_results.unwrap();
+
+        assert_eq!(
+            search_results,
+            vec![SearchResult {
+                id: GameId(181_361),
+                item_type: ItemType::RpgItem,
+                name: "Player's Handbook (D&D 5e)".to_owned(),
+                year_published: Some(2014),
+            }],
+        );
+    }
 }
@@ -4,6 +4,7 @@ use serde::Deserialize;
 
 use super::ItemType;
 use crate::deserialize::{XmlSignedValue, XmlStringValue};
+use crate::GameId;
 
 /// The returned struct containing a list of search results.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -16,9 +17,10 @@ pub(crate) struct SearchResults {
 /// A result when searching for a name. Includes the game's name, type, and year
 /// published.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct SearchResult {
     /// The ID of the game.
-    pub id: u64,
+    pub id: GameId,
     /// The type of game, which will either be a board game, expansion or accessory for a board
     /// game.
     pub item_type: ItemType,
@@ -98,7 +100,7 @@ impl<'de> Deserialize<'de> for SearchResult {
                     item_type.ok_or_else(|| serde::de::Error::missing_field("item_type"))?;
                 let name = name.ok_or_else(|| serde::de::Error::missing_field("name"))?;
                 Ok(Self::Value {
-                    id,
+                    id: GameId(id),
                     item_type,
                     name,
                     year_published,
@@ -0,0 +1,304 @@
+use super::{ItemType, Thing, ThingId, Things};
+use crate::{BoardGameGeekApi, IntoQueryParam, QueryParam, Result};
+
+/// All optional query parameters for making a request to the thing endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct ThingQueryParams {
+    // Whether to include the version information.
+    include_versions: Option<bool>,
+    // Whether to include links to related videos for games.
+    include_videos: Option<bool>,
+    // Whether to include marketplace data for each item.
+    include_marketplace_data: Option<bool>,
+    // Whether to include a page of comments for each item.
+    //
+    // Comment will include the rating too if there was one included. Sorted by username ascending.
+    // Cannot be used in conjunction with rating comments.
+    include_comments: Option<bool>,
+    // Whether to include a page of rating comments for each item.
+    //
+    // A rating comment is a rating for an item, which will also include a comment if there was
+    // one. Sorted by rating descending. Cannot be used in conjunction with comments.
+    include_rating_comments: Option<bool>,
+    // Which page of comments and videos to return. Default 1.
+    page: Option<u64>,
+    // Size of the comment and video pages, between 10 and 100.
+    page_size: Option<u64>,
+}
+
+impl ThingQueryParams {
+    /// Constructs a new thing query with parameters set to None.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `include_versions` query parameter. If set then information about different
+    /// versions of each item will be included, if applicable.
+    pub fn include_versions(mut self, include_versions: bool) -> Self {
+        self.include_versions = Some(include_versions);
+        self
+    }
+
+    /// Sets the `include_videos` query parameter. If set then links to related videos will be
+    /// included for games.
+    pub fn include_videos(mut self, include_videos: bool) -> Self {
+        self.include_videos = Some(include_videos);
+        self
+    }
+
+    /// Sets the `include_marketplace_data` query parameter. If set then information about where to
+    /// buy each item and for what cost will be included.
+    pub fn include_marketplace_data(mut self, include_marketplace_data: bool) -> Self {
+        self.include_marketplace_data = Some(include_marketplace_data);
+        self
+    }
+
+    /// Sets the `include_comments` query parameter. If set then comments on each item will be
+    /// included, along with a rating if one was included with the comment.
+    ///
+    /// List of comments is paginated, where the page and page size are changed via the `page` and
+    /// `page_size` query parameters. Ordered by username ascending.
+    ///
+    /// Note that this is not compatible with the `include_rating_comments` parameter.
+    pub fn include_comments(mut self, include_comments: bool) -> Self {
+        self.include_comments = Some(include_comments);
+        self
+    }
+
+    /// Sets the `include_rating_comments` query parameter. If set then ratings on each item will
+    /// be included, along with a comment if one was included with the rating.
+    ///
+    /// List of comments is paginated, where the page and page size are changed via the `page` and
+    /// `page_size` query parameters. Ordered by rating descending.
+    ///
+    /// Note that this is not compatible with the `include_comments` parameter.
+    pub fn include_rating_comments(mut self, include_rating_comments: bool) -> Self {
+        self.include_rating_comments = Some(include_rating_comments);
+        self
+    }
+
+    /// Sets the `page` query parameter. If set then this page of comments will be returned.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `page_size` query parameter. If set then comment pages will be this size. Minimum
+    /// 10 and maximum 100, if unset or out of these bounds the page size will be 100.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+// Struct for building a query for the request to the thing endpoint.
+#[derive(Clone, Debug)]
+struct ThingQueryBuilder<'builder> {
+    ids: &'builder [ThingId],
+    params: &'builder ThingQueryParams,
+}
+
+impl<'builder> ThingQueryBuilder<'builder> {
+    // Constructs a new query builder from a list of IDs to request, and the rest of the
+    // parameters.
+    fn new(ids: &'builder [ThingId], params: &'builder ThingQueryParams) -> Self {
+        Self { ids, params }
+    }
+
+    // Converts the list of parameters into a vector of
+    // key value pairs that reqwest can use as HTTP query parameters.
+    fn build(self) -> Vec<QueryParam<'builder>> {
+        let mut query_params: Vec<_> = vec![];
+        // Only the types that there is a `Thing` for are requested, so that items of other types
+        // with the same IDs aren't returned.
+        let item_types = vec![
+            ItemType::BoardGame,
+            ItemType::BoardGameExpansion,
+            ItemType::BoardGameAccessory,
+        ];
+        query_params.push(item_types.into_query_param("type"));
+        query_params.push(true.into_query_param("stats"));
+        query_params.push(self.ids.into_query_param("id"));
+        if let Some(include_versions) = self.params.include_versions {
+            query_params.push(include_versions.into_query_param("versions"));
+        }
+        if let Some(include_videos) = self.params.include_videos {
+            query_params.push(include_videos.into_query_param("videos"));
+        }
+        if let Some(include_marketplace_data) = self.params.include_marketplace_data {
+            query_params.push(include_marketplace_data.into_query_param("marketplace"));
+        }
+        if let Some(include_comments) = self.params.include_comments {
+            query_params.push(include_comments.into_query_param("comments"));
+        }
+        if let Some(include_rating_comments) = self.params.include_rating_comments {
+            query_params.push(include_rating_comments.into_query_param("ratingcomments"));
+        }
+        if let Some(page) = self.params.page {
+            query_params.push(page.into_query_param("page"));
+        }
+        if let Some(page_size) = self.params.page_size {
+            query_params.push(page_size.into_query_param("pagesize"));
+        }
+        query_params
+    }
+}
+
+/// Thing endpoint for the API.
+///
+/// Retrieve items of mixed types by their IDs, up to a max of 20 at once, such as the games,
+/// expansions and accessories in a collection. Each is returned as a [`Thing`] of the matching
+/// type.
+///
+/// This uses the same underlying "thing" API endpoint as the game and accessory endpoints.
+pub struct ThingApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> ThingApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "thing",
+        }
+    }
+
+    /// Gets games, expansions and accessories by their IDs. Can return items of each type
+    /// together, in the order they are returned by the API.
+    pub async fn get_by_ids(
+        &self,
+        ids: &[ThingId],
+        query_params: &ThingQueryParams,
+    ) -> Result<Vec<Thing>> {
+        let query = ThingQueryBuilder::new(ids, query_params);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let things = self.api.execute_request::<Things>(request).await?;
+
+        Ok(things.things)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::deserialize::deserialize_xml_string;
+    use crate::{Accessories, AccessoryId, Domain, GameId, GameType, Games, StaticTokenProvider};
+
+    #[tokio::test]
+    async fn get_by_ids() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "type".to_owned(),
+                    "boardgame,boardgameexpansion,boardgameaccessory".to_owned(),
+                ),
+                Matcher::UrlEncoded("stats".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "312484,196929,341254".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/thing/things.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let things = api
+            .thing()
+            .get_by_ids(
+                &[
+                    ThingId::Game(GameId(312_484)),
+                    ThingId::Accessory(AccessoryId(196_929)),
+                    ThingId::Game(GameId(341_254)),
+                ],
+                &ThingQueryParams::new(),
+            )
+            .await;
+        mock.assert_async().await;
+
+        assert!(things.is_ok(), "error returned when okay expected");
+        let things = things.unwrap();
+
+        // Each item is parsed the same as it would be on its own by the game or accessory models.
+        let game = deserialize_xml_string::<Games>(
+            &std::fs::read_to_string("test_data/game/game.xml").expect("failed to load test data"),
+        )
+        .unwrap()
+        .games
+        .remove(0);
+        let accessory = deserialize_xml_string::<Accessories>(
+            &std::fs::read_to_string("test_data/accessory/accessory.xml")
+                .expect("failed to load test data"),
+        )
+        .unwrap()
+        .accessories
+        .remove(0);
+        let expansion = deserialize_xml_string::<Games>(
+            &std::fs::read_to_string("test_data/game/game_expansion.xml")
+                .expect("failed to load test data"),
+        )
+        .unwrap()
+        .games
+        .remove(0);
+        assert_eq!(
+            things,
+            vec![
+                Thing::Game(Box::new(game)),
+                Thing::Accessory(Box::new(accessory)),
+                Thing::Game(Box::new(expansion)),
+            ],
+        );
+
+        let ids: Vec<_> = things.iter().map(Thing::id).collect();
+        assert_eq!(
+            ids,
+            vec![
+                ThingId::Game(GameId(312_484)),
+                ThingId::Accessory(AccessoryId(196_929)),
+                ThingId::Game(GameId(341_254)),
+            ],
+        );
+        assert_eq!(things[1].name(), "7 Wonders: Metal Coins");
+        assert_eq!(
+            things[2].as_game().map(|game| game.game_type),
+            Some(GameType::BoardGameExpansion),
+        );
+        assert!(things[1].as_game().is_none());
+    }
+
+    #[test]
+    fn deserialize_type_after_other_attributes() {
+        let xml = std::fs::read_to_string("test_data/thing/things.xml")
+            .expect("failed to load test data");
+        let things = deserialize_xml_string::<Things>(&xml).unwrap().things;
+
+        let reordered = xml
+            .replace(
+                r#"type="boardgame" id="312484""#,
+                r#"id="312484" type="boardgame""#,
+            )
+            .replace(
+                r#"type="boardgameaccessory" id="196929""#,
+                r#"id="196929" type="boardgameaccessory""#,
+            );
+        assert_ne!(xml, reordered);
+        let reordered_things = deserialize_xml_string::<Things>(&reordered).unwrap().things;
+
+        assert_eq!(reordered_things, things);
+    }
+}
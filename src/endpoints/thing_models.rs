@@ -0,0 +1,271 @@
+use core::fmt;
+use std::collections::VecDeque;
+
+use serde::de::value::{MapAccessDeserializer, StringDeserializer};
+use serde::de::{DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
+use serde::{forward_to_deserialize_any, Deserialize};
+
+use crate::{AccessoryDetails, AccessoryId, GameDetails, GameId};
+
+// A struct containing the list of requested things, of any supported type.
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct Things {
+    // List of things.
+    #[serde(default, rename = "item")]
+    pub(crate) things: Vec<Thing>,
+}
+
+/// An item returned by the `thing` endpoint, which can be one of several types of item depending
+/// on its `type` attribute.
+///
+/// More types of item may be added in future, so matching on this needs a wildcard arm. The details
+/// of each item are boxed, since they are large and vary in size between types.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Thing {
+    /// A board game or board game expansion.
+    Game(Box<GameDetails>),
+    /// A board game accessory.
+    Accessory(Box<AccessoryDetails>),
+}
+
+/// The ID of an item that can be requested from the `thing` endpoint, along with which type of
+/// item it is the ID of.
+///
+/// Displayed as the bare number, the same as the underlying ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum ThingId {
+    /// The ID of a board game or board game expansion.
+    Game(GameId),
+    /// The ID of a board game accessory.
+    Accessory(AccessoryId),
+}
+
+impl fmt::Display for ThingId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThingId::Game(id) => id.fmt(f),
+            ThingId::Accessory(id) => id.fmt(f),
+        }
+    }
+}
+
+impl From<GameId> for ThingId {
+    fn from(id: GameId) -> Self {
+        ThingId::Game(id)
+    }
+}
+
+impl From<AccessoryId> for ThingId {
+    fn from(id: AccessoryId) -> Self {
+        ThingId::Accessory(id)
+    }
+}
+
+impl Thing {
+    /// The ID of the item, which is a [`GameId`] for a game and an [`AccessoryId`] for an
+    /// accessory.
+    pub fn id(&self) -> ThingId {
+        match self {
+            Thing::Game(game) => ThingId::Game(game.id),
+            Thing::Accessory(accessory) => ThingId::Accessory(accessory.id),
+        }
+    }
+
+    /// The primary name of the item.
+    pub fn name(&self) -> &str {
+        match self {
+            Thing::Game(game) => &game.name,
+            Thing::Accessory(accessory) => &accessory.name,
+        }
+    }
+
+    /// The details of the item if it is a game or expansion.
+    pub fn as_game(&self) -> Option<&GameDetails> {
+        match self {
+            Thing::Game(game) => Some(game),
+            Thing::Accessory(_) => None,
+        }
+    }
+
+    /// The details of the item if it is an accessory.
+    pub fn as_accessory(&self) -> Option<&AccessoryDetails> {
+        match self {
+            Thing::Accessory(accessory) => Some(accessory),
+            Thing::Game(_) => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Thing {
+    fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ThingVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ThingVisitor {
+            type Value = Thing;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(
+                    "an XML object for an item returned by the `thing` endpoint from boardgamegeek",
+                )
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                // The type has to be known before choosing which model to parse the rest of the
+                // item as. Attributes always come before child elements, so any attributes before
+                // the type are kept and then passed on again along with the type, since the models
+                // also read it.
+                let mut attributes = VecDeque::new();
+                let item_type = loop {
+                    let Some(key) = map.next_key::<String>()? else {
+                        return Err(serde::de::Error::missing_field("type"));
+                    };
+                    if !key.starts_with('@') {
+                        return Err(serde::de::Error::missing_field("type"));
+                    }
+                    let value: String = map.next_value()?;
+                    if key == "@type" {
+                        attributes.push_back((key, value.clone()));
+                        break value;
+                    }
+                    attributes.push_back((key, value));
+                };
+                let map = WithAttributes {
+                    attributes,
+                    pending_value: None,
+                    map,
+                };
+
+                match item_type.as_str() {
+                    "boardgame" | "boardgameexpansion" => {
+                        GameDetails::deserialize(MapAccessDeserializer::new(map))
+                            .map(|game| Thing::Game(Box::new(game)))
+                    },
+                    "boardgameaccessory" => {
+                        AccessoryDetails::deserialize(MapAccessDeserializer::new(map))
+                            .map(|accessory| Thing::Accessory(Box::new(accessory)))
+                    },
+                    other => Err(serde::de::Error::unknown_variant(
+                        other,
+                        &["boardgame", "boardgameexpansion", "boardgameaccessory"],
+                    )),
+                }
+            }
+        }
+
+        // Only the attributes are read before the rest of the item is passed on, so only they
+        // need to be listed.
+        const FIELDS: &[&str] = &["@type", "@id"];
+        deserializer.deserialize_struct("Thing", FIELDS, ThingVisitor)
+    }
+}
+
+// Map access for the rest of an item after its type has been read, which first gives back the
+// attributes that were read while looking for the type.
+struct WithAttributes<A> {
+    attributes: VecDeque<(String, String)>,
+    pending_value: Option<String>,
+    map: A,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for WithAttributes<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if let Some((key, value)) = self.attributes.pop_front() {
+            self.pending_value = Some(value);
+            return seed
+                .deserialize(StringDeserializer::<Self::Error>::new(key))
+                .map(Some);
+        }
+        self.map.next_key_seed(seed)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        if let Some(value) = self.pending_value.take() {
+            return seed.deserialize(AttributeDeserializer {
+                value,
+                marker: std::marker::PhantomData,
+            });
+        }
+        self.map.next_value_seed(seed)
+    }
+}
+
+// Deserializer for an attribute value that has already been read as a string, which parses it
+// again for numeric types the same as the underlying deserializers do.
+struct AttributeDeserializer<E> {
+    value: String,
+    marker: std::marker::PhantomData<E>,
+}
+
+impl<E: serde::de::Error> AttributeDeserializer<E> {
+    fn parse<T: std::str::FromStr>(&self) -> Result<T, E>
+    where
+        T::Err: fmt::Display,
+    {
+        self.value.trim().parse().map_err(E::custom)
+    }
+}
+
+impl<'de, E: serde::de::Error> serde::Deserializer<'de> for AttributeDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.value)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_u64(self.parse()?)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_i64(self.parse()?)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_f64(self.parse()?)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.value.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 u8 u16 u32 f32 char str string bytes byte_buf option unit unit_struct seq
+        tuple tuple_struct map struct identifier ignored_any
+    }
+}
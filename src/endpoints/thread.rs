@@ -1,6 +1,6 @@
 use chrono::{NaiveDate, NaiveDateTime};
 
-use crate::{BoardGameGeekApi, IntoQueryParam, QueryParam, Result, Thread};
+use crate::{BoardGameGeekApi, IntoQueryParam, QueryParam, Result, Thread, ThreadId};
 
 #[derive(Clone, Copy, Debug)]
 enum DateOrDateTimeParam {
@@ -62,12 +62,12 @@ impl ThreadQueryParams {
 
 #[derive(Clone, Debug)]
 struct ThreadQueryBuilder<'builder> {
-    thread_id: u64,
+    thread_id: ThreadId,
     params: &'builder ThreadQueryParams,
 }
 
 impl<'builder> ThreadQueryBuilder<'builder> {
-    fn new(thread_id: u64, params: &'builder ThreadQueryParams) -> Self {
+    fn new(thread_id: ThreadId, params: &'builder ThreadQueryParams) -> Self {
         Self { thread_id, params }
     }
 
@@ -103,7 +103,11 @@ impl<'api> ThreadApi<'api> {
     }
 
     /// Get a thread by ID, with optional query params.
-    pub async fn get(&self, thread_id: u64, query_params: &ThreadQueryParams) -> Result<Thread> {
+    pub async fn get(
+        &self,
+        thread_id: ThreadId,
+        query_params: &ThreadQueryParams,
+    ) -> Result<Thread> {
         let query = ThreadQueryBuilder::new(thread_id, query_params);
 
         let request = self.api.build_request(self.endpoint, &query.build());
@@ -115,10 +119,15 @@ impl<'api> ThreadApi<'api> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{DateTime, NaiveDate, Utc};
     use mockito::Matcher;
 
-    use crate::{BoardGameGeekApi, Thread, ThreadPost, ThreadQueryParams};
+    use crate::{
+        BoardGameGeekApi, Domain, PostId, StaticTokenProvider, Thread, ThreadId, ThreadPost,
+        ThreadQueryParams,
+    };
 
     #[tokio::test]
     async fn get() {
@@ -126,6 +135,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -142,7 +153,10 @@ mod tests {
             .create_async()
             .await;
 
-        let thread = api.thread().get(3_707_929, &ThreadQueryParams::new()).await;
+        let thread = api
+            .thread()
+            .get(ThreadId(3_707_929), &ThreadQueryParams::new())
+            .await;
         mock.assert_async().await;
 
         dbg!(&thread);
@@ -153,13 +167,13 @@ mod tests {
         assert_eq!(
             thread,
             Thread {
-                id: 3_707_929,
+                id: ThreadId(3_707_929),
                 number_of_articles: 25,
                 link: "https://boardgamegeek.com/thread/3707929".to_owned(),
                 subject: "Do you prefer video or text reviews?".to_owned(),
                 posts: vec![
                     ThreadPost {
-                        id: 47_675_007,
+                        id: PostId(47_675_007),
                         username: "SiarX".to_owned(),
                         link: "https://boardgamegeek.com/thread/3707929/article/47675007#47675007"
                             .to_owned(),
@@ -182,14 +196,14 @@ mod tests {
                         body: "Which format you generally prefer? Video because it is more informative, text because it is faster to consume, or some other reason?".to_owned(),
                     },
                     ThreadPost {
-                        id: 47_675_065,
+                        id: PostId(47_675_065),
                         username: "qrayx".to_owned(),
                         link: "https://boardgamegeek.com/thread/3707929/article/47675065#47675065"
                             .to_owned(),
                         post_date: DateTime::from_naive_utc_and_offset(
                             NaiveDate::from_ymd_opt(2026, 5, 13)
                                 .unwrap()
-                                .and_hms_opt(17, 45, 06)
+                                .and_hms_opt(17, 45, 6)
                                 .unwrap(),
                             Utc,
                         ),
@@ -215,6 +229,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -237,7 +253,7 @@ mod tests {
             .min_post_date(NaiveDate::from_ymd_opt(2025, 5, 10).unwrap())
             .min_post_id(556)
             .post_count(10);
-        let thread = api.thread().get(3_707_929, &params).await;
+        let thread = api.thread().get(ThreadId(3_707_929), &params).await;
         mock.assert_async().await;
 
         assert!(thread.is_ok(), "error returned when okay expected");
@@ -249,6 +265,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -279,7 +297,7 @@ mod tests {
             )
             .min_post_id(556)
             .post_count(10);
-        let thread = api.thread().get(3_707_929, &params).await;
+        let thread = api.thread().get(ThreadId(3_707_929), &params).await;
         mock.assert_async().await;
 
         assert!(thread.is_ok(), "error returned when okay expected");
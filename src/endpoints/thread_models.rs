@@ -1,17 +1,20 @@
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
+use crate::{PostId, ThreadId};
+
 /// A thread in a forum, contains a subject and posts made to the thread.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Thread {
     /// ID of the thread.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: ThreadId,
     /// The number of articles in the thread.
-    #[serde(rename = "@numarticles")]
+    #[serde(rename(deserialize = "@numarticles"))]
     pub number_of_articles: u64,
     /// Link to the thread in boardgamegeek.com.
-    #[serde(rename = "@link")]
+    #[serde(rename(deserialize = "@link"))]
     pub link: String,
     /// Topic for the thread.
     pub subject: String,
@@ -19,7 +22,7 @@ pub struct Thread {
     /// posts, or start at the start.
     #[serde(
         default = "Vec::new",
-        rename = "articles",
+        rename(deserialize = "articles"),
         deserialize_with = "deserialize_nested_thread_posts_list"
     )]
     pub posts: Vec<ThreadPost>,
@@ -27,24 +30,25 @@ pub struct Thread {
 
 /// A post on a thread.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ThreadPost {
     /// ID of the post.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: PostId,
     /// The username of the user who posted.
-    #[serde(rename = "@username")]
+    #[serde(rename(deserialize = "@username"))]
     pub username: String,
     /// Link to the post in boardgamegeek.com.
-    #[serde(rename = "@link")]
+    #[serde(rename(deserialize = "@link"))]
     pub link: String,
     /// The date the post was posted.
-    #[serde(rename = "@postdate")]
+    #[serde(rename(deserialize = "@postdate"))]
     pub post_date: DateTime<Utc>,
     /// The date of the most recent edit, same as post date if no edits..
-    #[serde(rename = "@editdate")]
+    #[serde(rename(deserialize = "@editdate"))]
     pub edit_date: DateTime<Utc>,
     /// The number of times this post has been edited
-    #[serde(rename = "@numedits")]
+    #[serde(rename(deserialize = "@numedits"))]
     pub number_of_edits: u64,
     /// The subject, which is often the same as the thread subject for the first post, and "Re:
     /// that subject" for the rest.
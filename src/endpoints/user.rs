@@ -1,5 +1,5 @@
 use super::User;
-use crate::{BoardGameGeekApi, IntoQueryParam, QueryParam, Result};
+use crate::{BoardGameGeekApi, Domain, IntoQueryParam, QueryParam, Result};
 
 /// All optional query parameters for making a request to the user endpoint.
 #[derive(Clone, Debug, Default)]
@@ -33,6 +33,9 @@ impl UserQueryParams {
 
     /// Sets the `include_top_list` query parameter. If set to true the user's list of top
     /// games will be included in the result.
+    ///
+    /// The list is for the [`Domain`] that the API is set to, so is the user's top RPG items or
+    /// video games for those domains.
     pub fn include_top_list(mut self, include_top_list: bool) -> Self {
         self.include_top_list = Some(include_top_list);
         self
@@ -40,6 +43,8 @@ impl UserQueryParams {
 
     /// Sets the `include_hot_list` query parameter. If set to true the user's list of hot
     /// games will be included in the result.
+    ///
+    /// As with the top list, the list is for the [`Domain`] that the API is set to.
     pub fn include_hot_list(mut self, include_hot_list: bool) -> Self {
         self.include_hot_list = Some(include_hot_list);
         self
@@ -58,19 +63,23 @@ impl UserQueryParams {
 struct UserQueryBuilder<'builder> {
     username: &'builder str,
     params: &'builder UserQueryParams,
+    domain: Domain,
 }
 
 impl<'builder> UserQueryBuilder<'builder> {
-    fn new(username: &'builder str, params: &'builder UserQueryParams) -> Self {
-        Self { username, params }
+    fn new(username: &'builder str, params: &'builder UserQueryParams, domain: Domain) -> Self {
+        Self {
+            username,
+            params,
+            domain,
+        }
     }
 
     fn build(self) -> Vec<QueryParam<'builder>> {
         let mut query_params: Vec<_> = vec![];
         query_params.push(self.username.into_query_param("name"));
-        // Set the domain as board game. The internal API allows for rpg and
-        // video game to be allowed here but we hide these.
-        query_params.push("boardgame".into_query_param("domain"));
+        // The domain decides which items are included in the user's top and hot lists.
+        query_params.push(self.domain.into_query_param("domain"));
 
         if let Some(include_buddies) = self.params.include_buddies {
             query_params.push(include_buddies.into_query_param("buddies"));
@@ -107,7 +116,7 @@ impl<'api> UserApi<'api> {
 
     /// Get a user by their username.
     pub async fn get(&self, username: &str, query_params: &UserQueryParams) -> Result<User> {
-        let query = UserQueryBuilder::new(username, query_params);
+        let query = UserQueryBuilder::new(username, query_params, self.api.domain);
 
         let request = self.api.build_request(self.endpoint, &query.build());
         let response = self.api.execute_request::<User>(request).await?;
@@ -118,11 +127,16 @@ impl<'api> UserApi<'api> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::NaiveDate;
     use mockito::Matcher;
 
     use super::*;
-    use crate::{Buddy, BuddyList, GuildBrief, GuildList, ListItem, ListItemType};
+    use crate::{
+        Buddy, BuddyList, Domain, GameId, GuildBrief, GuildId, GuildList, ListItem, ListItemType,
+        StaticTokenProvider, UserId,
+    };
 
     #[tokio::test]
     async fn get() {
@@ -130,6 +144,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -155,7 +171,7 @@ mod tests {
         assert_eq!(
             user,
             User {
-                id: 3_855_477,
+                id: UserId(3_855_477),
                 username: "BluebearBGG".to_owned(),
                 first_name: "Matthew".to_owned(),
                 last_name: "".to_owned(),
@@ -193,6 +209,8 @@ mod tests {
         let api = BoardGameGeekApi {
             base_url: server.url(),
             client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
         };
 
         let mock = server
@@ -229,7 +247,7 @@ mod tests {
         assert_eq!(
             user,
             User {
-                id: 3_855_477,
+                id: UserId(3_855_477),
                 username: "BluebearBGG".to_owned(),
                 first_name: "Matthew".to_owned(),
                 last_name: "".to_owned(),
@@ -247,37 +265,37 @@ mod tests {
                 trade_rating: 0,
                 top_list: vec![
                     ListItem {
-                        id: 338_960,
+                        id: GameId(338_960),
                         name: "Slay the Spire: The Board Game".to_owned(),
                         rank: 1,
                         item_type: ListItemType::Thing,
                     },
                     ListItem {
-                        id: 103_500,
+                        id: GameId(103_500),
                         name: "Bobby Hill".to_owned(),
                         rank: 2,
                         item_type: ListItemType::Person,
                     },
                     ListItem {
-                        id: 5774,
+                        id: GameId(5774),
                         name: "Bézier Games".to_owned(),
                         rank: 3,
                         item_type: ListItemType::Company,
                     },
                     ListItem {
-                        id: 79006,
+                        id: GameId(79006),
                         name: "Components:  Meeples".to_owned(),
                         rank: 4,
                         item_type: ListItemType::Family,
                     },
                     ListItem {
-                        id: 2082,
+                        id: GameId(2082),
                         name: "Worker Placement".to_owned(),
                         rank: 5,
                         item_type: ListItemType::Property,
                     },
                     ListItem {
-                        id: 38,
+                        id: GameId(38),
                         name: "Gen Con 2020".to_owned(),
                         rank: 6,
                         item_type: ListItemType::Event,
@@ -285,13 +303,13 @@ mod tests {
                 ],
                 hot_list: vec![
                     ListItem {
-                        id: 454_971,
+                        id: GameId(454_971),
                         name: "Slay the Spire: The Board Game – Downfall".to_owned(),
                         rank: 1,
                         item_type: ListItemType::Thing,
                     },
                     ListItem {
-                        id: 2082,
+                        id: GameId(2082),
                         name: "Worker Placement".to_owned(),
                         rank: 2,
                         item_type: ListItemType::Property,
@@ -301,7 +319,7 @@ mod tests {
                     total: 1,
                     page: 1,
                     buddies: vec![Buddy {
-                        id: 379_939,
+                        id: UserId(379_939),
                         name: "happy_squid".to_owned(),
                     },],
                 },
@@ -310,11 +328,11 @@ mod tests {
                     page: 1,
                     guilds: vec![
                         GuildBrief {
-                            id: 1062,
+                            id: GuildId(1062),
                             name: "Kickstarter Games".to_owned(),
                         },
                         GuildBrief {
-                            id: 999_999,
+                            id: GuildId(999_999),
                             name: "A guild".to_owned(),
                         },
                     ],
@@ -4,12 +4,14 @@ use chrono::NaiveDate;
 use serde::Deserialize;
 
 use crate::deserialize::{XmlDateValue, XmlIntValue, XmlSignedValue, XmlStringValue};
+use crate::{GameId, GuildId, UserId};
 
 /// A user's information.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct User {
     /// The ID of the user.
-    pub id: u64,
+    pub id: UserId,
     /// The username of the user.
     pub username: String,
     /// The first name of the user.
@@ -59,25 +61,27 @@ struct ItemList {
 /// An item in a user made top 10 or hot 10 list. A brief representation of an item which
 /// contains a name type and rank.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ListItem {
     /// The ID of this item.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: GameId,
     /// The name of the game or person. It can also be a name of a mechanic such as worker
     /// placement.
-    #[serde(rename = "@name")]
+    #[serde(rename(deserialize = "@name"))]
     pub name: String,
     /// A number 1 through 10, with 1 being top of the list.
-    #[serde(rename = "@rank")]
+    #[serde(rename(deserialize = "@rank"))]
     pub rank: u64,
     /// The type of item, which may be a game, person, event.
-    #[serde(rename = "@type")]
+    #[serde(rename(deserialize = "@type"))]
     pub item_type: ListItemType,
 }
 
 /// A type of item in a user's top 10, or hot 10 list on their profile.
 /// Note that when choosing items on the website
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[serde(rename_all = "lowercase")]
 pub enum ListItemType {
     /// A board game, board game expansion, or board game accessory.
@@ -99,52 +103,56 @@ pub enum ListItemType {
 /// A single page of guilds the user belongs to, also includes the total number of guilds the
 /// user belongs to.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GuildList {
     /// The total number of guilds this user belongs to.
-    #[serde(rename = "@total")]
+    #[serde(rename(deserialize = "@total"))]
     pub total: u64,
     /// The page number for the guilds in the list.
-    #[serde(rename = "@page")]
+    #[serde(rename(deserialize = "@page"))]
     pub page: u64,
     /// The list of guilds.
-    #[serde(default, rename = "guild")]
+    #[serde(default, rename(deserialize = "guild"))]
     pub guilds: Vec<GuildBrief>,
 }
 
 /// A guild a user belongs to, including only the name and ID.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct GuildBrief {
     /// The ID of the guild.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: GuildId,
     /// The name of the guild that the user belongs to.
-    #[serde(rename = "@name")]
+    #[serde(rename(deserialize = "@name"))]
     pub name: String,
 }
 
 /// A single page of buddies that the users has, also includes the total number of buddies
 /// the user has.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct BuddyList {
     /// The total number of buddies this user has.
-    #[serde(rename = "@total")]
+    #[serde(rename(deserialize = "@total"))]
     pub total: u64,
     /// The page number for the buddies in the list.
-    #[serde(rename = "@page")]
+    #[serde(rename(deserialize = "@page"))]
     pub page: u64,
     /// The list of buddies.
-    #[serde(default, rename = "buddy")]
+    #[serde(default, rename(deserialize = "buddy"))]
     pub buddies: Vec<Buddy>,
 }
 
 /// A user that this user is buddies with.
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Buddy {
     /// The ID of the user.
-    #[serde(rename = "@id")]
-    pub id: u64,
+    #[serde(rename(deserialize = "@id"))]
+    pub id: UserId,
     /// The user's username.
-    #[serde(rename = "@name")]
+    #[serde(rename(deserialize = "@name"))]
     pub name: String,
 }
 
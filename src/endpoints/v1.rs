@@ -0,0 +1,318 @@
+use chrono::NaiveDate;
+
+use crate::{
+    BoardGameGeekApi, Domain, Error, GameId, GeekListApi, HistoricalRatings, IntoQueryParam,
+    QueryParam, Result, XmlV1BoardGame, XmlV1BoardGames,
+};
+
+/// All optional query parameters for making a request for historical ratings.
+#[derive(Clone, Debug, Default)]
+pub struct HistoricalRatingsQueryParams {
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    page: Option<u64>,
+}
+
+impl HistoricalRatingsQueryParams {
+    /// Construct a default `HistoricalRatingsQueryParams` with no parameters set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `from` parameter. Will only return ratings from this date onwards.
+    pub fn from(mut self, from: NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` parameter. Will only return ratings from up to this date.
+    pub fn to(mut self, to: NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets both the `from` and `to` parameters. Will only return ratings from within this range
+    /// of dates.
+    pub fn date_range(self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.from(from).to(to)
+    }
+
+    /// The page of ratings to return, if unset defaults to the first page. Long date ranges are
+    /// split over multiple pages by the underlying API.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HistoricalRatingsQueryBuilder<'builder> {
+    params: &'builder HistoricalRatingsQueryParams,
+}
+
+impl<'builder> HistoricalRatingsQueryBuilder<'builder> {
+    fn new(params: &'builder HistoricalRatingsQueryParams) -> Self {
+        Self { params }
+    }
+
+    fn build(self) -> Vec<QueryParam<'builder>> {
+        let mut query_params: Vec<_> = vec![
+            true.into_query_param("stats"),
+            true.into_query_param("historical"),
+        ];
+        if let Some(from) = &self.params.from {
+            query_params.push(from.into_query_param("from"));
+        }
+        if let Some(to) = &self.params.to {
+            query_params.push(to.into_query_param("to"));
+        }
+        if let Some(page) = self.params.page {
+            query_params.push(page.into_query_param("page"));
+        }
+        query_params
+    }
+}
+
+/// Endpoints of the older XML API v1, for data that the current API doesn't provide.
+///
+/// Requests are made to `/xmlapi` rather than `/xmlapi2`, but are otherwise handled the same as
+/// the other endpoints, including their errors.
+pub struct V1Api<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    board_game_endpoint: &'static str,
+}
+
+impl<'api> V1Api<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            board_game_endpoint: "boardgame",
+        }
+    }
+
+    /// Returns the geek list endpoint, which is only available from the XML API v1. This is the
+    /// same as [`BoardGameGeekApi::geek_list`].
+    pub fn geek_list(&self) -> GeekListApi<'api> {
+        GeekListApi::new(self.api)
+    }
+
+    /// Get the ratings of a board game over time, as a snapshot for each day.
+    pub async fn get_historical_ratings(
+        &self,
+        game_id: GameId,
+        query_params: &HistoricalRatingsQueryParams,
+    ) -> Result<HistoricalRatings> {
+        let mut ratings = self
+            .get_historical_ratings_by_ids(&[game_id], query_params)
+            .await?;
+        match ratings.len() {
+            0 => Err(Error::ItemNotFound),
+            1 => Ok(ratings.remove(0)),
+            len => Err(Error::UnexpectedResponseError(format!(
+                "expected 1 game but got {len}",
+            ))),
+        }
+    }
+
+    /// Get the ratings of board games over time, as a snapshot for each day. Games that don't
+    /// exist are left out.
+    pub async fn get_historical_ratings_by_ids(
+        &self,
+        game_ids: &[GameId],
+        query_params: &HistoricalRatingsQueryParams,
+    ) -> Result<Vec<HistoricalRatings>> {
+        // The v1 board game endpoint only has board games, there is no equivalent for the other
+        // domains.
+        if self.api.domain != Domain::BoardGame {
+            return Err(Error::UnsupportedDomain(self.api.domain));
+        }
+        let query = HistoricalRatingsQueryBuilder::new(query_params);
+        let game_ids: Vec<_> = game_ids.iter().map(ToString::to_string).collect();
+
+        let request = self.api.build_v1_request(
+            &format!("{}/{}", self.board_game_endpoint, game_ids.join(",")),
+            &query.build(),
+        );
+        let games = self.api.execute_request::<XmlV1BoardGames>(request).await?;
+
+        Ok(games
+            .games
+            .into_iter()
+            .filter_map(XmlV1BoardGame::into_historical_ratings)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+    use mockito::Matcher;
+
+    use super::*;
+    use crate::{
+        ItemFamilyRank, RankId, RankValue, RatingSnapshot, RatingValue, StaticTokenProvider,
+    };
+
+    #[tokio::test]
+    async fn get_historical_ratings() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/xmlapi/boardgame/312484")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("stats".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("historical".to_owned(), "1".to_owned()),
+                Matcher::UrlEncoded("from".to_owned(), "2026-04-30".to_owned()),
+                Matcher::UrlEncoded("to".to_owned(), "2026-05-01".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/v1/historical_ratings.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let params = HistoricalRatingsQueryParams::new().date_range(
+            NaiveDate::from_ymd_opt(2026, 4, 30).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 5, 1).unwrap(),
+        );
+        let ratings = api
+            .v1()
+            .get_historical_ratings(GameId(312_484), &params)
+            .await;
+        mock.assert_async().await;
+
+        assert!(ratings.is_ok(), "error returned when okay expected");
+        let ratings = ratings.unwrap();
+
+        assert_eq!(
+            ratings,
+            HistoricalRatings {
+                game_id: GameId(312_484),
+                name: "Lost Ruins of Arnak".to_owned(),
+                snapshots: vec![
+                    RatingSnapshot {
+                        date: NaiveDate::from_ymd_opt(2026, 4, 30).unwrap(),
+                        users_rated: 51_000,
+                        average_rating: 8.06,
+                        bayesian_average: 7.87,
+                        standard_deviation: 1.12,
+                        rank: Some(ItemFamilyRank {
+                            id: RankId(1),
+                            name: "boardgame".to_owned(),
+                            friendly_name: "Board Game Rank".to_owned(),
+                            value: RankValue::Ranked(26),
+                            bayesian_average: RatingValue::Rated(7.87),
+                        }),
+                        sub_family_ranks: vec![ItemFamilyRank {
+                            id: RankId(5497),
+                            name: "strategygames".to_owned(),
+                            friendly_name: "Strategy Game Rank".to_owned(),
+                            value: RankValue::Ranked(28),
+                            bayesian_average: RatingValue::Rated(7.86),
+                        }],
+                        users_owned: 78_000,
+                        users_trading: 800,
+                        users_want_in_trade: 1200,
+                        users_wishlisted: 12_000,
+                        number_of_comments: 7500,
+                        number_of_weights: 2100,
+                        weight_rating: 2.89,
+                    },
+                    RatingSnapshot {
+                        date: NaiveDate::from_ymd_opt(2026, 5, 1).unwrap(),
+                        users_rated: 51_012,
+                        average_rating: 8.061,
+                        bayesian_average: 7.871,
+                        standard_deviation: 1.121,
+                        rank: Some(ItemFamilyRank {
+                            id: RankId(1),
+                            name: "boardgame".to_owned(),
+                            friendly_name: "Board Game Rank".to_owned(),
+                            value: RankValue::Ranked(25),
+                            bayesian_average: RatingValue::Rated(7.871),
+                        }),
+                        sub_family_ranks: vec![],
+                        users_owned: 78_010,
+                        users_trading: 801,
+                        users_want_in_trade: 1201,
+                        users_wishlisted: 12_004,
+                        number_of_comments: 7502,
+                        number_of_weights: 2101,
+                        weight_rating: 2.89,
+                    },
+                ],
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn get_historical_ratings_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/xmlapi/boardgame/99999999")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/v1/historical_ratings_not_found.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let ratings = api
+            .v1()
+            .get_historical_ratings(GameId(99_999_999), &HistoricalRatingsQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(ratings, Err(Error::ItemNotFound)),
+            "expected item not found error but got {ratings:?}",
+        );
+    }
+
+    #[tokio::test]
+    async fn get_historical_ratings_unsupported_domain() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::VideoGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let ratings = api
+            .v1()
+            .get_historical_ratings(GameId(312_484), &HistoricalRatingsQueryParams::new())
+            .await;
+        mock.assert_async().await;
+
+        assert!(
+            matches!(ratings, Err(Error::UnsupportedDomain(Domain::VideoGame))),
+            "expected unsupported domain error but got {ratings:?}",
+        );
+    }
+}
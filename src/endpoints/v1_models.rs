@@ -0,0 +1,176 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::deserialize::{ItemFamilyType, XmlRanks};
+use crate::{GameId, ItemFamilyRank};
+
+/// The ratings of a game over time, returned from the older XML API v1.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct HistoricalRatings {
+    /// The ID of the game.
+    pub game_id: GameId,
+    /// The primary name of the game.
+    pub name: String,
+    /// A snapshot of the game's ratings for each day in the requested range, oldest first.
+    pub snapshots: Vec<RatingSnapshot>,
+}
+
+/// The ratings of a game as they were on a particular day.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct RatingSnapshot {
+    /// The day that the ratings were recorded.
+    pub date: NaiveDate,
+    /// The number of users who had rated the game.
+    pub users_rated: u64,
+    /// The average rating from users, from 0-10.
+    pub average_rating: f64,
+    /// The score out of 10, as a bayesian average.
+    ///
+    /// This is what boardgamegeek calls a Geek Rating. It is the average rating
+    /// that the users have given it along with a few thousand 5.5 ratings added
+    /// in too.
+    pub bayesian_average: f64,
+    /// The standard deviation of the ratings.
+    pub standard_deviation: f64,
+    /// The rank of the game amongst all games, if ranks were recorded on this day.
+    pub rank: Option<ItemFamilyRank>,
+    /// The ranks of the game within various game families, such as family or strategy games.
+    pub sub_family_ranks: Vec<ItemFamilyRank>,
+    /// The number of users who owned the game.
+    pub users_owned: u64,
+    /// The number of users who were trading away the game.
+    pub users_trading: u64,
+    /// The number of users who wanted to get the game in a trade.
+    pub users_want_in_trade: u64,
+    /// The number of users who had the game on their wishlist.
+    pub users_wishlisted: u64,
+    /// The number of comments made by users on the game.
+    pub number_of_comments: u64,
+    /// The total number of weight ratings given to the game by users.
+    pub number_of_weights: u64,
+    /// The average weight score given to the game, from 0-5.
+    pub weight_rating: f64,
+}
+
+// The root of a v1 board game response.
+#[derive(Debug, Deserialize)]
+pub(crate) struct XmlV1BoardGames {
+    #[serde(default, rename = "boardgame")]
+    pub(crate) games: Vec<XmlV1BoardGame>,
+}
+
+// A board game in a v1 response. Only the fields needed for historical ratings are read.
+#[derive(Debug, Deserialize)]
+pub(crate) struct XmlV1BoardGame {
+    #[serde(rename = "@objectid")]
+    id: GameId,
+    #[serde(default, rename = "name")]
+    names: Vec<XmlV1Name>,
+    // Set in place of the rest of the game when the game doesn't exist.
+    #[serde(default)]
+    error: Option<XmlV1Error>,
+    #[serde(default)]
+    statistics: Option<XmlV1Statistics>,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlV1Name {
+    #[serde(default, rename = "@primary")]
+    primary: Option<String>,
+    #[serde(default, rename = "#text")]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct XmlV1Error {}
+
+#[derive(Debug, Deserialize)]
+struct XmlV1Statistics {
+    #[serde(default)]
+    ratings: Vec<XmlV1Ratings>,
+}
+
+// The ratings of a game on a day. Unlike the current API the values are the text of each element,
+// rather than a `value` attribute.
+#[derive(Debug, Deserialize)]
+struct XmlV1Ratings {
+    #[serde(rename = "@date")]
+    date: NaiveDate,
+    usersrated: u64,
+    average: f64,
+    bayesaverage: f64,
+    stddev: f64,
+    #[serde(default)]
+    ranks: Option<XmlRanks>,
+    owned: u64,
+    trading: u64,
+    wanting: u64,
+    wishing: u64,
+    numcomments: u64,
+    numweights: u64,
+    averageweight: f64,
+}
+
+impl XmlV1BoardGame {
+    // Converts the game to its historical ratings, or None if the game wasn't found.
+    pub(crate) fn into_historical_ratings(self) -> Option<HistoricalRatings> {
+        if self.error.is_some() {
+            return None;
+        }
+        let name = self
+            .names
+            .iter()
+            .find(|name| name.primary.as_deref() == Some("true"))
+            .or_else(|| self.names.first())
+            .map(|name| name.name.clone())
+            .unwrap_or_default();
+        let snapshots = self
+            .statistics
+            .map(|statistics| {
+                statistics
+                    .ratings
+                    .into_iter()
+                    .map(RatingSnapshot::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(HistoricalRatings {
+            game_id: self.id,
+            name,
+            snapshots,
+        })
+    }
+}
+
+impl From<XmlV1Ratings> for RatingSnapshot {
+    fn from(ratings: XmlV1Ratings) -> Self {
+        // Older snapshots can be missing ranks, so unlike the current API a missing overall rank
+        // isn't an error.
+        let mut rank = None;
+        let mut sub_family_ranks = vec![];
+        for family_rank in ratings.ranks.map(|ranks| ranks.ranks).unwrap_or_default() {
+            match family_rank.game_family_type {
+                ItemFamilyType::Subtype => rank = Some(ItemFamilyRank::from(family_rank)),
+                ItemFamilyType::Family => sub_family_ranks.push(ItemFamilyRank::from(family_rank)),
+            }
+        }
+        Self {
+            date: ratings.date,
+            users_rated: ratings.usersrated,
+            average_rating: ratings.average,
+            bayesian_average: ratings.bayesaverage,
+            standard_deviation: ratings.stddev,
+            rank,
+            sub_family_ranks,
+            users_owned: ratings.owned,
+            users_trading: ratings.trading,
+            users_want_in_trade: ratings.wanting,
+            users_wishlisted: ratings.wishing,
+            number_of_comments: ratings.numcomments,
+            number_of_weights: ratings.numweights,
+            weight_rating: ratings.averageweight,
+        }
+    }
+}
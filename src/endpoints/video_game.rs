@@ -0,0 +1,141 @@
+use super::{parse_raw_items, ItemType, VideoGame};
+use crate::api::parse_response_with;
+use crate::{BoardGameGeekApi, Error, IntoQueryParam, QueryParam, Result, VideoGameId};
+
+#[derive(Clone, Debug)]
+struct VideoGameQueryBuilder<'builder> {
+    video_game_ids: &'builder [VideoGameId],
+}
+
+impl<'builder> VideoGameQueryBuilder<'builder> {
+    fn new(video_game_ids: &'builder [VideoGameId]) -> Self {
+        Self { video_game_ids }
+    }
+
+    fn build(self) -> Vec<QueryParam<'builder>> {
+        vec![
+            ItemType::VideoGame.into_query_param("type"),
+            self.video_game_ids.into_query_param("id"),
+        ]
+    }
+}
+
+/// Video game endpoint of the API. Used for retrieving video games from Video Game Geek by their
+/// IDs.
+///
+/// This uses the same underlying "thing" API endpoint as the game and accessory endpoints, and
+/// can be used regardless of the [`crate::Domain`] that the API is set to.
+pub struct VideoGameApi<'api> {
+    pub(crate) api: &'api BoardGameGeekApi,
+    endpoint: &'static str,
+}
+
+impl<'api> VideoGameApi<'api> {
+    pub(crate) fn new(api: &'api BoardGameGeekApi) -> Self {
+        Self {
+            api,
+            endpoint: "thing",
+        }
+    }
+
+    /// Gets a video game by ID.
+    pub async fn get_by_id(&self, id: VideoGameId) -> Result<VideoGame> {
+        let mut video_games = self.get_by_ids(&[id]).await?;
+
+        match video_games.len() {
+            0 => Err(Error::ItemNotFound),
+            1 => Ok(video_games.remove(0)),
+            len => Err(Error::UnexpectedResponseError(format!(
+                "expected 1 video game but got {len}",
+            ))),
+        }
+    }
+
+    /// Gets video games by their IDs, up to a max of 20 at once.
+    pub async fn get_by_ids(&self, ids: &[VideoGameId]) -> Result<Vec<VideoGame>> {
+        let query = VideoGameQueryBuilder::new(ids);
+
+        let request = self.api.build_request(self.endpoint, &query.build());
+        let text = self.api.execute_raw_request(request).await?;
+
+        parse_response_with(&text, |xml| {
+            parse_raw_items(xml)?
+                .iter()
+                .map(VideoGame::from_raw)
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+    use mockito::Matcher;
+
+    use crate::{
+        BoardGameGeekApi, Domain, LinkedItem, ObjectId, StaticTokenProvider, VideoGame, VideoGameId,
+    };
+
+    fn linked_item(id: u64, name: &str) -> LinkedItem {
+        LinkedItem {
+            id: ObjectId(id),
+            name: name.to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_by_id() {
+        let mut server = mockito::Server::new_async().await;
+        let api = BoardGameGeekApi {
+            base_url: server.url(),
+            client: reqwest::Client::new(),
+            domain: Domain::BoardGame,
+            token_provider: Arc::new(StaticTokenProvider::new("my_auth_token")),
+        };
+
+        let mock = server
+            .mock("GET", "/thing")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("type".to_owned(), "videogame".to_owned()),
+                Matcher::UrlEncoded("id".to_owned(), "144138".to_owned()),
+            ]))
+            .with_status(200)
+            .with_body(
+                std::fs::read_to_string("test_data/video_game/video_game.xml")
+                    .expect("failed to load test data"),
+            )
+            .create_async()
+            .await;
+
+        let video_game = api.video_game().get_by_id(VideoGameId(144_138)).await;
+        mock.assert_async().await;
+
+        assert!(video_game.is_ok(), "error returned when okay expected");
+        let video_game = video_game.unwrap();
+
+        assert_eq!(
+            video_game,
+            VideoGame {
+                id: VideoGameId(144_138),
+                name: "The Legend of Zelda: Breath of the Wild".to_owned(),
+                alternate_names: vec!["Zelda no Densetsu: Breath of the Wild".to_owned()],
+                description: "Step into a world of discovery, exploration and adventure.".to_owned(),
+                image: Some("https://cf.geekdo-images.com/aF4rDJQ8y0gs3sBQBn7ZWQ__original/img/4qx2UaG9dGY8mvm3RJn0tYyzhnA=/0x0/filters:format(jpeg)/pic3409140.jpg".to_owned()),
+                thumbnail: Some("https://cf.geekdo-images.com/aF4rDJQ8y0gs3sBQBn7ZWQ__thumb/img/8c3F9mAhGi5zYb4Vxh9J1VRJbyw=/fit-in/200x150/filters:strip_icc()/pic3409140.jpg".to_owned()),
+                release_date: NaiveDate::from_ymd_opt(2017, 3, 3),
+                platforms: vec![
+                    linked_item(69463, "Nintendo Switch"),
+                    linked_item(57508, "Nintendo Wii U"),
+                ],
+                genres: vec![linked_item(5492, "Action Adventure")],
+                themes: vec![linked_item(5564, "Fantasy")],
+                franchises: vec![linked_item(30452, "The Legend of Zelda")],
+                series: vec![],
+                developers: vec![linked_item(6066, "Nintendo EPD")],
+                publishers: vec![linked_item(4618, "Nintendo")],
+            },
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+
+use super::RawItem;
+use crate::{description_paragraphs, LinkedItem, VideoGameId};
+
+/// A video game from Video Game Geek.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct VideoGame {
+    /// The ID of the video game.
+    pub id: VideoGameId,
+    /// The name of the video game.
+    pub name: String,
+    /// A list of alternate names for the video game, often translations.
+    pub alternate_names: Vec<String>,
+    /// A description of the video game.
+    pub description: String,
+    /// A link to a jpg image for the video game.
+    pub image: Option<String>,
+    /// A link to a jpg thumbnail image for the video game.
+    pub thumbnail: Option<String>,
+    /// The date the video game was first released, if known.
+    pub release_date: Option<NaiveDate>,
+    /// The platforms the video game was released on, such as consoles or operating systems.
+    pub platforms: Vec<LinkedItem>,
+    /// The genres of the video game, such as platformer or strategy.
+    pub genres: Vec<LinkedItem>,
+    /// The themes of the video game, such as science fiction.
+    pub themes: Vec<LinkedItem>,
+    /// The franchises that the video game is part of.
+    pub franchises: Vec<LinkedItem>,
+    /// The series that the video game is part of.
+    pub series: Vec<LinkedItem>,
+    /// The developers of the video game.
+    pub developers: Vec<LinkedItem>,
+    /// The publishers of the video game.
+    pub publishers: Vec<LinkedItem>,
+}
+
+impl VideoGame {
+    /// The paragraphs of the video game's description, see [`description_paragraphs`].
+    pub fn description_paragraphs(&self) -> Vec<&str> {
+        description_paragraphs(&self.description)
+    }
+
+    pub(crate) fn from_raw(item: &RawItem) -> Result<Self, serde_xml_rs::Error> {
+        if item.item_type != "videogame" {
+            return Err(serde::de::Error::custom(format!(
+                "expected item of type `videogame` but got `{}`",
+                item.item_type,
+            )));
+        }
+        // Release dates that aren't known are returned as `0000-00-00`, which fails to parse.
+        let release_date = item
+            .element
+            .child_value("releasedate")
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+        Ok(Self {
+            id: VideoGameId(item.id.0),
+            name: item.primary_name()?,
+            alternate_names: item.alternate_names(),
+            description: item.description(),
+            image: item.child_text("image"),
+            thumbnail: item.child_text("thumbnail"),
+            release_date,
+            platforms: item.linked_items("videogameplatform"),
+            genres: item.linked_items("videogamegenre"),
+            themes: item.linked_items("videogametheme"),
+            franchises: item.linked_items("videogamefranchise"),
+            series: item.linked_items("videogameseries"),
+            developers: item.linked_items("videogamedeveloper"),
+            publishers: item.linked_items("videogamepublisher"),
+        })
+    }
+}
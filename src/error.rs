@@ -3,6 +3,10 @@ use std::fmt;
 
 use serde::Deserialize;
 use serde_xml_rs::from_str;
+use xml::reader::XmlEvent;
+
+use crate::deserialize::deserialize_xml_events;
+use crate::Domain;
 
 /// A [`std::result::Result`] alias where the `Err` case is [`Error`].
 pub type Result<T> = std::result::Result<T, Error>;
@@ -15,7 +19,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug)]
 pub enum Error {
     /// An error returned creating the Http client. Can only happen when constructing a new
-    /// instance of the API when the `auth_token` contains invalid characters.
+    /// instance of the API, such as when the `auth_token` contains invalid characters.
     HttpClientCreationError(String),
     /// An error was returned making the HTTP request, or an error status code was returned.
     HttpError(reqwest::Error),
@@ -48,6 +52,22 @@ pub enum Error {
     ItemNotFound,
     /// The underlying API returned a list of errors that we do not recognise.
     UnknownApiErrors(Vec<String>),
+    /// The request isn't supported by the underlying API for the [`Domain`] that the API is set
+    /// to, for example there are no video game families.
+    UnsupportedDomain(Domain),
+    /// Logging in to the site failed, for example because the username or password was wrong.
+    LoginError(String),
+    /// The [`crate::Session`] used for a request has expired, or was ended by the site, so the
+    /// user needs to log in again.
+    SessionExpired,
+    /// A saved [`crate::Session`] could not be read or written.
+    InvalidSession(String),
+    /// The [`crate::TokenProvider`] of the API failed to provide a token, for example because the
+    /// environment variable or file it reads from is missing, or the token contains invalid
+    /// characters.
+    TokenError(String),
+    /// A request was rejected by the API as unauthorized, even after refreshing the auth token.
+    Unauthorized,
 }
 
 impl From<reqwest::Error> for Error {
@@ -87,6 +107,14 @@ impl fmt::Display for Error {
                 1 => write!(f, "got unknown error from API: {}", messages[0]),
                 _ => write!(f, "got errors from API: {}", messages.join(", ")),
             },
+            Error::UnsupportedDomain(domain) => {
+                write!(f, "request not supported for the {domain} domain")
+            },
+            Error::LoginError(reason) => write!(f, "error logging in: {reason}"),
+            Error::SessionExpired => write!(f, "session has expired, log in again"),
+            Error::InvalidSession(reason) => write!(f, "invalid session: {reason}"),
+            Error::TokenError(reason) => write!(f, "error getting auth token: {reason}"),
+            Error::Unauthorized => write!(f, "request was unauthorized, check the auth token"),
         }
     }
 }
@@ -103,6 +131,12 @@ impl StdError for Error {
             Error::InvalidCollectionItemType => None,
             Error::ItemNotFound => None,
             Error::UnknownApiErrors(_) => None,
+            Error::UnsupportedDomain(_) => None,
+            Error::LoginError(_) => None,
+            Error::SessionExpired => None,
+            Error::InvalidSession(_) => None,
+            Error::TokenError(_) => None,
+            Error::Unauthorized => None,
         }
     }
 }
@@ -128,6 +162,21 @@ pub(crate) fn deserialize_maybe_error(api_response: &str) -> Option<Error> {
     None
 }
 
+// The same as `deserialize_maybe_error`, but for the events of a response that has already been
+// read by the streaming reader.
+pub(crate) fn deserialize_maybe_error_events(events: &[XmlEvent]) -> Option<Error> {
+    if let Ok(error_list) = deserialize_xml_events::<ApiXmlErrorList>(events) {
+        return Some(error_list.into());
+    }
+    if let Ok(id_error) = deserialize_xml_events::<IdApiXmlError>(events) {
+        return Some(id_error.into());
+    }
+    if let Ok(single_error) = deserialize_xml_events::<SingleApiXmlError>(events) {
+        return Some(single_error.into());
+    }
+    None
+}
+
 // The XML returned by the API in case of an error is a list
 // of `message` tags. Usually with just one error inside.
 #[derive(Debug, Deserialize)]
@@ -151,6 +200,7 @@ pub(crate) struct IdApiXmlError {
 // such as for guilds with a too high ID requested.
 #[derive(Debug, Deserialize)]
 pub(crate) struct SingleApiXmlError {
+    #[serde(rename = "@message")]
     pub(crate) message: String,
 }
 
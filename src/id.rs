@@ -0,0 +1,276 @@
+use core::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::{IntoQueryParam, QueryParam};
+
+// Defines a newtype wrapper around a `u64` ID, so that IDs for different kinds of items cannot be
+// mixed up with each other. Each is displayed and parsed as the bare number, the same as it appears
+// in the underlying API and in URLs on the site.
+macro_rules! id_type {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl IntoQueryParam for $name {
+            fn into_query_param(self, key: &str) -> QueryParam<'_> {
+                self.0.into_query_param(key)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The ID of a board game or board game expansion.
+    ///
+    /// ```
+    /// use arnak::GameId;
+    ///
+    /// let id: GameId = "312484".parse().unwrap();
+    /// assert_eq!(id, GameId(312_484));
+    /// assert_eq!(id.to_string(), "312484");
+    /// ```
+    GameId
+);
+
+id_type!(
+    /// The ID of a board game accessory.
+    AccessoryId
+);
+
+id_type!(
+    /// The ID of an item for a role-playing game, such as a rulebook or adventure.
+    RpgItemId
+);
+
+id_type!(
+    /// The ID of a video game.
+    VideoGameId
+);
+
+id_type!(
+    /// The ID of a version of a board game, such as a translated edition.
+    ///
+    /// This is the ID of the version itself, not of the game that it is a version of.
+    VersionId
+);
+
+id_type!(
+    /// The ID of a publisher of games.
+    PublisherId
+);
+
+id_type!(
+    /// The ID of a game family.
+    FamilyId
+);
+
+id_type!(
+    /// The ID of a forum, either for a game or a game family.
+    ForumId
+);
+
+id_type!(
+    /// The ID of a thread within a forum.
+    ThreadId
+);
+
+id_type!(
+    /// The ID of a geek list, a curated list of items such as games.
+    GeekListId
+);
+
+id_type!(
+    /// The ID of a guild.
+    GuildId
+);
+
+id_type!(
+    /// The ID of a user.
+    UserId
+);
+
+id_type!(
+    /// The ID of a recorded play.
+    PlayId
+);
+
+id_type!(
+    /// The ID of an entry in a user's collection.
+    ///
+    /// This identifies the user's copy of an item, not the item itself, so differs between users
+    /// and between multiple copies of the same game in a collection.
+    CollectionId
+);
+
+id_type!(
+    /// The ID of an entry in a geek list.
+    ///
+    /// This identifies the entry itself, not the item that it refers to.
+    GeekListItemId
+);
+
+id_type!(
+    /// The ID of a post in a forum thread.
+    PostId
+);
+
+id_type!(
+    /// The ID of a person, such as a designer or artist of games.
+    PersonId
+);
+
+id_type!(
+    /// The ID of a game category, such as card game or fantasy.
+    CategoryId
+);
+
+id_type!(
+    /// The ID of a game mechanic, such as deck building or worker placement.
+    MechanicId
+);
+
+id_type!(
+    /// The ID of a language that versions of games can support.
+    LanguageId
+);
+
+id_type!(
+    /// The ID of a ranking that games are placed in, such as the overall board game rank or the
+    /// strategy game rank.
+    RankId
+);
+
+id_type!(
+    /// The ID of a video posted about a game.
+    VideoId
+);
+
+id_type!(
+    /// The ID of an item of any kind, such as a game, accessory, or RPG item.
+    ///
+    /// Used where the kind of item is only known from a separate type, such as items in a
+    /// collection or recorded plays, which can be any kind of item the site tracks. Any of the more
+    /// specific item IDs can be converted into this.
+    ///
+    /// ```
+    /// use arnak::{GameId, ObjectId};
+    ///
+    /// assert_eq!(ObjectId::from(GameId(312_484)), ObjectId(312_484));
+    /// ```
+    ObjectId
+);
+
+/// The ID of any kind of item that can be requested from the underlying `thing` endpoint, for
+/// requests where the types of item to return are given separately.
+pub trait ItemId: Copy + Into<u64> {}
+
+// Each kind of item ID is also an item ID of any kind.
+macro_rules! item_id {
+    ($($name:ident),*) => {
+        $(
+            impl ItemId for $name {}
+
+            impl From<$name> for ObjectId {
+                fn from(id: $name) -> Self {
+                    Self(id.0)
+                }
+            }
+        )*
+    };
+}
+
+item_id!(GameId, AccessoryId, RpgItemId, VideoGameId, VersionId);
+
+impl ItemId for ObjectId {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deserialize::deserialize_xml_string;
+
+    #[test]
+    fn from_str() {
+        assert_eq!("312484".parse::<GameId>(), Ok(GameId(312_484)));
+        assert_eq!("0".parse::<UserId>(), Ok(UserId(0)));
+        assert!("".parse::<GameId>().is_err());
+        assert!("-1".parse::<GameId>().is_err());
+        assert!("12a".parse::<ForumId>().is_err());
+        assert!("18446744073709551616".parse::<PlayId>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(GameId(312_484).to_string(), "312484");
+        assert_eq!(CollectionId(0).to_string(), "0");
+        assert_eq!(format!("{:>8}", AccessoryId(42)), "      42");
+
+        let id = ThreadId(3_208_373);
+        assert_eq!(id.to_string().parse::<ThreadId>(), Ok(id));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(GameId::from(13), GameId(13));
+        assert_eq!(u64::from(GameId(13)), 13);
+        assert_eq!(VersionId(7).into_query_param("id"), ("id", "7".to_owned()));
+        assert_eq!(ObjectId::from(AccessoryId(2)), ObjectId(2));
+        assert_eq!(ObjectId::from(RpgItemId(3)), ObjectId(3));
+    }
+
+    #[test]
+    fn deserialize() {
+        #[derive(Debug, Deserialize)]
+        struct Item {
+            #[serde(rename = "@id")]
+            id: GameId,
+        }
+
+        let item: Item = deserialize_xml_string(r#"<item id="312484"/>"#).unwrap();
+        assert_eq!(item.id, GameId(312_484));
+
+        let id: GuildId = serde_json::from_str("1229").unwrap();
+        assert_eq!(id, GuildId(1229));
+        assert!(serde_json::from_str::<GuildId>(r#""abc""#).is_err());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serialize_round_trip() {
+        let id = GeekListId(66_420);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "66420");
+        assert_eq!(serde_json::from_str::<GeekListId>(&json).unwrap(), id);
+    }
+}
@@ -18,6 +18,22 @@
 //!     }
 //! })
 //! ```
+//!
+//! ## Features
+//!
+//! - `serialize`: Implements [`serde::Serialize`] for all public models, so that results can be
+//!   forwarded on or stored, for example as JSON. This only goes one way, the `Deserialize`
+//!   implementations of the models read the XML responses of the API so can't read the JSON back.
+//!   The serialised shape is stable and uses the Rust field names rather than the original XML
+//!   names:
+//!   - Structs are maps keyed by their `snake_case` field names.
+//!   - Durations, such as playing times, are a whole number of minutes.
+//!   - Dates and date times use the `chrono` serde format, `YYYY-MM-DD` and RFC 3339.
+//!   - Enums with fixed values, such as [`ItemType`], use the same lowercase names as the API.
+//!   - Enums with data, such as [`RankValue`] and [`PlayerCount`], are externally tagged with
+//!     `snake_case` names, e.g. `{"ranked": 12}` or `"not_ranked"`.
+//! - `quick-xml`: Parses responses with `quick-xml` instead of the default `xml-rs` based parser.
+//!   The resulting models are identical. Streaming requests still use the default parser.
 
 #![deny(clippy::pedantic, clippy::cargo)]
 #![allow(
@@ -52,13 +68,39 @@
 mod api;
 pub use api::*;
 
+mod description;
+pub use description::*;
+
 mod endpoints;
 pub use endpoints::*;
 
 mod error;
 pub use error::*;
 
+mod id;
+pub use id::*;
+
+mod price;
+pub use price::*;
+
 mod query_param;
 pub(crate) use query_param::*;
 
+mod session;
+pub use session::*;
+
+mod stream;
+pub use stream::*;
+
+mod token;
+pub use token::*;
+
 mod deserialize;
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+#[allow(missing_docs)]
+pub mod bench;
+
+#[cfg(feature = "serialize")]
+mod serialize;
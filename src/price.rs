@@ -0,0 +1,297 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt;
+use std::str::FromStr;
+
+pub use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::deserialize::BorrowedStr;
+use crate::{ItemCondition, MarketplaceListing};
+
+/// A currency, identified by its three letter ISO 4217 code such as `USD` or `EUR`.
+///
+/// Any three letter code can be parsed, constants are provided for the currencies most commonly
+/// seen on the boardgamegeek marketplace.
+///
+/// ```
+/// use arnak::Currency;
+///
+/// let currency: Currency = "eur".parse().unwrap();
+/// assert_eq!(currency, Currency::EUR);
+/// assert_eq!(currency.to_string(), "EUR");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Currency([u8; 3]);
+
+impl Currency {
+    /// Australian dollar.
+    pub const AUD: Self = Self(*b"AUD");
+    /// Canadian dollar.
+    pub const CAD: Self = Self(*b"CAD");
+    /// Swiss franc.
+    pub const CHF: Self = Self(*b"CHF");
+    /// Czech koruna.
+    pub const CZK: Self = Self(*b"CZK");
+    /// Danish krone.
+    pub const DKK: Self = Self(*b"DKK");
+    /// Euro.
+    pub const EUR: Self = Self(*b"EUR");
+    /// Pound sterling.
+    pub const GBP: Self = Self(*b"GBP");
+    /// Japanese yen.
+    pub const JPY: Self = Self(*b"JPY");
+    /// Norwegian krone.
+    pub const NOK: Self = Self(*b"NOK");
+    /// New Zealand dollar.
+    pub const NZD: Self = Self(*b"NZD");
+    /// Polish zloty.
+    pub const PLN: Self = Self(*b"PLN");
+    /// Swedish krona.
+    pub const SEK: Self = Self(*b"SEK");
+    /// United States dollar.
+    pub const USD: Self = Self(*b"USD");
+
+    /// The three letter code of the currency, always in upper case.
+    pub fn code(&self) -> &str {
+        // Only ever constructed from ASCII letters.
+        std::str::from_utf8(&self.0).unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Currency").field(&self.code()).finish()
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[a, b, c] if s.bytes().all(|byte| byte.is_ascii_alphabetic()) => Ok(Self([
+                a.to_ascii_uppercase(),
+                b.to_ascii_uppercase(),
+                c.to_ascii_uppercase(),
+            ])),
+            _ => Err(ParseCurrencyError(s.to_owned())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: serde::de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = BorrowedStr::deserialize(deserializer)?.0;
+        s.parse().map_err(|_| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&s),
+                &"a three letter ISO 4217 currency code",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for Currency {
+    fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// An error returned when parsing a string that is not a three letter currency code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCurrencyError(String);
+
+impl fmt::Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid ISO 4217 currency code `{}`", self.0)
+    }
+}
+
+impl StdError for ParseCurrencyError {}
+
+/// A table of exchange rates provided by the caller, used to convert prices between currencies.
+///
+/// Each rate is the amount of that currency equal to one unit of the base currency. Conversions
+/// between two currencies which are not the base currency go via the base currency, and are not
+/// rounded.
+///
+/// ```
+/// use arnak::{Currency, Decimal, ExchangeRates};
+///
+/// let rates = ExchangeRates::new(Currency::USD)
+///     .rate(Currency::EUR, Decimal::new(8, 1))
+///     .rate(Currency::GBP, Decimal::new(5, 1));
+/// assert_eq!(
+///     rates.convert(Decimal::new(10, 0), Currency::EUR, Currency::USD),
+///     Some(Decimal::new(125, 1)),
+/// );
+/// assert_eq!(
+///     rates.convert(Decimal::new(10, 0), Currency::EUR, Currency::GBP),
+///     Some(Decimal::new(625, 2)),
+/// );
+/// assert_eq!(rates.convert(Decimal::new(10, 0), Currency::EUR, Currency::CAD), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExchangeRates {
+    base: Currency,
+    rates: HashMap<Currency, Decimal>,
+}
+
+impl ExchangeRates {
+    /// Constructs an empty rate table, where prices are normalised to the given base currency.
+    pub fn new(base: Currency) -> Self {
+        Self {
+            base,
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Sets the rate for a currency, as the amount of that currency equal to one unit of the base
+    /// currency.
+    pub fn rate(mut self, currency: Currency, rate: Decimal) -> Self {
+        self.rates.insert(currency, rate);
+        self
+    }
+
+    /// The currency that prices are normalised to.
+    pub fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Returns the rate for a currency, if it is in the table. The rate for the base currency is
+    /// always one.
+    pub fn get(&self, currency: Currency) -> Option<Decimal> {
+        if currency == self.base {
+            return Some(Decimal::ONE);
+        }
+        self.rates.get(&currency).copied()
+    }
+
+    /// Converts an amount from one currency to another.
+    ///
+    /// Returns `None` if either currency is missing from the table, has a rate of zero, or if the
+    /// converted amount would overflow.
+    pub fn convert(&self, amount: Decimal, from: Currency, to: Currency) -> Option<Decimal> {
+        if from == to {
+            return Some(amount);
+        }
+        amount
+            .checked_div(self.get(from)?)?
+            .checked_mul(self.get(to)?)
+    }
+}
+
+/// Criteria for filtering marketplace listings, by their condition and their price once
+/// normalised to the base currency of an [`ExchangeRates`] table.
+///
+/// ```
+/// use arnak::{Currency, Decimal, ExchangeRates, ItemCondition, ListingFilter};
+///
+/// let rates = ExchangeRates::new(Currency::USD).rate(Currency::EUR, Decimal::new(8, 1));
+/// let filter = ListingFilter::new()
+///     .min_condition(ItemCondition::VeryGood)
+///     .max_price(Decimal::new(30, 0));
+/// # let listings: Vec<arnak::MarketplaceListing> = vec![];
+/// let matching: Vec<_> = listings
+///     .iter()
+///     .filter(|listing| filter.matches(listing, &rates))
+///     .collect();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListingFilter {
+    // The worst condition allowed, inclusive.
+    min_condition: Option<ItemCondition>,
+    // Lowest normalised price allowed, inclusive.
+    min_price: Option<Decimal>,
+    // Highest normalised price allowed, inclusive.
+    max_price: Option<Decimal>,
+}
+
+impl ListingFilter {
+    /// Constructs a new filter which matches all listings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match listings in at least this condition, where [`ItemCondition::New`] is the best.
+    pub fn min_condition(mut self, min_condition: ItemCondition) -> Self {
+        self.min_condition = Some(min_condition);
+        self
+    }
+
+    /// Only match listings costing at least this much, in the base currency of the rates.
+    pub fn min_price(mut self, min_price: Decimal) -> Self {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    /// Only match listings costing at most this much, in the base currency of the rates.
+    pub fn max_price(mut self, max_price: Decimal) -> Self {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    /// Whether the listing meets all criteria of the filter.
+    ///
+    /// If a price range is set, listings in a currency missing from the rates never match.
+    pub fn matches(&self, listing: &MarketplaceListing, rates: &ExchangeRates) -> bool {
+        if self
+            .min_condition
+            .is_some_and(|min_condition| listing.condition < min_condition)
+        {
+            return false;
+        }
+        if self.min_price.is_none() && self.max_price.is_none() {
+            return true;
+        }
+        let Some(price) = listing.normalized_price(rates) else {
+            return false;
+        };
+        self.min_price.is_none_or(|min_price| price >= min_price)
+            && self.max_price.is_none_or(|max_price| price <= max_price)
+    }
+}
+
+/// Sorts marketplace listings by their price once normalised to the base currency of the rates,
+/// cheapest first.
+///
+/// Listings at the same price are ordered by condition, best first. Listings in a currency missing
+/// from the rates are placed at the end, in their original order.
+pub fn sort_listings_by_price(listings: &mut [MarketplaceListing], rates: &ExchangeRates) {
+    listings.sort_by_cached_key(|listing| {
+        (
+            PriceKey(listing.normalized_price(rates)),
+            std::cmp::Reverse(listing.condition),
+        )
+    });
+}
+
+// Orders known prices ascending, followed by unknown ones.
+#[derive(PartialEq, Eq)]
+struct PriceKey(Option<Decimal>);
+
+impl PartialOrd for PriceKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PriceKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0, other.0) {
+            (Some(price), Some(other_price)) => price.cmp(&other_price),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
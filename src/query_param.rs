@@ -2,7 +2,9 @@ use std::string::ToString;
 
 use chrono::{NaiveDate, NaiveDateTime};
 
-use crate::{CollectionItemType, GameType, ItemDomain, ItemSubType, ItemType, WishlistPriority};
+use crate::{
+    CollectionItemType, Domain, GameType, ItemDomain, ItemSubType, ItemType, WishlistPriority,
+};
 
 pub(crate) type QueryParam<'a> = (&'a str, String);
 
@@ -92,6 +94,12 @@ impl IntoQueryParam for &WishlistPriority {
     }
 }
 
+impl IntoQueryParam for Domain {
+    fn into_query_param(self, key: &str) -> QueryParam<'_> {
+        (key, self.to_string())
+    }
+}
+
 impl IntoQueryParam for &ItemDomain {
     fn into_query_param(self, key: &str) -> QueryParam<'_> {
         match self {
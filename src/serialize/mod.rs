@@ -0,0 +1,125 @@
+use chrono::Duration;
+
+// Durations are always deserialised from a whole number of minutes, so serialise them back to the
+// same rather than exposing the internal representation.
+pub(crate) fn serialize_minutes<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::ser::Serializer,
+{
+    serializer.serialize_i64(duration.num_minutes())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    use crate::deserialize::deserialize_xml_string;
+    use crate::{
+        parse_raw_items, Accessories, Collection, CollectionItem, CollectionItemBrief, Forum,
+        ForumGroup, GameFamilies, Games, GeekList, Guild, HotList, HotListCompany, HotListGame,
+        HotListPerson, Plays, RpgItem, SearchResults, Things, Thread, User, VideoGame,
+        XmlV1BoardGame, XmlV1BoardGames,
+    };
+
+    // Parses a response straight into the model that is returned for it.
+    fn parse<T: DeserializeOwned>(xml: &str) -> T {
+        deserialize_xml_string(xml).expect("failed to parse test data")
+    }
+
+    // Parses the text of a response and serialises the models returned for it.
+    type ParseToJson = fn(&str) -> serde_json::Value;
+
+    fn to_json(value: impl Serialize) -> serde_json::Value {
+        serde_json::to_value(value).expect("failed to serialize")
+    }
+
+    // Each response in the test data along with how it is parsed into the models returned for it,
+    // which are compared in full against the JSON expected for them.
+    #[test]
+    fn serialize_models() {
+        let models: [(&str, ParseToJson); 22] = [
+            ("accessory/accessory_full", |xml| {
+                to_json(parse::<Accessories>(xml).accessories)
+            }),
+            ("collection/collection_private_info", |xml| {
+                to_json(parse::<Collection<CollectionItem>>(xml))
+            }),
+            ("collection/collection_brief_with_version", |xml| {
+                to_json(parse::<Collection<CollectionItemBrief>>(xml))
+            }),
+            ("forum/forum", |xml| to_json(parse::<Forum>(xml))),
+            ("forum_group/thing_forum_group", |xml| {
+                to_json(parse::<ForumGroup>(xml))
+            }),
+            ("game/game", |xml| to_json(parse::<Games>(xml).games)),
+            ("game/game_all", |xml| to_json(parse::<Games>(xml).games)),
+            ("game/game_multiple", |xml| {
+                to_json(parse_raw_items(xml).expect("failed to parse test data"))
+            }),
+            ("game_family/game_family_single", |xml| {
+                to_json(parse::<GameFamilies>(xml).game_families)
+            }),
+            ("geek_list/geek_list", |xml| to_json(parse::<GeekList>(xml))),
+            ("guild/guild", |xml| to_json(parse::<Guild>(xml))),
+            ("hot_list/hot_list", |xml| {
+                to_json(parse::<HotList<HotListGame>>(xml).items)
+            }),
+            ("hot_list/hot_list_people", |xml| {
+                to_json(parse::<HotList<HotListPerson>>(xml).items)
+            }),
+            ("hot_list/hot_list_companies", |xml| {
+                to_json(parse::<HotList<HotListCompany>>(xml).items)
+            }),
+            ("plays/user_plays", |xml| to_json(parse::<Plays>(xml))),
+            ("rpg_item/rpg_item", |xml| {
+                let items = parse_raw_items(xml).expect("failed to parse test data");
+                to_json(
+                    items
+                        .iter()
+                        .map(RpgItem::from_raw)
+                        .collect::<Result<Vec<_>, _>>()
+                        .expect("failed to parse test data"),
+                )
+            }),
+            ("search/search", |xml| {
+                to_json(parse::<SearchResults>(xml).results)
+            }),
+            ("thing/things", |xml| to_json(parse::<Things>(xml).things)),
+            ("thread/thread", |xml| to_json(parse::<Thread>(xml))),
+            ("user/user_full", |xml| to_json(parse::<User>(xml))),
+            ("v1/historical_ratings", |xml| {
+                to_json(
+                    parse::<XmlV1BoardGames>(xml)
+                        .games
+                        .into_iter()
+                        .filter_map(XmlV1BoardGame::into_historical_ratings)
+                        .collect::<Vec<_>>(),
+                )
+            }),
+            ("video_game/video_game", |xml| {
+                let items = parse_raw_items(xml).expect("failed to parse test data");
+                to_json(
+                    items
+                        .iter()
+                        .map(VideoGame::from_raw)
+                        .collect::<Result<Vec<_>, _>>()
+                        .expect("failed to parse test data"),
+                )
+            }),
+        ];
+
+        for (name, parse_to_json) in models {
+            let xml = std::fs::read_to_string(format!("test_data/{name}.xml"))
+                .expect("failed to load test data");
+            let expected: serde_json::Value = serde_json::from_str(
+                &std::fs::read_to_string(format!("test_data/serialize/{name}.json"))
+                    .expect("failed to load expected json"),
+            )
+            .expect("failed to parse expected json");
+
+            let json = parse_to_json(&xml);
+            assert_eq!(json, expected, "serialised {name} differs from expected");
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use std::borrow::Cow;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use reqwest::header::HeaderValue;
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+/// A logged in session for a user on the site, returned from [`crate::BoardGameGeekApi::log_in`].
+///
+/// Needed for requests that act as the user, such as logging plays, which aren't part of the XML
+/// API and so can't be made with just the auth token. The auth token is still sent alongside the
+/// session with these requests.
+///
+/// A session can be kept between runs with [`Session::save`] and [`Session::load`], to avoid
+/// logging in each time. Once it expires, requests made with it return [`Error::SessionExpired`]
+/// and the user needs to log in again.
+#[derive(Clone, Debug)]
+pub struct Session {
+    // The username of the user that the session is for.
+    pub(crate) username: String,
+    // The cookies set by the site when logging in, in the form sent back with a `Cookie` header.
+    pub(crate) cookie: HeaderValue,
+    // When the first of the cookies expires, if any of them were given an expiry.
+    pub(crate) expires_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    /// The username of the user that the session is for.
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// When the session expires, if the site gave it an expiry.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+
+    /// Whether the session has expired, in which case the user needs to log in again.
+    ///
+    /// A session can also be ended early by the site, such as when the user logs out elsewhere,
+    /// which is only found out when a request made with it returns [`Error::SessionExpired`].
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+
+    /// Converts the session into a string that can be stored, and later turned back into a
+    /// session with [`Session::restore`].
+    ///
+    /// The string contains the session cookies, which allow acting as the user, so it should be
+    /// stored as securely as a password would be.
+    pub fn persist(&self) -> String {
+        let persisted = PersistedSession {
+            username: Cow::Borrowed(&self.username),
+            cookie: Cow::Borrowed(self.cookie.to_str().unwrap_or_default()),
+            expires_at: self.expires_at,
+        };
+        // Serializing a struct of strings and an optional date can't fail.
+        serde_json::to_string(&persisted).unwrap_or_default()
+    }
+
+    /// Restores a session from a string returned by [`Session::persist`].
+    ///
+    /// Returns an [`Error::InvalidSession`] if the string is not a persisted session, or an
+    /// [`Error::SessionExpired`] if the session has since expired.
+    pub fn restore(persisted: &str) -> Result<Self> {
+        let persisted: PersistedSession<'_> = serde_json::from_str(persisted)
+            .map_err(|e| Error::InvalidSession(format!("failed to parse session: {e}")))?;
+        let mut cookie = HeaderValue::from_str(&persisted.cookie).map_err(|_| {
+            Error::InvalidSession("session cookies contain invalid header characters".to_owned())
+        })?;
+        cookie.set_sensitive(true);
+
+        let session = Self {
+            username: persisted.username.into_owned(),
+            cookie,
+            expires_at: persisted.expires_at,
+        };
+        if session.is_expired() {
+            return Err(Error::SessionExpired);
+        }
+        Ok(session)
+    }
+
+    /// Saves the session to a file, to be loaded again with [`Session::load`]. See
+    /// [`Session::persist`].
+    ///
+    /// The file contains the session cookies in plain text, so it is a credential in the same way
+    /// a password file would be. On unix it is created readable and writable only by the owner,
+    /// and the permissions of an existing file are restricted the same way before it is
+    /// overwritten. Elsewhere it is up to the caller to pick a location that others can't read.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let save_error =
+            |e: std::io::Error| Error::InvalidSession(format!("failed to save session: {e}"));
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(path).map_err(save_error)?;
+        #[cfg(unix)]
+        file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))
+            .map_err(save_error)?;
+        file.write_all(self.persist().as_bytes())
+            .map_err(save_error)
+    }
+
+    /// Loads a session from a file saved with [`Session::save`]. See [`Session::restore`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let persisted = std::fs::read_to_string(path)
+            .map_err(|e| Error::InvalidSession(format!("failed to load session: {e}")))?;
+        Self::restore(&persisted)
+    }
+
+    // Builds a session from the cookies set in a successful login response. The site also sends
+    // cookies with the value `deleted` to clear any from a previous session, which are skipped.
+    pub(crate) fn from_login_response(username: &str, response: &Response) -> Result<Self> {
+        let set_cookies: Vec<&str> = response
+            .headers()
+            .get_all(reqwest::header::SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter(|set_cookie| {
+                set_cookie.split(';').next().is_some_and(|cookie| {
+                    !cookie.trim().is_empty() && !cookie.trim().ends_with("=deleted")
+                })
+            })
+            .collect();
+        if set_cookies.is_empty() {
+            return Err(Error::LoginError(
+                "no session cookies were returned".to_owned(),
+            ));
+        }
+
+        let cookies: Vec<&str> = set_cookies
+            .iter()
+            .filter_map(|set_cookie| set_cookie.split(';').next())
+            .map(str::trim)
+            .collect();
+        let mut cookie = HeaderValue::from_str(&cookies.join("; ")).map_err(|_| {
+            Error::LoginError("session cookies contain invalid header characters".to_owned())
+        })?;
+        cookie.set_sensitive(true);
+
+        let now = Utc::now();
+        Ok(Self {
+            username: username.to_owned(),
+            cookie,
+            expires_at: set_cookies
+                .iter()
+                .filter_map(|set_cookie| cookie_expiry(set_cookie, now))
+                .min(),
+        })
+    }
+}
+
+// When a cookie expires, from the attributes of the `Set-Cookie` header that set it. `Max-Age`
+// takes precedence over `Expires` if both are given, unless it can't be parsed in which case it is
+// ignored. Cookies without either last until the browser is closed, which for us means they don't
+// expire.
+fn cookie_expiry(set_cookie: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let mut expires = None;
+    for attribute in set_cookie.split(';').skip(1) {
+        let (name, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+        let (name, value) = (name.trim(), value.trim());
+        if name.eq_ignore_ascii_case("max-age") {
+            let max_age = value.parse::<i64>().ok().and_then(TimeDelta::try_seconds);
+            if let Some(expires_at) = max_age.and_then(|max_age| now.checked_add_signed(max_age)) {
+                return Some(expires_at);
+            }
+        }
+        if name.eq_ignore_ascii_case("expires") {
+            expires = parse_cookie_date(value);
+        }
+    }
+    expires
+}
+
+// Cookie dates are usually in the format `Wed, 21 Oct 2026 07:28:00 GMT`, but older formats with
+// dashes between the parts of the date are still seen.
+fn parse_cookie_date(date: &str) -> Option<DateTime<Utc>> {
+    ["%a, %d %b %Y %H:%M:%S GMT", "%a, %d-%b-%Y %H:%M:%S GMT"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(date, format).ok())
+        .map(|date| date.and_utc())
+}
+
+// The form that a session is persisted in by `Session::persist`.
+#[derive(Serialize, Deserialize)]
+struct PersistedSession<'a> {
+    #[serde(borrow)]
+    username: Cow<'a, str>,
+    #[serde(borrow)]
+    cookie: Cow<'a, str>,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+// The body of a login request to the site.
+#[derive(Serialize)]
+pub(crate) struct LoginRequest<'a> {
+    credentials: Credentials<'a>,
+}
+
+#[derive(Serialize)]
+struct Credentials<'a> {
+    username: &'a str,
+    password: &'a str,
+}
+
+impl<'a> LoginRequest<'a> {
+    pub(crate) fn new(username: &'a str, password: &'a str) -> Self {
+        Self {
+            credentials: Credentials { username, password },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn persist_and_restore() {
+        let mut cookie = HeaderValue::from_static("SessionID=abc123; bggusername=bluebearbgg");
+        cookie.set_sensitive(true);
+        let session = Session {
+            username: "bluebearbgg".to_owned(),
+            cookie,
+            expires_at: Some(Utc.with_ymd_and_hms(2999, 1, 1, 0, 0, 0).unwrap()),
+        };
+
+        let restored = Session::restore(&session.persist());
+        assert!(restored.is_ok(), "error returned when okay expected");
+        let restored = restored.unwrap();
+        assert_eq!(restored.username(), "bluebearbgg");
+        assert_eq!(restored.cookie, "SessionID=abc123; bggusername=bluebearbgg");
+        assert!(restored.cookie.is_sensitive());
+        assert_eq!(restored.expires_at(), session.expires_at());
+    }
+
+    #[test]
+    fn restore_invalid() {
+        let restored = Session::restore("not a session");
+        assert!(
+            matches!(restored, Err(Error::InvalidSession(_))),
+            "expected invalid session error but got {restored:?}",
+        );
+    }
+
+    #[test]
+    fn restore_expired() {
+        let restored = Session::restore(
+            r#"{"username":"bluebearbgg","cookie":"SessionID=abc123","expires_at":"2020-01-01T00:00:00Z"}"#,
+        );
+        assert!(
+            matches!(restored, Err(Error::SessionExpired)),
+            "expected session expired error but got {restored:?}",
+        );
+    }
+
+    #[test]
+    fn cookie_expiry_max_age() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap();
+        assert_eq!(
+            cookie_expiry(
+                "SessionID=abc123; Expires=Wed, 21-Oct-2026 07:28:00 GMT; Max-Age=3600; path=/",
+                now,
+            ),
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 13, 0, 0).unwrap()),
+        );
+        assert_eq!(
+            cookie_expiry(
+                "SessionID=abc123; Expires=Wed, 21-Oct-2026 07:28:00 GMT",
+                now
+            ),
+            Some(Utc.with_ymd_and_hms(2026, 10, 21, 7, 28, 0).unwrap()),
+        );
+        assert_eq!(cookie_expiry("SessionID=abc123; path=/", now), None);
+    }
+
+    #[test]
+    fn cookie_expiry_invalid_max_age() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap();
+        assert_eq!(
+            cookie_expiry(
+                "SessionID=abc123; Max-Age=soon; Expires=Wed, 21-Oct-2026 07:28:00 GMT",
+                now,
+            ),
+            Some(Utc.with_ymd_and_hms(2026, 10, 21, 7, 28, 0).unwrap()),
+        );
+        assert_eq!(
+            cookie_expiry(
+                "SessionID=abc123; Expires=Wed, 21-Oct-2026 07:28:00 GMT; Max-Age=99999999999999999",
+                now,
+            ),
+            Some(Utc.with_ymd_and_hms(2026, 10, 21, 7, 28, 0).unwrap()),
+        );
+        assert_eq!(cookie_expiry("SessionID=abc123; Max-Age=", now), None);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join(format!("arnak_session_{}", std::process::id()));
+        let mut cookie = HeaderValue::from_static("SessionID=abc123");
+        cookie.set_sensitive(true);
+        let session = Session {
+            username: "bluebearbgg".to_owned(),
+            cookie,
+            expires_at: None,
+        };
+
+        let saved = session.save(&path);
+        assert!(saved.is_ok(), "error returned when okay expected");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let loaded = Session::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(loaded.is_ok(), "error returned when okay expected");
+        assert_eq!(loaded.unwrap().username(), "bluebearbgg");
+    }
+}
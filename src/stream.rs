@@ -0,0 +1,275 @@
+use std::io::Read;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+use tokio::sync::mpsc;
+use xml::attribute::OwnedAttribute;
+use xml::reader::XmlEvent;
+
+use crate::deserialize::{deserialize_xml_events, streaming_xml_reader_config};
+use crate::{deserialize_maybe_error_events, Error, Result};
+
+// Number of response chunks that can be waiting to be parsed before we stop reading from the
+// response.
+const CHUNK_BUFFER_SIZE: usize = 16;
+// Number of parsed items that can be waiting to be consumed before we stop parsing.
+const ITEM_BUFFER_SIZE: usize = 64;
+
+/// An asynchronous stream of items, parsed one at a time as the response is read from the
+/// underlying API.
+///
+/// Unlike the non streaming requests, the full response is never held in memory at once. Each
+/// item is only parsed once it has been fully received, and reading of the response is paused
+/// while previously parsed items have not yet been consumed. This keeps memory usage flat for
+/// very large responses, such as collections with thousands of items.
+///
+/// Implements [`futures_core::Stream`], but items can also be read by awaiting
+/// [`ItemStream::next`] without any additional dependencies.
+///
+/// If the request fails part way through, or the API returned an error, an `Err` is yielded and
+/// the stream ends.
+#[derive(Debug)]
+pub struct ItemStream<T> {
+    receiver: mpsc::Receiver<Result<T>>,
+}
+
+impl<T> ItemStream<T> {
+    // Creates a new stream along with the sender half that parsed items are sent to.
+    pub(crate) fn channel() -> (mpsc::Sender<Result<T>>, Self) {
+        let (sender, receiver) = mpsc::channel(ITEM_BUFFER_SIZE);
+        (sender, Self { receiver })
+    }
+
+    /// Waits for the next item in the stream. Returns `None` once there are no more items.
+    pub async fn next(&mut self) -> Option<Result<T>> {
+        self.receiver.recv().await
+    }
+}
+
+impl<T> Stream for ItemStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+// The items of a response that were sent to a stream by `forward_items`.
+pub(crate) struct ForwardedItems {
+    // The number of items sent.
+    pub(crate) count: usize,
+    // The attributes of the root element, such as the total number of items across all pages.
+    root_attributes: Vec<OwnedAttribute>,
+}
+
+impl ForwardedItems {
+    // The value of an attribute on the root element of the response, if it was set.
+    pub(crate) fn root_attribute(&self, name: &str) -> Option<&str> {
+        self.root_attributes
+            .iter()
+            .find(|attribute| attribute.name.local_name == name)
+            .map(|attribute| attribute.value.as_str())
+    }
+}
+
+// Reads the body of a response, sending each `element` directly inside the `root` element to the
+// sender as soon as it has been parsed.
+//
+// Returns the items that were sent, or None if the stream was ended early. Either because an error
+// was sent, or the receiving end of the stream was dropped.
+pub(crate) async fn forward_items<T: DeserializeOwned + Send + 'static>(
+    mut response: Response,
+    root: &'static str,
+    element: &'static str,
+    sender: &mpsc::Sender<Result<T>>,
+) -> Option<ForwardedItems> {
+    let (chunk_sender, chunk_receiver) = mpsc::channel(CHUNK_BUFFER_SIZE);
+    let item_sender = sender.clone();
+    // The XML reader is blocking, so is run on its own thread and fed chunks of the response as
+    // they arrive.
+    let parser = tokio::task::spawn_blocking(move || {
+        parse_items(
+            ChunkReader::new(chunk_receiver),
+            root,
+            element,
+            &item_sender,
+        )
+    });
+
+    loop {
+        match response.chunk().await {
+            Ok(Some(chunk)) => {
+                // If the parser has stopped there is no need to read the rest of the response.
+                if chunk_sender.send(chunk).await.is_err() {
+                    break;
+                }
+            },
+            Ok(None) => break,
+            Err(e) => {
+                // Close the channel before waiting for the parser, so it is not left waiting for
+                // more data.
+                drop(chunk_sender);
+                let _ = parser.await;
+                let _ = sender.send(Err(Error::HttpError(e))).await;
+                return None;
+            },
+        }
+    }
+    drop(chunk_sender);
+    parser.await.ok().flatten()
+}
+
+fn parse_items<T: DeserializeOwned, R: Read>(
+    reader: R,
+    root: &str,
+    element: &str,
+    sender: &mpsc::Sender<Result<T>>,
+) -> Option<ForwardedItems> {
+    let mut events = streaming_xml_reader_config().create_reader(reader);
+
+    let root_start = loop {
+        match events.next() {
+            Ok(event @ XmlEvent::StartElement { .. }) => break event,
+            Ok(XmlEvent::EndDocument) => {
+                let error = Error::UnexpectedResponseError("response was empty".to_owned());
+                let _ = sender.blocking_send(Err(error));
+                return None;
+            },
+            Ok(_) => {},
+            Err(e) => {
+                let _ = sender.blocking_send(Err(Error::InvalidResponseError(e.into())));
+                return None;
+            },
+        }
+    };
+
+    let root_attributes = match root_start {
+        XmlEvent::StartElement {
+            name, attributes, ..
+        } if name.local_name == root => attributes,
+        root_start => {
+            // The API returns a 200 but with an XML error in some cases, such as a username not
+            // found, so the rest of the response is read to try and parse that.
+            let error = match read_element(&mut events, root_start) {
+                Ok(element_events) => deserialize_maybe_error_events(&element_events)
+                    .unwrap_or_else(|| {
+                        Error::UnexpectedResponseError(format!("expected root element `{root}`"))
+                    }),
+                Err(e) => Error::InvalidResponseError(e),
+            };
+            let _ = sender.blocking_send(Err(error));
+            return None;
+        },
+    };
+
+    let mut item_count = 0;
+    loop {
+        let event = match events.next() {
+            Ok(event) => event,
+            Err(e) => {
+                let _ = sender.blocking_send(Err(Error::InvalidResponseError(e.into())));
+                return None;
+            },
+        };
+        match event {
+            XmlEvent::StartElement { ref name, .. } if name.local_name == element => {
+                let item = read_element(&mut events, event)
+                    .and_then(|element_events| deserialize_xml_events::<T>(&element_events))
+                    .map_err(Error::InvalidResponseError);
+                let is_err = item.is_err();
+                if sender.blocking_send(item).is_err() || is_err {
+                    return None;
+                }
+                item_count += 1;
+            },
+            XmlEvent::StartElement { .. } => {
+                // Other elements alongside the items are skipped over.
+                if let Err(e) = read_element(&mut events, event) {
+                    let _ = sender.blocking_send(Err(Error::InvalidResponseError(e)));
+                    return None;
+                }
+            },
+            XmlEvent::EndElement { .. } => break,
+            XmlEvent::EndDocument => {
+                let _ = sender.blocking_send(Err(Error::InvalidResponseError(truncated_error())));
+                return None;
+            },
+            _ => {},
+        }
+    }
+    Some(ForwardedItems {
+        count: item_count,
+        root_attributes,
+    })
+}
+
+// Reads the rest of an element whose start event has already been read, returning all of the
+// events of the element so that it can be deserialized on its own.
+fn read_element<R: Read>(
+    events: &mut xml::EventReader<R>,
+    start: XmlEvent,
+) -> core::result::Result<Vec<XmlEvent>, serde_xml_rs::Error> {
+    let mut element_events = vec![start];
+    let mut depth = 1;
+    while depth > 0 {
+        let event = events.next()?;
+        match event {
+            XmlEvent::StartElement { .. } => depth += 1,
+            XmlEvent::EndElement { .. } => depth -= 1,
+            XmlEvent::EndDocument => return Err(truncated_error()),
+            _ => {},
+        }
+        element_events.push(event);
+    }
+    Ok(element_events)
+}
+
+// The error for a response that ended before all of its elements were closed, such as when the
+// connection was cut off part way through.
+fn truncated_error() -> serde_xml_rs::Error {
+    serde_xml_rs::Error::Custom("response ended part way through an element".to_owned())
+}
+
+// Blocking reader over chunks of a response received from a channel.
+struct ChunkReader<B> {
+    receiver: mpsc::Receiver<B>,
+    chunk: Option<B>,
+    position: usize,
+}
+
+impl<B> ChunkReader<B> {
+    fn new(receiver: mpsc::Receiver<B>) -> Self {
+        Self {
+            receiver,
+            chunk: None,
+            position: 0,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Read for ChunkReader<B> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        loop {
+            if let Some(chunk) = &self.chunk {
+                let remaining = &chunk.as_ref()[self.position..];
+                if !remaining.is_empty() {
+                    let length = remaining.len().min(buf.len());
+                    buf[..length].copy_from_slice(&remaining[..length]);
+                    self.position += length;
+                    return Ok(length);
+                }
+            }
+            match self.receiver.blocking_recv() {
+                Some(chunk) => {
+                    self.chunk = Some(chunk);
+                    self.position = 0;
+                },
+                // Sender has been dropped, so the whole response has been read.
+                None => return Ok(0),
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::env::VarError;
+use std::path::PathBuf;
+use std::sync::{PoisonError, RwLock};
+use std::time::SystemTime;
+
+use futures_core::future::BoxFuture;
+use reqwest::header::HeaderValue;
+
+use crate::{Error, Result};
+
+/// Provides the auth token that is sent with each request to the API.
+///
+/// The API asks the provider for the current token on every request, so a token that is rotated
+/// is picked up without rebuilding the API or any of its endpoints. If a request is rejected with
+/// a 401, [`TokenProvider::refresh`] is called and the request retried once, before returning an
+/// [`Error::Unauthorized`]. A 403 is returned for private resources rather than a bad token, so
+/// doesn't cause a refresh.
+///
+/// [`StaticTokenProvider`], [`EnvTokenProvider`], and [`FileTokenProvider`] cover the common
+/// cases, but the trait can also be implemented to get tokens from elsewhere, such as a secrets
+/// manager. Both methods return futures so that providers can do I/O without blocking the
+/// runtime.
+///
+/// ```
+/// use std::future::Future;
+/// use std::pin::Pin;
+///
+/// use arnak::{Result, TokenProvider};
+///
+/// struct SecretsManagerTokenProvider;
+///
+/// impl TokenProvider for SecretsManagerTokenProvider {
+///     fn token(&self) -> Pin<Box<dyn Future<Output = Result<String>> + Send + '_>> {
+///         Box::pin(async { Ok("token from the secrets manager".to_owned()) })
+///     }
+/// }
+/// ```
+pub trait TokenProvider: Send + Sync {
+    /// The current auth token.
+    fn token(&self) -> BoxFuture<'_, Result<String>>;
+
+    /// Called when a request was rejected with the current token, before it is retried. Providers
+    /// that cache the token should reload it here. Does nothing by default.
+    fn refresh(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// A [`TokenProvider`] that always returns the same token. Used by
+/// [`crate::BoardGameGeekApi::new`].
+#[derive(Clone, Debug)]
+pub struct StaticTokenProvider {
+    token: String,
+}
+
+impl StaticTokenProvider {
+    /// Creates a provider that always returns the given token.
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+impl TokenProvider for StaticTokenProvider {
+    fn token(&self) -> BoxFuture<'_, Result<String>> {
+        Box::pin(async { Ok(self.token.clone()) })
+    }
+}
+
+/// A [`TokenProvider`] that reads the token from an environment variable each time it is needed,
+/// so changes to the variable are picked up by the next request.
+#[derive(Clone, Debug)]
+pub struct EnvTokenProvider {
+    variable: String,
+    // Reads the variable, which is always `std::env::var` outside of tests, so that the tests
+    // don't have to modify the environment of the whole test process.
+    read_var: fn(&str) -> std::result::Result<String, VarError>,
+}
+
+impl EnvTokenProvider {
+    /// Creates a provider that reads the token from the given environment variable.
+    pub fn new(variable: impl Into<String>) -> Self {
+        Self {
+            variable: variable.into(),
+            read_var: |variable| std::env::var(variable),
+        }
+    }
+}
+
+impl TokenProvider for EnvTokenProvider {
+    fn token(&self) -> BoxFuture<'_, Result<String>> {
+        Box::pin(async {
+            (self.read_var)(&self.variable).map_err(|e| {
+                Error::TokenError(format!(
+                    "failed to read environment variable {}: {e}",
+                    self.variable,
+                ))
+            })
+        })
+    }
+}
+
+/// A [`TokenProvider`] that reads the token from a file, such as a mounted secret, and reloads it
+/// whenever the file is modified. Leading and trailing whitespace in the file is ignored.
+///
+/// The file is not watched. Instead, its modification time is checked before each request, and
+/// the file is only read again when that has changed. The file is accessed with [`tokio::fs`], so
+/// this does not block the runtime.
+#[derive(Debug)]
+pub struct FileTokenProvider {
+    path: PathBuf,
+    // The token last read from the file, and when the file was modified at the time.
+    cached: RwLock<Option<CachedToken>>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    token: String,
+    modified: Option<SystemTime>,
+}
+
+impl FileTokenProvider {
+    /// Creates a provider that reads the token from the given file.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cached: RwLock::new(None),
+        }
+    }
+
+    // When the file was last modified, if the platform supports it.
+    async fn modified(&self) -> Result<Option<SystemTime>> {
+        let metadata = tokio::fs::metadata(&self.path)
+            .await
+            .map_err(|e| self.read_error(&e))?;
+        Ok(metadata.modified().ok())
+    }
+
+    async fn reload(&self) -> Result<String> {
+        let modified = self.modified().await?;
+        let token = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|e| self.read_error(&e))?
+            .trim()
+            .to_owned();
+        let mut cached = self.cached.write().unwrap_or_else(PoisonError::into_inner);
+        *cached = Some(CachedToken {
+            token: token.clone(),
+            modified,
+        });
+        Ok(token)
+    }
+
+    fn read_error(&self, e: &std::io::Error) -> Error {
+        Error::TokenError(format!(
+            "failed to read token file {}: {e}",
+            self.path.display(),
+        ))
+    }
+}
+
+impl TokenProvider for FileTokenProvider {
+    fn token(&self) -> BoxFuture<'_, Result<String>> {
+        Box::pin(async {
+            let modified = self.modified().await?;
+            {
+                let cached = self.cached.read().unwrap_or_else(PoisonError::into_inner);
+                if let Some(cached) = cached.as_ref() {
+                    if modified.is_some() && cached.modified == modified {
+                        return Ok(cached.token.clone());
+                    }
+                }
+            }
+            self.reload().await
+        })
+    }
+
+    fn refresh(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async { self.reload().await.map(|_| ()) })
+    }
+}
+
+// The value of the `Authorization` header sent with a token.
+pub(crate) fn auth_header_value(token: &str) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {token}")).map_err(|_| {
+        Error::TokenError("auth token contains invalid header characters".to_owned())
+    })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    // The number of times the fake environment variable has been set, standing in for the real
+    // environment so that the test doesn't race with other threads reading it.
+    static ENV_TOKEN_VERSION: AtomicUsize = AtomicUsize::new(0);
+
+    fn read_fake_var(variable: &str) -> std::result::Result<String, VarError> {
+        assert_eq!(variable, "BGG_AUTH_TOKEN");
+        match ENV_TOKEN_VERSION.load(Ordering::SeqCst) {
+            0 => Err(VarError::NotPresent),
+            version => Ok(format!("token_{version}")),
+        }
+    }
+
+    #[tokio::test]
+    async fn env_token_provider() {
+        let provider = EnvTokenProvider {
+            variable: "BGG_AUTH_TOKEN".to_owned(),
+            read_var: read_fake_var,
+        };
+        assert!(matches!(provider.token().await, Err(Error::TokenError(_))));
+
+        ENV_TOKEN_VERSION.store(1, Ordering::SeqCst);
+        assert_eq!(provider.token().await.unwrap(), "token_1");
+        ENV_TOKEN_VERSION.store(2, Ordering::SeqCst);
+        assert_eq!(provider.token().await.unwrap(), "token_2");
+    }
+
+    #[tokio::test]
+    async fn file_token_provider() {
+        let path = std::env::temp_dir().join(format!("arnak_token_{}", std::process::id()));
+        std::fs::write(&path, "first_token\n").unwrap();
+        let provider = FileTokenProvider::new(&path);
+        assert_eq!(provider.token().await.unwrap(), "first_token");
+
+        // The modification time may not change between two quick writes, but a refresh always
+        // reloads the file.
+        std::fs::write(&path, "second_token\n").unwrap();
+        provider.refresh().await.unwrap();
+        assert_eq!(provider.token().await.unwrap(), "second_token");
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(provider.token().await, Err(Error::TokenError(_))));
+    }
+}